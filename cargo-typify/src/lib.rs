@@ -139,7 +139,7 @@ impl std::str::FromStr for CrateSpec {
 /// Generate Rust code for the selected JSON Schema.
 pub fn convert(args: &CliArgs) -> Result<String> {
     let content = std::fs::read_to_string(&args.input)
-        .wrap_err_with(|| format!("Failed to open input file: {}", &args.input.display()))?;
+        .wrap_err_with(|| format!("Failed to open input file: {}", args.input.display()))?;
 
     let schema = serde_json::from_str::<schemars::schema::RootSchema>(&content)
         .wrap_err("Failed to parse input file as JSON Schema")?;
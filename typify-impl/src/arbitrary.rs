@@ -0,0 +1,731 @@
+// Copyright 2026 Oxide Computer Company
+
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
+
+use crate::{
+    type_entry::{
+        StructProperty, StructPropertyRename, TypeEntry, TypeEntryDetails, TypeEntryEnum,
+        TypeEntryNative, TypeEntryNewtype, TypeEntryNewtypeConstraints, TypeEntryStruct,
+        VariantDetails,
+    },
+    TypeId, TypeSpace,
+};
+
+/// Recursive types stop generating optional or variable-length content after
+/// this many nested levels.
+const MAX_DEPTH: u32 = 3;
+
+/// The default (exclusive) upper bound on the number of items we generate for
+/// arrays and maps beyond their minimum.
+const EXTRA_ITEMS: u32 = 4;
+
+/// The `proptest::arbitrary::Arbitrary` impl for a struct.
+pub(crate) fn struct_arbitrary_impl(
+    type_space: &TypeSpace,
+    entry_id: Option<&TypeId>,
+    struct_details: &TypeEntryStruct,
+) -> TokenStream {
+    let TypeEntryStruct {
+        name,
+        properties,
+        schema,
+        ..
+    } = struct_details;
+
+    let generator = Generator::new(type_space, entry_id);
+
+    let property_schemas = match &schema.0 {
+        Schema::Object(SchemaObject {
+            object: Some(object),
+            ..
+        }) => Some(&object.properties),
+        _ => None,
+    };
+    let strategy = generator.properties_strategy(
+        properties,
+        |prop| {
+            let raw_name = match &prop.rename {
+                StructPropertyRename::Rename(raw_name) => raw_name,
+                StructPropertyRename::None | StructPropertyRename::Flatten => &prop.name,
+            };
+            property_schemas.and_then(|schemas| schemas.get(raw_name))
+        },
+        quote! { Self },
+    );
+
+    generator.arbitrary_impl(name, strategy)
+}
+
+/// The `proptest::arbitrary::Arbitrary` impl for an enum.
+pub(crate) fn enum_arbitrary_impl(
+    type_space: &TypeSpace,
+    entry_id: Option<&TypeId>,
+    enum_details: &TypeEntryEnum,
+) -> TokenStream {
+    let TypeEntryEnum { name, variants, .. } = enum_details;
+
+    let generator = Generator::new(type_space, entry_id);
+
+    // Variants that contain the type itself (other than via an optional or
+    // variable-length container) are only generated until we reach the
+    // maximum depth.
+    let (deep, shallow): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = format_ident!("{}", variant.ident_name.as_ref().unwrap());
+            let (strategy, type_ids) = match &variant.details {
+                VariantDetails::Simple => (
+                    quote! { ::proptest::strategy::Just(Self::#variant_name) },
+                    vec![],
+                ),
+                VariantDetails::Item(type_id) => {
+                    let strategy = generator.strategy(type_id, None);
+                    (
+                        quote! { #strategy.prop_map(Self::#variant_name) },
+                        vec![type_id],
+                    )
+                }
+                VariantDetails::Tuple(type_ids) => {
+                    let (strategy, pattern, values) = generator.tuple_strategy(type_ids);
+                    (
+                        quote! {
+                            #strategy.prop_map(|#pattern| Self::#variant_name(#(#values),*))
+                        },
+                        type_ids.iter().collect(),
+                    )
+                }
+                VariantDetails::Struct(properties) => (
+                    generator.properties_strategy(
+                        properties,
+                        |_| None,
+                        quote! { Self::#variant_name },
+                    ),
+                    properties.iter().map(|prop| &prop.type_id).collect(),
+                ),
+            };
+            let deep = generator.recursive
+                && type_ids
+                    .into_iter()
+                    .any(|type_id| generator.requires_recursion(type_id));
+            (deep, quote! { #strategy.boxed() })
+        })
+        .partition(|(deep, _)| *deep);
+
+    let deep = deep
+        .into_iter()
+        .map(|(_, strategy)| strategy)
+        .collect::<Vec<_>>();
+    let shallow = shallow
+        .into_iter()
+        .map(|(_, strategy)| strategy)
+        .collect::<Vec<_>>();
+
+    let strategy = if deep.is_empty() || shallow.is_empty() {
+        quote! {
+            ::proptest::strategy::Union::new([#(#deep,)* #(#shallow,)*])
+        }
+    } else {
+        quote! {
+            {
+                let mut variants = vec![#(#shallow,)*];
+                if depth < #MAX_DEPTH {
+                    variants.extend([#(#deep,)*]);
+                }
+                ::proptest::strategy::Union::new(variants)
+            }
+        }
+    };
+
+    generator.arbitrary_impl(name, strategy)
+}
+
+/// The `proptest::arbitrary::Arbitrary` impl for a newtype.
+pub(crate) fn newtype_arbitrary_impl(
+    type_space: &TypeSpace,
+    entry_id: Option<&TypeId>,
+    newtype_details: &TypeEntryNewtype,
+) -> TokenStream {
+    let TypeEntryNewtype {
+        name,
+        type_id,
+        constraints,
+        schema,
+        ..
+    } = newtype_details;
+
+    let generator = Generator::new(type_space, entry_id);
+
+    // Constrained values are checked with the same conversion used during
+    // deserialization.
+    let strategy = match constraints {
+        TypeEntryNewtypeConstraints::None => {
+            let inner = generator.strategy(type_id, Some(&schema.0));
+            quote! { #inner.prop_map(Self) }
+        }
+
+        TypeEntryNewtypeConstraints::EnumValue(values) => {
+            let inner_type = type_space.id_to_entry.get(type_id).unwrap();
+            let values = values
+                .iter()
+                .map(|value| inner_type.output_value(type_space, &value.0, &quote! {}));
+            quote! {
+                ::proptest::sample::select(vec![#(#values,)*]).prop_map(Self)
+            }
+        }
+
+        TypeEntryNewtypeConstraints::DenyValue(_) => {
+            let inner = generator.strategy(type_id, Some(&schema.0));
+            quote! {
+                #inner.prop_filter_map("denied value", |value| Self::try_from(value).ok())
+            }
+        }
+
+        TypeEntryNewtypeConstraints::String {
+            max_length,
+            min_length,
+            pattern,
+            not_pattern: _,
+        } => {
+            let inner = match pattern {
+                // Schema patterns are not anchored so strings that match
+                // the pattern without its anchors also match the pattern. If
+                // the pattern can't be parsed we fall back to arbitrary
+                // strings and rely on the conversion below.
+                Some(pattern) => {
+                    let pattern = strip_anchors(pattern);
+                    quote! {
+                        match ::proptest::string::string_regex(#pattern) {
+                            Ok(strategy) => strategy.boxed(),
+                            Err(_) => ::proptest::arbitrary::any::<
+                                ::std::string::String
+                            >().boxed(),
+                        }
+                    }
+                }
+                None => {
+                    let min = min_length.unwrap_or(0) as usize;
+                    let max = max_length.unwrap_or(min_length.unwrap_or(0) + EXTRA_ITEMS) as usize;
+                    quote! {
+                        ::proptest::collection::vec(
+                            ::proptest::arbitrary::any::<char>(),
+                            #min..=#max,
+                        )
+                        .prop_map(|chars| chars.into_iter().collect::<::std::string::String>())
+                    }
+                }
+            };
+            quote! {
+                #inner.prop_filter_map("string constraints", |value| Self::try_from(value).ok())
+            }
+        }
+    };
+
+    generator.arbitrary_impl(name, strategy)
+}
+
+struct Generator<'a> {
+    type_space: &'a TypeSpace,
+    /// Whether the type being generated contains itself; if so, it takes a
+    /// depth parameter used to bound the size of generated values.
+    recursive: bool,
+}
+
+impl<'a> Generator<'a> {
+    /// The id is that of the type being generated, if it's in the type space.
+    fn new(type_space: &'a TypeSpace, entry_id: Option<&TypeId>) -> Self {
+        let recursive = entry_id.is_some_and(|type_id| is_recursive(type_space, type_id));
+        Self {
+            type_space,
+            recursive,
+        }
+    }
+
+    fn arbitrary_impl(&self, name: &str, strategy: TokenStream) -> TokenStream {
+        let type_name = format_ident!("{}", name);
+
+        let (parameters, arg) = if self.recursive {
+            (quote! { u32 }, quote! { depth })
+        } else {
+            (quote! { () }, quote! { _ })
+        };
+
+        // If we're generating validation methods, use them to filter out
+        // values that violate constraints we can't otherwise express.
+        let validate = self.type_space.settings.validation_methods.then(|| {
+            quote! {
+                .prop_filter("schema constraints", |value| value.validate().is_ok())
+            }
+        });
+
+        quote! {
+            impl ::proptest::arbitrary::Arbitrary for #type_name {
+                type Parameters = #parameters;
+                type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+                fn arbitrary_with(#arg: Self::Parameters) -> Self::Strategy {
+                    use ::proptest::strategy::Strategy as _;
+                    #strategy
+                        #validate
+                        .boxed()
+                }
+            }
+        }
+    }
+
+    /// A strategy that produces a struct or struct variant given by `ctor`.
+    fn properties_strategy<'s>(
+        &self,
+        properties: &[StructProperty],
+        schema_for: impl Fn(&StructProperty) -> Option<&'s Schema>,
+        ctor: TokenStream,
+    ) -> TokenStream {
+        let (strategies, names): (Vec<_>, Vec<_>) = properties
+            .iter()
+            .map(|prop| {
+                let prop_type = self.type_space.id_to_entry.get(&prop.type_id).unwrap();
+                let strategy = match (&prop.rename, &prop_type.details) {
+                    // Additional properties could collide with named ones so
+                    // we leave them empty.
+                    (StructPropertyRename::Flatten, TypeEntryDetails::Map(..)) => quote! {
+                        ::proptest::strategy::Just(::std::default::Default::default())
+                    },
+                    _ => self.strategy(&prop.type_id, schema_for(prop)),
+                };
+                (strategy, format_ident!("{}", prop.name))
+            })
+            .unzip();
+
+        match nest(&strategies, &names) {
+            Some((strategy, pattern)) => quote! {
+                #strategy.prop_map(|#pattern| #ctor { #(#names),* })
+            },
+            None => quote! {
+                ::proptest::strategy::Just(#ctor {})
+            },
+        }
+    }
+
+    /// A strategy that produces a tuple of values along with the pattern that
+    /// destructures it and the names of the bound values.
+    fn tuple_strategy(&self, type_ids: &[TypeId]) -> (TokenStream, TokenStream, Vec<syn::Ident>) {
+        let strategies = type_ids
+            .iter()
+            .map(|type_id| self.strategy(type_id, None))
+            .collect::<Vec<_>>();
+        let names = (0..type_ids.len())
+            .map(|ii| format_ident!("v{}", ii))
+            .collect::<Vec<_>>();
+        let (strategy, pattern) = nest(&strategies, &names)
+            .unwrap_or_else(|| (quote! { ::proptest::strategy::Just(()) }, quote! { () }));
+        (strategy, pattern, names)
+    }
+
+    /// A strategy for the given type. The `schema` is the schema from which
+    /// the type was generated, if known; we use it for constraints such as
+    /// numeric bounds that aren't captured by the type.
+    fn strategy(&self, type_id: &TypeId, schema: Option<&Schema>) -> TokenStream {
+        let schema = schema.and_then(|schema| match schema {
+            Schema::Object(schema_object) => Some(schema_object),
+            Schema::Bool(_) => None,
+        });
+        self.strategy_for_object(type_id, schema)
+    }
+
+    fn strategy_for_object(&self, type_id: &TypeId, schema: Option<&SchemaObject>) -> TokenStream {
+        let type_entry = self.type_space.id_to_entry.get(type_id).unwrap();
+
+        match &type_entry.details {
+            TypeEntryDetails::Enum(_)
+            | TypeEntryDetails::Struct(_)
+            | TypeEntryDetails::Newtype(_) => {
+                let type_name = type_entry.type_ident(self.type_space, &None);
+                if self.recursive && is_recursive(self.type_space, type_id) {
+                    quote! {
+                        <#type_name as ::proptest::arbitrary::Arbitrary>::arbitrary_with(
+                            depth + 1
+                        )
+                    }
+                } else {
+                    quote! { ::proptest::arbitrary::any::<#type_name>() }
+                }
+            }
+
+            TypeEntryDetails::Option(inner_id) => {
+                let inner = self.type_space.id_to_entry.get(inner_id).unwrap();
+                // Nested options are flattened in the generated type.
+                if let TypeEntryDetails::Option(_) = &inner.details {
+                    return self.strategy_for_object(inner_id, schema);
+                }
+                let schema = schema.map(schema_without_null);
+                let strategy = self.strategy_for_object(inner_id, schema.as_ref());
+                self.bounded(inner_id, true, quote! { ::proptest::option::of(#strategy) })
+            }
+
+            TypeEntryDetails::Box(inner_id, pointer_type) => {
+                let strategy = self.strategy_for_object(inner_id, schema);
+                let pointer = pointer_type.path();
+                quote! { #strategy.prop_map(#pointer::new) }
+            }
+
+            TypeEntryDetails::Vec(item_id) | TypeEntryDetails::Set(item_id) => {
+                let array = schema.and_then(|s| s.array.as_deref());
+                let item_schema = array.and_then(|a| match &a.items {
+                    Some(SingleOrVec::Single(item)) => Some(item.as_ref()),
+                    _ => None,
+                });
+                let item = self.strategy(item_id, item_schema);
+
+                let min = array.and_then(|a| a.min_items).unwrap_or(0);
+                let max = array
+                    .and_then(|a| a.max_items)
+                    .unwrap_or(min + EXTRA_ITEMS)
+                    .max(min);
+                let size = {
+                    let min = min as usize;
+                    let max = max as usize;
+                    quote! { #min..=#max }
+                };
+
+                let unique = matches!(&type_entry.details, TypeEntryDetails::Set(_))
+                    || array.and_then(|a| a.unique_items) == Some(true);
+                let strategy = if unique {
+                    // Removing duplicates always leaves at least one item.
+                    let too_few = (min > 1).then(|| {
+                        let min = min as usize;
+                        quote! {
+                            .prop_filter("too few unique items", |items| items.len() >= #min)
+                        }
+                    });
+                    quote! {
+                        ::proptest::collection::vec(#item, #size)
+                            .prop_map(|items| {
+                                let mut seen = ::std::vec::Vec::new();
+                                items
+                                    .into_iter()
+                                    .filter(|item| {
+                                        let value = ::serde_json::to_value(item).ok();
+                                        let unique = !seen.contains(&value);
+                                        seen.push(value);
+                                        unique
+                                    })
+                                    .collect::<::std::vec::Vec<_>>()
+                            })
+                            #too_few
+                    }
+                } else {
+                    quote! { ::proptest::collection::vec(#item, #size) }
+                };
+                self.bounded(item_id, min == 0, strategy)
+            }
+
+            TypeEntryDetails::Array(item_id, length) => {
+                let item_schema =
+                    schema
+                        .and_then(|s| s.array.as_deref())
+                        .and_then(|a| match &a.items {
+                            Some(SingleOrVec::Single(item)) => Some(item.as_ref()),
+                            _ => None,
+                        });
+                let item = self.strategy(item_id, item_schema);
+                quote! {
+                    ::proptest::collection::vec(#item, #length).prop_map(|items| {
+                        <[_; #length]>::try_from(items).unwrap_or_else(|_| unreachable!())
+                    })
+                }
+            }
+
+            TypeEntryDetails::Map(key_id, value_id) => {
+                let key = self.strategy(key_id, None);
+                let value = self.strategy(
+                    value_id,
+                    schema
+                        .and_then(|s| s.object.as_deref())
+                        .and_then(|o| o.additional_properties.as_deref()),
+                );
+                let max = EXTRA_ITEMS as usize;
+                let strategy = quote! {
+                    ::proptest::collection::vec((#key, #value), 0..=#max)
+                        .prop_map(|entries| entries.into_iter().collect())
+                };
+                self.bounded(value_id, true, strategy)
+            }
+
+            TypeEntryDetails::Tuple(type_ids) => {
+                let (strategy, pattern, values) = self.tuple_strategy(type_ids);
+                quote! {
+                    #strategy.prop_map(|#pattern| (#(#values,)*))
+                }
+            }
+
+            TypeEntryDetails::Unit => quote! { ::proptest::strategy::Just(()) },
+            TypeEntryDetails::Boolean => quote! { ::proptest::arbitrary::any::<bool>() },
+            TypeEntryDetails::String => {
+                quote! { ::proptest::arbitrary::any::<::std::string::String>() }
+            }
+
+            TypeEntryDetails::Integer(type_name) | TypeEntryDetails::Float(type_name) => {
+                let is_float = matches!(&type_entry.details, TypeEntryDetails::Float(_));
+                number_strategy(type_name, is_float, schema)
+            }
+
+            TypeEntryDetails::Native(TypeEntryNative { type_name, .. }) => {
+                native_strategy(type_entry.type_ident(self.type_space, &None), type_name)
+            }
+
+            TypeEntryDetails::JsonValue => quote! {
+                ::proptest::prop_oneof![
+                    ::proptest::strategy::Just(::serde_json::Value::Null),
+                    ::proptest::arbitrary::any::<bool>().prop_map(::serde_json::Value::from),
+                    ::proptest::arbitrary::any::<i64>().prop_map(::serde_json::Value::from),
+                    ::proptest::arbitrary::any::<::std::string::String>()
+                        .prop_map(::serde_json::Value::from),
+                ]
+            },
+
+            TypeEntryDetails::Reference(_) => unreachable!(),
+        }
+    }
+
+    /// In recursive types, produce the default (empty) value for optional or
+    /// variable-length content once we reach the maximum depth. Note that we
+    /// must not construct the inner strategy past that point since
+    /// construction itself would recurse.
+    fn bounded(&self, inner_id: &TypeId, can_be_empty: bool, strategy: TokenStream) -> TokenStream {
+        if can_be_empty && self.recursive && self.reaches_recursion(inner_id) {
+            quote! {
+                if depth < #MAX_DEPTH {
+                    #strategy.boxed()
+                } else {
+                    ::proptest::strategy::Just(::std::default::Default::default()).boxed()
+                }
+            }
+        } else {
+            strategy
+        }
+    }
+
+    /// Whether the type contains a recursive type.
+    fn reaches_recursion(&self, type_id: &TypeId) -> bool {
+        let type_entry = self.type_space.id_to_entry.get(type_id).unwrap();
+        match &type_entry.details {
+            TypeEntryDetails::Enum(_)
+            | TypeEntryDetails::Struct(_)
+            | TypeEntryDetails::Newtype(_) => is_recursive(self.type_space, type_id),
+            _ => child_ids(type_entry)
+                .into_iter()
+                .any(|child_id| self.reaches_recursion(child_id)),
+        }
+    }
+
+    /// Whether the type necessarily contains a recursive type i.e. not only
+    /// via an optional or variable-length container.
+    fn requires_recursion(&self, type_id: &TypeId) -> bool {
+        let type_entry = self.type_space.id_to_entry.get(type_id).unwrap();
+        match &type_entry.details {
+            TypeEntryDetails::Enum(_)
+            | TypeEntryDetails::Struct(_)
+            | TypeEntryDetails::Newtype(_) => is_recursive(self.type_space, type_id),
+            TypeEntryDetails::Box(..)
+            | TypeEntryDetails::Array(..)
+            | TypeEntryDetails::Tuple(_) => child_ids(type_entry)
+                .into_iter()
+                .any(|child_id| self.requires_recursion(child_id)),
+            _ => false,
+        }
+    }
+}
+
+/// Combine strategies into nested pairs so that we aren't limited by the
+/// maximum tuple size for which proptest implements `Strategy`. Returns the
+/// combined strategy and a pattern that binds each value to its name.
+fn nest(strategies: &[TokenStream], names: &[syn::Ident]) -> Option<(TokenStream, TokenStream)> {
+    let mut pairs = strategies.iter().zip(names).rev();
+    let (strategy, name) = pairs.next()?;
+    Some(pairs.fold(
+        (strategy.clone(), quote! { #name }),
+        |(strategy, pattern), (next, name)| {
+            (quote! { (#next, #strategy) }, quote! { (#name, #pattern) })
+        },
+    ))
+}
+
+fn number_strategy(type_name: &str, is_float: bool, schema: Option<&SchemaObject>) -> TokenStream {
+    let type_ident = syn::parse_str::<syn::Type>(type_name).unwrap();
+    let any = quote! { ::proptest::arbitrary::any::<#type_ident>() };
+
+    // We can only apply bounds to primitive types.
+    if type_name.contains("::") {
+        return any;
+    }
+    let Some(number) = schema.and_then(|s| s.number.as_deref()) else {
+        return any;
+    };
+
+    let (min, max) = if is_float {
+        (
+            number.minimum.or(number.exclusive_minimum),
+            number.maximum.or(number.exclusive_maximum),
+        )
+    } else {
+        (
+            number
+                .minimum
+                .map(f64::ceil)
+                .or(number.exclusive_minimum.map(|v| v.floor() + 1.0)),
+            number
+                .maximum
+                .map(f64::floor)
+                .or(number.exclusive_maximum.map(|v| v.ceil() - 1.0)),
+        )
+    };
+
+    let literal = |value: f64| -> TokenStream {
+        if is_float {
+            let lit = syn::LitFloat::new(
+                &format!("{:?}{}", value, type_name),
+                proc_macro2::Span::call_site(),
+            );
+            quote! { #lit }
+        } else {
+            let lit = syn::LitInt::new(
+                &format!("{}{}", value as i128, type_name),
+                proc_macro2::Span::call_site(),
+            );
+            quote! { #lit }
+        }
+    };
+
+    let range = match (min, max) {
+        (None, None) => return any,
+        (Some(min), None) => {
+            let min = literal(min);
+            quote! { #min.. }
+        }
+        (None, Some(max)) => {
+            let max = literal(max);
+            quote! { ..=#max }
+        }
+        (Some(min), Some(max)) => {
+            let min = literal(min);
+            let max = literal(max);
+            quote! { #min..=#max }
+        }
+    };
+
+    // Exclusive float bounds are enforced by filtering.
+    let exclusive_min = is_float
+        .then_some(number.exclusive_minimum)
+        .flatten()
+        .filter(|_| number.minimum.is_none())
+        .map(|v| {
+            let v = literal(v);
+            quote! { .prop_filter("exclusive minimum", |value| *value > #v) }
+        });
+    let exclusive_max = is_float
+        .then_some(number.exclusive_maximum)
+        .flatten()
+        .filter(|_| number.maximum.is_none())
+        .map(|v| {
+            let v = literal(v);
+            quote! { .prop_filter("exclusive maximum", |value| *value < #v) }
+        });
+
+    quote! { (#range) #exclusive_min #exclusive_max }
+}
+
+fn native_strategy(type_ident: TokenStream, type_name: &str) -> TokenStream {
+    match type_name {
+        "::uuid::Uuid" => quote! {
+            ::proptest::arbitrary::any::<u128>().prop_map(::uuid::Uuid::from_u128)
+        },
+        // Stick to years 1 through 9999 so that values can be represented
+        // in RFC 3339 format.
+        "::chrono::naive::NaiveDate" => quote! {
+            (1i32..=3_652_059).prop_map(|days| {
+                ::chrono::naive::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            })
+        },
+        "::chrono::DateTime<::chrono::offset::Utc>" => quote! {
+            (-62_135_596_800i64..=253_402_300_799).prop_map(|secs| {
+                ::chrono::DateTime::from_timestamp(secs, 0).unwrap()
+            })
+        },
+        // Everything else (including the `std::net` types) must implement
+        // `Arbitrary` itself.
+        _ => quote! { ::proptest::arbitrary::any::<#type_ident>() },
+    }
+}
+
+/// The schema for the non-null values of a (possibly) nullable type.
+fn schema_without_null(schema: &SchemaObject) -> SchemaObject {
+    let mut schema = schema.clone();
+    if let Some(SingleOrVec::Vec(types)) = &schema.instance_type {
+        let types = types
+            .iter()
+            .filter(|t| **t != InstanceType::Null)
+            .cloned()
+            .collect::<Vec<_>>();
+        schema.instance_type = Some(types.into());
+    }
+    schema
+}
+
+/// Remove the anchors from a pattern since the proptest regex generator
+/// doesn't support them.
+fn strip_anchors(pattern: &str) -> &str {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    match pattern.strip_suffix('$') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => pattern,
+    }
+}
+
+fn is_recursive(type_space: &TypeSpace, type_id: &TypeId) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![type_id];
+    while let Some(id) = stack.pop() {
+        let type_entry = type_space.id_to_entry.get(id).unwrap();
+        for child_id in child_ids(type_entry) {
+            if child_id == type_id {
+                return true;
+            }
+            if visited.insert(child_id) {
+                stack.push(child_id);
+            }
+        }
+    }
+    false
+}
+
+fn child_ids(type_entry: &TypeEntry) -> Vec<&TypeId> {
+    match &type_entry.details {
+        TypeEntryDetails::Enum(TypeEntryEnum { variants, .. }) => variants
+            .iter()
+            .flat_map(|variant| match &variant.details {
+                VariantDetails::Simple => Vec::new(),
+                VariantDetails::Item(type_id) => vec![type_id],
+                VariantDetails::Tuple(type_ids) => type_ids.iter().collect(),
+                VariantDetails::Struct(properties) => {
+                    properties.iter().map(|prop| &prop.type_id).collect()
+                }
+            })
+            .collect(),
+        TypeEntryDetails::Struct(TypeEntryStruct { properties, .. }) => {
+            properties.iter().map(|prop| &prop.type_id).collect()
+        }
+        TypeEntryDetails::Newtype(TypeEntryNewtype { type_id, .. })
+        | TypeEntryDetails::Option(type_id)
+        | TypeEntryDetails::Box(type_id, _)
+        | TypeEntryDetails::Vec(type_id)
+        | TypeEntryDetails::Set(type_id)
+        | TypeEntryDetails::Array(type_id, _) => vec![type_id],
+        TypeEntryDetails::Map(key_id, value_id) => vec![key_id, value_id],
+        TypeEntryDetails::Tuple(type_ids) => type_ids.iter().collect(),
+        TypeEntryDetails::Native(TypeEntryNative { parameters, .. }) => parameters.iter().collect(),
+        _ => Vec::new(),
+    }
+}
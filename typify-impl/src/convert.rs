@@ -4,19 +4,24 @@ use std::collections::BTreeSet;
 
 use crate::merge::{merge_all, try_merge_with_subschemas};
 use crate::type_entry::{
-    EnumTagType, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype, TypeEntryStruct,
-    Variant, VariantDetails,
+    Annotations, EnumTagType, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype,
+    TypeEntryStruct, Variant, VariantDetails,
 };
-use crate::util::{all_mutually_exclusive, ref_key, ReorderedInstanceType, StringValidator};
-use log::{debug, info};
+use crate::util::{
+    all_mutually_exclusive, property_test, ref_key, required_only, split_conditionals, Conditional,
+    Exclusions, ReorderedInstanceType, StringValidator,
+};
+use log::{debug, info, warn};
 use schemars::schema::{
     ArrayValidation, InstanceType, Metadata, ObjectValidation, Schema, SchemaObject, SingleOrVec,
     StringValidation, SubschemaValidation,
 };
 
-use crate::util::get_type_name;
+use crate::naming::NameFrame;
+use crate::util::TypePatch;
+use crate::validation::contains_checked;
 
-use crate::{Error, Name, Result, TypeSpace, TypeSpaceImpl};
+use crate::{AnyOfStrategy, Error, Name, Result, TypeSpace, TypeSpaceImpl};
 
 pub const STD_NUM_NONZERO_PREFIX: &str = "::std::num::NonZero";
 
@@ -95,11 +100,16 @@ impl TypeSpace {
                     // wrapper to give it a name). In such a case, we invent a
                     // new name for the inner type; otherwise, the inner type
                     // can just have this name.
-                    let inner_type_name = match &type_name {
-                        Name::Required(name) => Name::Suggested(format!("{}Inner", name)),
-                        _ => type_name,
+                    let (inner_type_name, frame) = match &type_name {
+                        Name::Required(name) => (
+                            Name::Suggested(format!("{}Inner", name)),
+                            NameFrame::derived("inner", Some(name)),
+                        ),
+                        _ => (type_name, NameFrame::new::<&str>(&[], None)),
                     };
-                    self.convert_option(inner_type_name, metadata, &ss)
+                    self.in_subschema(frame, |type_space| {
+                        type_space.convert_option(inner_type_name, metadata, &ss)
+                    })
                 } else {
                     // .. otherwise we try again with a simpler type.
                     let new_schema = SchemaObject {
@@ -430,7 +440,11 @@ impl TypeSpace {
                     new_schema = merge_all(
                         &[schema_without_ref, schema_only_ref, new_schema],
                         &self.definitions,
-                    );
+                    )
+                    .map_err(|reason| Error::InvalidSchema {
+                        type_name: type_name.clone().into_option(),
+                        reason,
+                    })?;
                     if let Some(r) = r {
                         def = self.definitions.get(&ref_key(r)).unwrap();
                     } else {
@@ -464,6 +478,25 @@ impl TypeSpace {
                 extensions: _,
             } => self.convert_unknown_enum(type_name, original_schema, metadata, enum_values),
 
+            // Conditional subschemas.
+            SchemaObject {
+                subschemas: Some(subschemas),
+                ..
+            } if !split_conditionals(subschemas).0.is_empty() => {
+                self.convert_conditional(type_name, original_schema, schema)
+            }
+
+            // Values or string patterns excluded from an otherwise simple
+            // schema.
+            SchemaObject {
+                subschemas: Some(subschemas),
+                ..
+            } if Exclusions::from_subschemas(subschemas)
+                .is_some_and(|exclusions| exclusions.applies_to(schema)) =>
+            {
+                self.convert_excluded(type_name, original_schema, schema)
+            }
+
             // Subschemas
             SchemaObject {
                 metadata,
@@ -515,7 +548,9 @@ impl TypeSpace {
                     if_schema: None,
                     then_schema: None,
                     else_schema: None,
-                } => self.convert_not(type_name, original_schema, metadata, subschema),
+                } if schema.instance_type.is_none() => {
+                    self.convert_not(type_name, original_schema, metadata, subschema)
+                }
 
                 // Multiple subschemas may be present at the same time; attempt
                 // to merge and then convert.
@@ -529,15 +564,19 @@ impl TypeSpace {
                         schema_object,
                         Some(subschemas),
                         &self.definitions,
-                    );
+                    )
+                    .map_err(|reason| Error::InvalidSchema {
+                        type_name: type_name.clone().into_option(),
+                        reason,
+                    })?;
                     match merged_schema {
-                        Ok(s) => {
+                        Some(s) => {
                             let (type_entry, _) =
                                 self.convert_schema_object(type_name, original_schema, &s)?;
                             Ok((type_entry, &None))
                         }
-                        // An error indicates that the schema is unresolvable.
-                        Err(_) => self.convert_never(type_name, original_schema),
+                        // No value satisfies the schema.
+                        None => self.convert_never(type_name, original_schema),
                     }
                 }
             },
@@ -561,8 +600,12 @@ impl TypeSpace {
                     without_subschemas,
                     subschemas.as_deref(),
                     &self.definitions,
-                ) {
-                    Ok(merged_schema) => {
+                )
+                .map_err(|reason| Error::InvalidSchema {
+                    type_name: type_name.clone().into_option(),
+                    reason,
+                })? {
+                    Some(merged_schema) => {
                         // Preserve metadata from the outer schema.
                         let merged_schema = SchemaObject {
                             metadata: metadata.clone(),
@@ -578,7 +621,7 @@ impl TypeSpace {
                         Ok((type_entry, &None))
                     }
 
-                    Err(_) => self.convert_never(type_name, original_schema),
+                    None => self.convert_never(type_name, original_schema),
                 }
             }
 
@@ -892,6 +935,7 @@ impl TypeSpace {
                                 metadata,
                                 type_id,
                                 validation,
+                                None,
                                 original_schema.clone(),
                             ),
                             metadata,
@@ -937,6 +981,7 @@ impl TypeSpace {
                     Some(Ok(Variant::new(
                         variant_name.clone(),
                         None,
+                        Annotations::default(),
                         VariantDetails::Simple,
                     )))
                 }
@@ -1329,21 +1374,32 @@ impl TypeSpace {
 
             // The typical case
             Some(validation) => {
-                let tmp_type_name = get_type_name(&type_name, metadata);
-                let (properties, deny_unknown_fields) =
-                    self.struct_members(tmp_type_name, validation)?;
+                let tmp_type_name = self.resolve_type_name(&type_name, metadata);
+                let (mut properties, deny_unknown_fields) =
+                    self.struct_members(tmp_type_name.clone(), validation)?;
+                let extra_prop = self.unknown_properties(tmp_type_name, validation, &properties)?;
+                let unknown_properties = extra_prop.is_some();
+                properties.extend(extra_prop);
+
+                let mut type_entry = TypeEntryStruct::from_metadata(
+                    self,
+                    type_name,
+                    metadata,
+                    properties,
+                    deny_unknown_fields,
+                    unknown_properties,
+                    original_schema.clone(),
+                );
+                if let TypeEntryDetails::Struct(details) = &mut type_entry.details {
+                    details.forbidden_properties = validation
+                        .properties
+                        .iter()
+                        .filter(|(_, schema)| matches!(schema, Schema::Bool(false)))
+                        .map(|(name, _)| name.clone())
+                        .collect();
+                }
 
-                Ok((
-                    TypeEntryStruct::from_metadata(
-                        self,
-                        type_name,
-                        metadata,
-                        properties,
-                        deny_unknown_fields,
-                        original_schema.clone(),
-                    ),
-                    &None,
-                ))
+                Ok((type_entry, &None))
             }
         }
     }
@@ -1428,19 +1484,21 @@ impl TypeSpace {
         // suboptimal in that we would like the generated types to reflect some
         // association with the original sub-type.
         //
-        // TODO
-        // In cases where we have a named type, we would like to provide
-        // conversion methods to extract the named type from the merged type.
-        // In the "SuperClass" example above, we would provide an
-        // Into<SuperClass> implementation to discard the additional properties
-        // and produce an instance of "SuperClass".
-        //
-        // We can do something similar for types that become additionally
-        // constrained: a field that becomes required can be converted to an
-        // optional field; a number whose value is limited can be converted to
-        // the more expansive numeric type.
-
-        let merged_schema = merge_all(subschemas, &self.definitions);
+        // In cases where we have a named type, we can provide conversions to
+        // extract the named type from the merged type. In the "SuperClass"
+        // example above, we provide a From<Merged> for SuperClass
+        // implementation that discards the additional properties and produces
+        // an instance of "SuperClass". We do something similar for types that
+        // become additionally constrained: a field that becomes required can
+        // be converted to an optional field (and vice versa, fallibly). We
+        // don't convert properties whose types differ in other ways (e.g. a
+        // number whose value is limited); see `output_base_conversions`.
+
+        let merged_schema =
+            merge_all(subschemas, &self.definitions).map_err(|reason| Error::InvalidSchema {
+                type_name: type_name.clone().into_option(),
+                reason,
+            })?;
         if let Schema::Bool(false) = &merged_schema {
             self.convert_never(type_name, original_schema)
         } else {
@@ -1448,8 +1506,24 @@ impl TypeSpace {
             assert!(merged_schema.metadata.is_none());
             merged_schema.metadata = metadata.clone();
 
-            let (type_entry, _) =
+            let (mut type_entry, _) =
                 self.convert_schema_object(type_name, original_schema, &merged_schema)?;
+
+            // Note the named types from which a struct was merged.
+            if let TypeEntryDetails::Struct(details) = &mut type_entry.details {
+                details.bases = subschemas
+                    .iter()
+                    .filter_map(|schema| match schema {
+                        Schema::Object(SchemaObject {
+                            reference: Some(ref_name),
+                            ..
+                        }) => self.ref_to_id.get(&ref_key(ref_name)).cloned(),
+                        _ => None,
+                    })
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect();
+            }
             Ok((type_entry, &None))
         }
     }
@@ -1473,19 +1547,356 @@ impl TypeSpace {
         // occurs if each subschema is mutually exclusive i.e. so that exactly
         // one of them can match.
         if all_mutually_exclusive(subschemas, &self.definitions) {
-            self.convert_one_of(type_name, original_schema, metadata, subschemas)
+            return self.convert_one_of(type_name, original_schema, metadata, subschemas);
+        }
+
+        let strategy = match self.resolve_type_name(&type_name, metadata) {
+            Some(name) => TypePatch::new(self, name).any_of_strategy,
+            None => self.settings.any_of_strategy,
+        };
+
+        match strategy {
+            AnyOfStrategy::Flatten => {}
+
+            // An untagged enum tries its variants in order, so we put the
+            // subschemas that demand the most first: a value that matches a
+            // more specific subschema would also match a less specific one.
+            AnyOfStrategy::UntaggedEnum => {
+                let mut sorted = subschemas.to_vec();
+                sorted.sort_by_key(|schema| std::cmp::Reverse(self.object_specificity(schema)));
+                let ty = self.untagged_enum(type_name, original_schema, metadata, &sorted)?;
+                return Ok((ty, metadata));
+            }
+
+            AnyOfStrategy::MergedStruct => {
+                if let Some(merged_schema) = self.union_object(metadata, subschemas) {
+                    let (ty, _) =
+                        self.convert_schema_object(type_name, original_schema, &merged_schema)?;
+                    return Ok((ty, &None));
+                }
+                info!(
+                    "anyOf subschemas are not all objects; falling back to a \
+                     flattened struct"
+                );
+            }
+        }
+
+        // We'll want to build a struct that looks like this:
+        // struct Name {
+        //     #[serde(flatten)]
+        //     schema1: Option<Schema1Type>,
+        //     #[serde(flatten)]
+        //     schema2: Option<Schema2Type>,
+        //     ...
+        // }
+        self.flattened_union_struct(type_name, original_schema, metadata, subschemas, true)
+    }
+
+    /// Follow a single level of reference to the schema that describes an
+    /// object, if that's what this schema is.
+    fn object_validation<'a>(&'a self, schema: &'a Schema) -> Option<&'a ObjectValidation> {
+        let schema = match schema {
+            Schema::Object(SchemaObject {
+                reference: Some(ref_name),
+                ..
+            }) => self.definitions.get(&ref_key(ref_name))?,
+            schema => schema,
+        };
+
+        match schema {
+            Schema::Object(SchemaObject {
+                metadata: _,
+                instance_type,
+                format: None,
+                enum_values: None,
+                const_value: None,
+                subschemas: None,
+                number: None,
+                string: None,
+                array: None,
+                object: Some(validation),
+                reference: None,
+                extensions: _,
+            }) => match instance_type {
+                None => Some(validation),
+                Some(SingleOrVec::Single(it)) if **it == InstanceType::Object => Some(validation),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// How much an object schema demands of a value: the number of its
+    /// required properties and then of all its properties.
+    fn object_specificity(&self, schema: &Schema) -> (usize, usize) {
+        self.object_validation(schema)
+            .map(|validation| (validation.required.len(), validation.properties.len()))
+            .unwrap_or_default()
+    }
+
+    /// Produce an object schema whose properties are the union of those of the
+    /// given object schemas, all of them optional. A property described
+    /// differently by several subschemas may take any of those forms.
+    fn union_object(
+        &self,
+        metadata: &Option<Box<Metadata>>,
+        subschemas: &[Schema],
+    ) -> Option<SchemaObject> {
+        let validations = subschemas
+            .iter()
+            .map(|schema| self.object_validation(schema))
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut properties = schemars::Map::<String, Vec<Schema>>::new();
+        for validation in &validations {
+            for (name, schema) in &validation.properties {
+                let forms = properties.entry(name.clone()).or_default();
+                if !forms.contains(schema) {
+                    forms.push(schema.clone());
+                }
+            }
+        }
+        let properties = properties
+            .into_iter()
+            .map(|(name, mut forms)| {
+                let schema = if forms.len() == 1 {
+                    forms.pop().unwrap()
+                } else {
+                    SchemaObject {
+                        subschemas: Some(Box::new(SubschemaValidation {
+                            any_of: Some(forms),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }
+                    .into()
+                };
+                (name, schema)
+            })
+            .collect();
+
+        // Unknown properties may take the form that any subschema permits;
+        // they're only rejected if every subschema rejects them, and they may
+        // be anything if any subschema permits anything.
+        let mut additional_forms = Vec::<Schema>::new();
+        for validation in &validations {
+            match validation.additional_properties.as_deref() {
+                Some(Schema::Bool(false)) => {}
+                None | Some(Schema::Bool(true)) => {
+                    additional_forms = vec![Schema::Bool(true)];
+                    break;
+                }
+                Some(schema) => {
+                    if !additional_forms.contains(schema) {
+                        additional_forms.push(schema.clone());
+                    }
+                }
+            }
+        }
+        let additional_properties = match additional_forms.len() {
+            0 => Some(Schema::Bool(false)),
+            1 if additional_forms[0] == Schema::Bool(true) => None,
+            1 => additional_forms.pop(),
+            _ => Some(
+                SchemaObject {
+                    subschemas: Some(Box::new(SubschemaValidation {
+                        any_of: Some(additional_forms),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+        }
+        .map(Box::new);
+
+        Some(SchemaObject {
+            metadata: metadata.clone(),
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation {
+                properties,
+                additional_properties,
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+
+    /// A schema with `if`/`then`/`else` conditionals that each test whether a
+    /// required property with enumerated values has a particular value is
+    /// effectively a discriminated union; we produce an internally tagged
+    /// enum with a variant for each value. Otherwise we produce a type for the
+    /// schema without its conditionals (and a struct's `validate()` method
+    /// checks the conditionals that only require properties).
+    fn convert_conditional<'a>(
+        &mut self,
+        type_name: Name,
+        original_schema: &'a Schema,
+        schema: &'a SchemaObject,
+    ) -> Result<(TypeEntry, &'a Option<Box<Metadata>>)> {
+        let subschemas = schema.subschemas.as_deref().unwrap();
+        let (conditionals, all_of) = split_conditionals(subschemas);
+
+        // The schema without its conditionals.
+        let remaining = SubschemaValidation {
+            all_of: (!all_of.is_empty()).then(|| all_of.into_iter().cloned().collect()),
+            if_schema: None,
+            then_schema: None,
+            else_schema: None,
+            ..subschemas.clone()
+        };
+        let base_schema = SchemaObject {
+            subschemas: (remaining != SubschemaValidation::default()).then(|| Box::new(remaining)),
+            ..schema.clone()
+        };
+
+        if let Some(variants) = self.conditional_variants(&base_schema, &conditionals) {
+            if let Some(type_entry) = self.maybe_internally_tagged_enum(
+                type_name.clone(),
+                original_schema,
+                &schema.metadata,
+                &variants,
+            ) {
+                return Ok((type_entry, &schema.metadata));
+            }
+        }
+
+        let name = self
+            .resolve_type_name(&type_name, &schema.metadata)
+            .unwrap_or_else(|| "an anonymous type".to_string());
+        let checked = conditionals.iter().all(|conditional| {
+            property_test(conditional.if_schema).is_some()
+                && [conditional.then_schema, conditional.else_schema]
+                    .into_iter()
+                    .flatten()
+                    .all(|schema| required_only(schema).is_some())
+        });
+        if checked && self.settings.validation_methods {
+            info!(
+                "the if/then/else conditionals of {} are checked by its validate() method",
+                name,
+            );
         } else {
-            // We'll want to build a struct that looks like this:
-            // struct Name {
-            //     #[serde(flatten)]
-            //     schema1: Option<Schema1Type>,
-            //     #[serde(flatten)]
-            //     schema2: Option<Schema2Type>,
-            //     ...
-            // }
-
-            self.flattened_union_struct(type_name, original_schema, metadata, subschemas, true)
+            warn!(
+                "the if/then/else conditionals of {} are not reflected in its type",
+                name,
+            );
         }
+
+        let (type_entry, _) =
+            self.convert_schema_object(type_name, original_schema, &base_schema)?;
+        Ok((type_entry, &None))
+    }
+
+    /// The object schemas for each value of the property that the
+    /// conditionals test, each merged with the `then` or `else` schema that
+    /// applies.
+    fn conditional_variants(
+        &self,
+        base_schema: &SchemaObject,
+        conditionals: &[Conditional<'_>],
+    ) -> Option<Vec<Schema>> {
+        // Each conditional must test a different value of the same property.
+        let tests = conditionals
+            .iter()
+            .map(|conditional| property_test(conditional.if_schema))
+            .collect::<Option<Vec<_>>>()?;
+        let (tag, _, _) = *tests.first()?;
+        let tested = tests
+            .iter()
+            .map(|(prop_name, value, _)| (*prop_name == tag).then_some(*value))
+            .collect::<Option<BTreeSet<_>>>()?;
+        if tested.len() != tests.len() {
+            return None;
+        }
+
+        // With several conditionals, an `else` would apply to values that
+        // other conditionals also match.
+        if conditionals.len() > 1
+            && conditionals
+                .iter()
+                .any(|conditional| conditional.else_schema.is_some())
+        {
+            return None;
+        }
+
+        // The property must be required and its values enumerated.
+        let base = try_merge_with_subschemas(
+            SchemaObject {
+                subschemas: None,
+                ..base_schema.clone()
+            },
+            base_schema.subschemas.as_deref(),
+            &self.definitions,
+        )
+        .ok()??;
+        let validation = base.object.as_deref()?;
+        if !validation.required.contains(tag) {
+            return None;
+        }
+        let tag_schema = match validation.properties.get(tag)? {
+            Schema::Object(SchemaObject {
+                reference: Some(ref_name),
+                ..
+            }) => self.definitions.get(&ref_key(ref_name))?,
+            schema => schema,
+        };
+        let Schema::Object(SchemaObject {
+            enum_values: Some(values),
+            ..
+        }) = tag_schema
+        else {
+            return None;
+        };
+        let values = values
+            .iter()
+            .map(|value| value.as_str())
+            .collect::<Option<Vec<_>>>()?;
+        if !tested.iter().all(|value| values.contains(value)) {
+            return None;
+        }
+
+        // A reference that we can't resolve is left for the general case to
+        // report.
+        let variants = values
+            .into_iter()
+            .map(|value| {
+                let branch = match tests.iter().position(|(_, tested, _)| *tested == value) {
+                    Some(ii) => conditionals[ii].then_schema,
+                    None => conditionals[0].else_schema,
+                };
+
+                let mut variant = SchemaObject {
+                    metadata: None,
+                    ..base.clone()
+                };
+                variant.object.as_mut().unwrap().properties.insert(
+                    tag.to_string(),
+                    SchemaObject {
+                        instance_type: Some(InstanceType::String.into()),
+                        enum_values: Some(vec![value.into()]),
+                        ..Default::default()
+                    }
+                    .into(),
+                );
+
+                match branch {
+                    None => Ok(Some(variant.into())),
+                    Some(branch) => {
+                        merge_all(&[variant.into(), branch.clone()], &self.definitions).map(
+                            |merged| match merged {
+                                // This value can't satisfy the schema.
+                                Schema::Bool(false) => None,
+                                merged => Some(merged),
+                            },
+                        )
+                    }
+                }
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .ok()?;
+
+        Some(variants.into_iter().flatten().collect())
     }
 
     /// A "one of" may reasonably be converted into a Rust enum, but there are
@@ -1761,8 +2172,126 @@ impl TypeSpace {
                 }
             }
 
-            _ => todo!("unhandled not schema {:#?}", subschema),
+            // Otherwise we subtract the schema from the permissive schema.
+            _ => {
+                let subschemas = SubschemaValidation {
+                    not: Some(Box::new(subschema.clone())),
+                    ..Default::default()
+                };
+                match try_merge_with_subschemas(
+                    SchemaObject::default(),
+                    Some(&subschemas),
+                    &self.definitions,
+                )
+                .map_err(|reason| Error::InvalidSchema {
+                    type_name: type_name.clone().into_option(),
+                    reason,
+                })? {
+                    Some(merged_schema) => {
+                        let merged_schema = SchemaObject {
+                            metadata: metadata.clone(),
+                            ..merged_schema
+                        };
+                        let (type_entry, _) =
+                            self.convert_schema_object(type_name, original_schema, &merged_schema)?;
+                        Ok((type_entry, metadata))
+                    }
+                    None => self.convert_never(type_name, original_schema),
+                }
+            }
+        }
+    }
+
+    /// Convert a schema whose `not` subschema excludes values or string
+    /// patterns. Excluded strings become a constraint on a string newtype;
+    /// other excluded values wrap the type of the rest of the schema.
+    fn convert_excluded<'a>(
+        &mut self,
+        type_name: Name,
+        original_schema: &'a Schema,
+        schema: &'a SchemaObject,
+    ) -> Result<(TypeEntry, &'a Option<Box<Metadata>>)> {
+        let metadata = &schema.metadata;
+        let Exclusions { values, patterns } = schema
+            .subschemas
+            .as_deref()
+            .and_then(Exclusions::from_subschemas)
+            .unwrap();
+        let base = SchemaObject {
+            metadata: None,
+            subschemas: None,
+            ..schema.clone()
+        };
+
+        // Values excluded from a constrained string join its other
+        // constraints; otherwise they wrap the type of the rest of the schema.
+        if patterns.is_empty() && !(Exclusions::accepts_patterns(schema) && base.string.is_some()) {
+            let frame = NameFrame::derived("inner", type_name.clone().into_option().as_deref());
+            let (type_entry, _) = self.in_subschema(frame, |type_space| {
+                type_space.convert_schema_object(type_name.append("inner"), original_schema, &base)
+            })?;
+
+            // Make sure all the values are valid.
+            values
+                .iter()
+                .try_for_each(|value| type_entry.validate_value(self, value).map(|_| ()))?;
+
+            let type_id = self.assign_type(type_entry);
+
+            let newtype_entry = TypeEntryNewtype::from_metadata_with_deny_values(
+                self,
+                type_name,
+                metadata,
+                type_id,
+                &values,
+                original_schema.clone(),
+            );
+
+            return Ok((newtype_entry, metadata));
+        }
+
+        // Excluded string values become one more excluded pattern.
+        let mut patterns = patterns;
+        let strings = values
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .map(escape_pattern)
+            .collect::<Vec<_>>();
+        if !strings.is_empty() {
+            patterns.push(format!("^(?:{})$", strings.join("|")));
+        }
+        let not_pattern = match patterns.as_slice() {
+            [pattern] => pattern.clone(),
+            _ => patterns
+                .iter()
+                .map(|pattern| format!("(?:{})", pattern))
+                .collect::<Vec<_>>()
+                .join("|"),
+        };
+
+        let validation = base.string.as_deref().cloned().unwrap_or_default();
+        for pattern in validation.pattern.iter().chain(Some(&not_pattern)) {
+            let _ = regress::Regex::new(pattern).map_err(|e| Error::InvalidSchema {
+                type_name: type_name.clone().into_option(),
+                reason: format!("invalid pattern '{}' {}", pattern, e),
+            })?;
         }
+        self.uses_regress = true;
+
+        let string = TypeEntryDetails::String.into();
+        let type_id = self.assign_type(string);
+        Ok((
+            TypeEntryNewtype::from_metadata_with_string_validation(
+                self,
+                type_name,
+                metadata,
+                type_id,
+                &validation,
+                Some(not_pattern),
+                original_schema.clone(),
+            ),
+            metadata,
+        ))
     }
 
     fn convert_array<'a>(
@@ -1786,15 +2315,20 @@ impl TypeSpace {
             } if max_items == min_items && *max_items > 0 => match items {
                 // Tuple with fewer types specified than required items.
                 Some(SingleOrVec::Vec(items)) if items.len() < *max_items as usize => {
+                    let parent = type_name.clone().into_option();
                     let rest_name = type_name.append("additional");
-                    let rest_id = if let Some(rest_schema) = additional_items {
-                        self.id_for_schema(rest_name, rest_schema)?.0
-                    } else {
-                        self.id_for_schema(rest_name, &Schema::Bool(true))?.0
-                    };
+                    let frame = NameFrame::new(&["additionalItems"], parent.as_deref());
+                    let rest_id = self.in_subschema(frame, |type_space| {
+                        let rest_schema =
+                            additional_items.as_deref().unwrap_or(&Schema::Bool(true));
+                        Ok::<_, Error>(type_space.id_for_schema(rest_name, rest_schema)?.0)
+                    })?;
                     let start = items.iter().enumerate().map(|(ii, item_schema)| {
                         let item_name = type_name.append(&format!("item{}", ii));
-                        Ok(self.id_for_schema(item_name, item_schema)?.0)
+                        let frame = NameFrame::new(&["items", &ii.to_string()], parent.as_deref());
+                        self.in_subschema(frame, |type_space| {
+                            Ok(type_space.id_for_schema(item_name, item_schema)?.0)
+                        })
                     });
                     let rest = (items.len()..*max_items as usize).map(|_| Ok(rest_id.clone()));
                     let types = start.chain(rest).collect::<Result<Vec<_>>>()?;
@@ -1808,7 +2342,13 @@ impl TypeSpace {
                         .enumerate()
                         .map(|(ii, item_schema)| {
                             let item_name = type_name.append(&format!("item{}", ii));
-                            Ok(self.id_for_schema(item_name, item_schema)?.0)
+                            let frame = NameFrame::new(
+                                &["items", &ii.to_string()],
+                                type_name.clone().into_option().as_deref(),
+                            );
+                            self.in_subschema(frame, |type_space| {
+                                Ok(type_space.id_for_schema(item_name, item_schema)?.0)
+                            })
                         })
                         .collect::<Result<_>>()?;
                     Ok((TypeEntryDetails::Tuple(types).into(), metadata))
@@ -1816,7 +2356,13 @@ impl TypeSpace {
 
                 // Array with a schema for the item.
                 Some(SingleOrVec::Single(item_schema)) => {
-                    let item_id = self.id_for_schema(type_name.append("item"), item_schema)?.0;
+                    let frame =
+                        NameFrame::new(&["items"], type_name.clone().into_option().as_deref());
+                    let item_id = self
+                        .in_subschema(frame, |type_space| {
+                            type_space.id_for_schema(type_name.append("item"), item_schema)
+                        })?
+                        .0;
                     Ok((
                         TypeEntryDetails::Array(item_id, *max_items as usize).into(),
                         metadata,
@@ -1841,13 +2387,17 @@ impl TypeSpace {
                 max_items: _,        // TODO enforce size limitations
                 min_items: _,        // TODO enforce size limitations
                 unique_items,
-                contains: None,
-            } => {
-                let item_type_name = match get_type_name(&type_name, metadata) {
+                contains,
+            } if contains_checked(self, contains.as_deref()) => {
+                let parent = self.resolve_type_name(&type_name, metadata);
+                let item_type_name = match &parent {
                     Some(s) => Name::Suggested(format!("{}Item", s)),
                     None => Name::Unknown,
                 };
-                let (type_id, _) = self.id_for_schema(item_type_name, item.as_ref())?;
+                let frame = NameFrame::new(&["items"], parent.as_deref());
+                let (type_id, _) = self.in_subschema(frame, |type_space| {
+                    type_space.id_for_schema(item_type_name, item.as_ref())
+                })?;
 
                 // If items are unique, this is a Set; otherwise it's an Array.
                 match unique_items {
@@ -1863,8 +2413,8 @@ impl TypeSpace {
                 max_items: _,        // TODO enforce size limitations
                 min_items: _,        // TODO enforce size limitations
                 unique_items,
-                contains: None,
-            } => {
+                contains,
+            } if contains_checked(self, contains.as_deref()) => {
                 self.uses_serde_json = true;
                 let type_id = self.assign_type(TypeEntryDetails::JsonValue.into());
 
@@ -1931,18 +2481,29 @@ impl TypeSpace {
         schema: &'a SchemaObject,
         enum_values: &[serde_json::Value],
     ) -> Result<(TypeEntry, &'a Option<Box<Metadata>>)> {
+        if self.settings.integer_enums {
+            if let Some(type_entry) =
+                self.maybe_integer_enum(type_name.clone(), original_schema, schema, enum_values)
+            {
+                return Ok((type_entry, &schema.metadata));
+            }
+        }
+
         let type_schema = SchemaObject {
             enum_values: None,
             ..schema.clone()
         };
 
-        let inner_type_name = match get_type_name(&type_name, &schema.metadata) {
+        let parent = self.resolve_type_name(&type_name, &schema.metadata);
+        let inner_type_name = match &parent {
             Some(s) => Name::Suggested(format!("{}Inner", s)),
             None => Name::Unknown,
         };
 
-        let (type_entry, metadata) =
-            self.convert_schema_object(inner_type_name, original_schema, &type_schema)?;
+        let frame = NameFrame::derived("inner", parent.as_deref());
+        let (type_entry, metadata) = self.in_subschema(frame, |type_space| {
+            type_space.convert_schema_object(inner_type_name, original_schema, &type_schema)
+        })?;
 
         // Make sure all the values are valid.
         enum_values
@@ -2011,8 +2572,23 @@ impl TypeSpace {
             if enum_values.is_empty() {
                 self.convert_null(metadata)
             } else {
-                let (type_entry, metadata) =
-                    self.convert_unknown_enum(type_name, original_schema, metadata, &enum_values)?;
+                // As above, a required name goes to the newtype wrapper of the
+                // Option rather than to the inner type.
+                let (inner_type_name, frame) = match &type_name {
+                    Name::Required(name) => (
+                        Name::Suggested(format!("{}Inner", name)),
+                        NameFrame::derived("inner", Some(name)),
+                    ),
+                    _ => (type_name, NameFrame::new::<&str>(&[], None)),
+                };
+                let (type_entry, metadata) = self.in_subschema(frame, |type_space| {
+                    type_space.convert_unknown_enum(
+                        inner_type_name,
+                        original_schema,
+                        metadata,
+                        &enum_values,
+                    )
+                })?;
                 let type_entry = self.type_to_option(type_entry);
                 Ok((type_entry, metadata))
             }
@@ -2054,10 +2630,16 @@ impl TypeSpace {
                     ))
                 }
                 (1, None) => unreachable!(),
-                _ => panic!(
-                    "multiple implied types for an un-typed enum {:?} {:?}",
-                    instance_types, enum_values,
-                ),
+                _ => {
+                    match self.maybe_literal_enum(type_name, original_schema, metadata, enum_values)
+                    {
+                        Some(type_entry) => Ok((type_entry, metadata)),
+                        None => panic!(
+                            "multiple implied types for an un-typed enum {:?} {:?}",
+                            instance_types, enum_values,
+                        ),
+                    }
+                }
             }
         }
     }
@@ -2089,6 +2671,18 @@ impl TypeSpace {
     }
 }
 
+/// Escape a string so that it matches itself as a regular expression.
+fn escape_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
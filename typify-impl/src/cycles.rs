@@ -7,136 +7,303 @@ use std::{
 
 use crate::{
     type_entry::{
-        TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype, TypeEntryStruct,
-        VariantDetails,
+        StructPropertyRename, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype,
+        TypeEntryStruct, VariantDetails,
     },
-    TypeId, TypeSpace,
+    PointerType, Result, TypeId, TypeSpace,
 };
 
+/// An edge in the containment graph: the child type in the given slot (i.e.
+/// the index into [get_child_ids]) of the `from` type. We look through
+/// `Option` types so that a pointer goes inside the `Option` rather than
+/// modifying the (shared) `Option` type.
+struct Edge {
+    from: TypeId,
+    slot: usize,
+    to: TypeId,
+    /// The `Option` types between the slot and the `to` type, outermost
+    /// first.
+    options: Vec<TypeId>,
+    /// The pointer type specified for this slot by a patch, if any.
+    pointer_type: Option<PointerType>,
+    /// Whether the edge refers back to a type that encloses it in the
+    /// depth-first traversal of the graph.
+    back: bool,
+}
+
 impl TypeSpace {
     /// We need to root out any containment cycles, breaking them by inserting
-    /// a `Box` type. Our choice of *where* to break cycles is more arbitrary
-    /// than optimal, but is well beyond sufficient.
-    pub(crate) fn break_cycles(&mut self, range: Range<u64>) {
-        enum Node {
-            Start {
-                type_id: TypeId,
-            },
-            Processing {
-                type_id: TypeId,
-                children_ids: Vec<TypeId>,
-            },
+    /// a pointer type (`Box` by default). We look for a minimal set of break
+    /// points, i.e. one from which no pointer could be removed without
+    /// leaving a cycle. That's not necessarily the smallest such set (finding
+    /// that is NP-hard), but it's close to what one would write by hand: we
+    /// prefer fields for which a patch specified the pointer type, then
+    /// optional fields, then edges that refer back to an enclosing type.
+    pub(crate) fn break_cycles(&mut self, range: Range<u64>) -> Result<()> {
+        let edges = self.containment_edges(range);
+        let component = strongly_connected_components(&edges);
+
+        // Only an edge within a strongly connected component can be part of a
+        // cycle. We start by breaking all of them and then restore them one
+        // at a time--least preferred break points first--so long as doing so
+        // doesn't close a cycle.
+        let mut candidates = edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| component.get(&edge.from) == component.get(&edge.to))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(index, edge)| {
+            (
+                edge.pointer_type.is_some(),
+                !edge.options.is_empty(),
+                edge.back,
+                *index,
+            )
+        });
+
+        let mut kept = BTreeMap::<&TypeId, Vec<&TypeId>>::new();
+        let mut snip = Vec::new();
+        for (_, edge) in candidates {
+            if edge.from != edge.to && !reachable(&kept, &edge.to, &edge.from) {
+                kept.entry(&edge.from).or_default().push(&edge.to);
+            } else {
+                snip.push(edge);
+            }
         }
 
+        // Figure out the replacement for each slot we're snipping, wrapping
+        // the pointer in as many `Option`s as we looked through.
+        let replace = snip
+            .into_iter()
+            .map(|edge| {
+                let pointer_type = edge
+                    .pointer_type
+                    .clone()
+                    .unwrap_or_else(|| self.settings.pointer_type.clone());
+                pointer_type.validate(
+                    self.id_to_entry
+                        .get(&edge.from)
+                        .and_then(|type_entry| type_entry.name()),
+                )?;
+                let mut type_id = self.id_to_box(&edge.to, pointer_type);
+                for option_id in edge.options.iter().rev() {
+                    type_id = if self.double_options.contains(option_id) {
+                        self.id_to_double_option_inner(&type_id)
+                    } else {
+                        self.id_to_option(&type_id)
+                    };
+                }
+                Ok(((edge.from.clone(), edge.slot), type_id))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Break the cycles by reassigning the child types.
+        for ((type_id, slot), replace_id) in replace {
+            let type_entry = self.id_to_entry.get_mut(&type_id).unwrap();
+            let mut child_ids = get_child_ids(type_entry);
+            *child_ids[slot] = replace_id;
+        }
+
+        Ok(())
+    }
+
+    /// Collect the edges of the containment graph reachable from the types in
+    /// the given range in the order of a depth-first traversal.
+    fn containment_edges(&mut self, range: Range<u64>) -> Vec<Edge> {
+        let mut edges = Vec::new();
         let mut visited = BTreeSet::<TypeId>::new();
 
         for id in range {
-            let type_id = TypeId(id);
-
-            // This isn't strictly necessary, but we'll short-circuit some work
-            // by checking this right away.
-            if visited.contains(&type_id) {
+            let root_id = TypeId(id);
+            if visited.contains(&root_id) || self.is_option(&root_id) {
                 continue;
             }
 
+            // Each entry on the stack holds a type and the edges from it that
+            // we've yet to follow.
             let mut active = BTreeSet::<TypeId>::new();
-            let mut stack = Vec::<Node>::new();
-
-            active.insert(type_id.clone());
-            stack.push(Node::Start { type_id });
-
-            while let Some(top) = stack.last_mut() {
-                match top {
-                    // Skip right to the end since we've already seen this type.
-                    Node::Start { type_id } if visited.contains(type_id) => {
-                        assert!(active.contains(type_id));
-
-                        let type_id = type_id.clone();
-                        *top = Node::Processing {
-                            type_id,
-                            children_ids: Vec::new(),
-                        };
+            let mut stack = Vec::<(TypeId, std::vec::IntoIter<Edge>)>::new();
+
+            visited.insert(root_id.clone());
+            active.insert(root_id.clone());
+            let children = self.child_edges(&root_id).into_iter();
+            stack.push((root_id, children));
+
+            while let Some((type_id, children)) = stack.last_mut() {
+                if let Some(mut edge) = children.next() {
+                    edge.back = active.contains(&edge.to);
+                    let child_id = edge.to.clone();
+                    edges.push(edge);
+
+                    if visited.insert(child_id.clone()) {
+                        active.insert(child_id.clone());
+                        let children = self.child_edges(&child_id).into_iter();
+                        stack.push((child_id, children));
                     }
+                } else {
+                    active.remove(type_id);
+                    let _ = stack.pop();
+                }
+            }
+        }
 
-                    // Break any immediate cycles and queue up this type for
-                    // descent into its child types.
-                    Node::Start { type_id } => {
-                        assert!(active.contains(type_id));
-
-                        visited.insert(type_id.clone());
-
-                        // Determine which child types form cycles--and
-                        // therefore need to be snipped--and the rest--into
-                        // which we should descend. We make this its own block
-                        // to clarify the lifetime of the exclusive reference
-                        // to the type. We don't really *need* to have an
-                        // exclusive reference here, but there's no point in
-                        // writing `get_child_ids` again for shared references.
-                        let (snip, descend) = {
-                            let type_entry = self.id_to_entry.get_mut(type_id).unwrap();
-
-                            let child_ids = get_child_ids(type_entry)
-                                .into_iter()
-                                .map(|child_id| child_id.clone());
-
-                            // If the child type is in active then we've found
-                            // a cycle (otherwise we'll descend).
-                            child_ids.partition::<Vec<_>, _>(|child_id| active.contains(child_id))
-                        };
-
-                        // Note that while `snip` might contain duplicates,
-                        // `id_to_box` is idempotent insofar as the same input
-                        // TypeId will result in the same output TypeId. Ergo
-                        // the resulting pairs from which we construct the
-                        // mapping would contain exact duplicates; it would not
-                        // contain two values associated with the same key.
-                        let replace = snip
-                            .into_iter()
-                            .map(|type_id| {
-                                let box_id = self.id_to_box(&type_id);
-
-                                (type_id, box_id)
-                            })
-                            .collect::<BTreeMap<_, _>>();
-
-                        // Break any cycles by reassigning the child type to a box.
-                        let type_entry = self.id_to_entry.get_mut(type_id).unwrap();
-                        let child_ids = get_child_ids(type_entry);
-                        for child_id in child_ids {
-                            if let Some(replace_id) = replace.get(child_id) {
-                                *child_id = replace_id.clone();
-                            }
-                        }
+        edges
+    }
 
-                        // Descend into child types.
-                        let node = Node::Processing {
-                            type_id: type_id.clone(),
-                            children_ids: descend,
-                        };
-                        *top = node;
-                    }
+    /// The edges from the given type, looking through `Option` types.
+    fn child_edges(&mut self, type_id: &TypeId) -> Vec<Edge> {
+        // We don't really *need* an exclusive reference here, but there's no
+        // point in writing `get_child_ids` again for shared references.
+        let type_entry = self.id_to_entry.get_mut(type_id).unwrap();
+        let pointer_types = slot_pointer_types(type_entry);
+        let child_ids = get_child_ids(type_entry)
+            .into_iter()
+            .map(|child_id| child_id.clone())
+            .collect::<Vec<_>>();
+
+        child_ids
+            .into_iter()
+            .zip(pointer_types)
+            .enumerate()
+            .map(|(slot, (mut to, pointer_type))| {
+                let mut options = Vec::new();
+                while let TypeEntryDetails::Option(inner_id) =
+                    &self.id_to_entry.get(&to).unwrap().details
+                {
+                    options.push(to.clone());
+                    to = inner_id.clone();
+                }
+                Edge {
+                    from: type_id.clone(),
+                    slot,
+                    to,
+                    options,
+                    pointer_type,
+                    back: false,
+                }
+            })
+            .collect()
+    }
+
+    fn is_option(&self, type_id: &TypeId) -> bool {
+        matches!(
+            self.id_to_entry
+                .get(type_id)
+                .map(|type_entry| &type_entry.details),
+            Some(TypeEntryDetails::Option(_))
+        )
+    }
+}
 
-                    // If there are children left, push the next child onto the
-                    // stack. If there are none left, pop this type.
-                    Node::Processing {
-                        type_id,
-                        children_ids,
-                    } => {
-                        if let Some(type_id) = children_ids.pop() {
-                            // Descend into the next child node.
-                            active.insert(type_id.clone());
-                            stack.push(Node::Start { type_id });
-                        } else {
-                            // All done; remove the item from the active list
-                            // and stack.
-                            active.remove(type_id);
-                            let _ = stack.pop();
+/// Compute the strongly connected components of the graph, returning a
+/// component identifier for each type.
+fn strongly_connected_components(edges: &[Edge]) -> BTreeMap<&TypeId, usize> {
+    let mut graph = BTreeMap::<&TypeId, Vec<&TypeId>>::new();
+    for edge in edges {
+        graph.entry(&edge.from).or_default().push(&edge.to);
+    }
+    let children = |type_id: &TypeId| graph.get(type_id).map_or(&[][..], Vec::as_slice);
+
+    // This is Tarjan's algorithm, albeit with an explicit stack rather than
+    // recursion.
+    let mut index = BTreeMap::<&TypeId, usize>::new();
+    let mut lowlink = BTreeMap::<&TypeId, usize>::new();
+    let mut stack = Vec::<&TypeId>::new();
+    let mut on_stack = BTreeSet::<&TypeId>::new();
+    let mut component = BTreeMap::new();
+    let mut count = 0;
+
+    for root in graph.keys().copied() {
+        if index.contains_key(root) {
+            continue;
+        }
+
+        index.insert(root, index.len());
+        lowlink.insert(root, index[root]);
+        stack.push(root);
+        on_stack.insert(root);
+        let mut work = vec![(root, 0)];
+
+        while let Some(&(type_id, next)) = work.last() {
+            if let Some(&child_id) = children(type_id).get(next) {
+                work.last_mut().unwrap().1 += 1;
+                if !index.contains_key(child_id) {
+                    index.insert(child_id, index.len());
+                    lowlink.insert(child_id, index[child_id]);
+                    stack.push(child_id);
+                    on_stack.insert(child_id);
+                    work.push((child_id, 0));
+                } else if on_stack.contains(child_id) {
+                    let low = lowlink[type_id].min(index[child_id]);
+                    lowlink.insert(type_id, low);
+                }
+            } else {
+                let _ = work.pop();
+                if lowlink[type_id] == index[type_id] {
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack.remove(member);
+                        component.insert(member, count);
+                        if member == type_id {
+                            break;
                         }
                     }
+                    count += 1;
+                }
+                if let Some(&(parent_id, _)) = work.last() {
+                    let low = lowlink[parent_id].min(lowlink[type_id]);
+                    lowlink.insert(parent_id, low);
                 }
             }
         }
     }
+
+    component
+}
+
+/// Whether `to` can be reached from `from` via the given edges.
+fn reachable(edges: &BTreeMap<&TypeId, Vec<&TypeId>>, from: &TypeId, to: &TypeId) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut work = vec![from];
+
+    while let Some(type_id) = work.pop() {
+        if type_id == to {
+            return true;
+        }
+        if visited.insert(type_id) {
+            if let Some(children) = edges.get(type_id) {
+                work.extend(children.iter().copied());
+            }
+        }
+    }
+
+    false
+}
+
+/// The pointer types specified by a patch for each slot of the type (i.e.
+/// corresponding with [get_child_ids]).
+fn slot_pointer_types(type_entry: &mut TypeEntry) -> Vec<Option<PointerType>> {
+    match &type_entry.details {
+        TypeEntryDetails::Struct(TypeEntryStruct {
+            properties,
+            pointer_types,
+            ..
+        }) => properties
+            .iter()
+            .map(|prop| {
+                let name = match &prop.rename {
+                    StructPropertyRename::None => &prop.name,
+                    StructPropertyRename::Rename(rename) => rename,
+                    StructPropertyRename::Flatten => return None,
+                };
+                pointer_types.get(name).cloned()
+            })
+            .collect(),
+        _ => {
+            let len = get_child_ids(type_entry).len();
+            vec![None; len]
+        }
+    }
 }
 
 /// For types that could potentially participate in a cycle, return a list of
@@ -179,7 +346,7 @@ mod tests {
     use schema::Schema;
     use schemars::JsonSchema;
 
-    use crate::test_util::validate_output;
+    use crate::{test_util::validate_output, Error, PointerType, TypeSpace, TypeSpaceSettings};
 
     #[test]
     fn test_trivial_cycle() {
@@ -245,4 +412,71 @@ mod tests {
 
         validate_output::<A>();
     }
+
+    #[test]
+    fn test_optional_break() {
+        #[derive(JsonSchema, Schema)]
+        #[allow(dead_code)]
+        struct A {
+            b: B,
+        }
+
+        #[derive(JsonSchema, Schema)]
+        #[allow(dead_code)]
+        struct B {
+            a: Option<Box<A>>,
+        }
+
+        validate_output::<A>();
+        validate_output::<B>();
+    }
+
+    #[test]
+    fn test_minimal_breaks() {
+        #[derive(JsonSchema, Schema)]
+        #[allow(dead_code)]
+        struct A {
+            b: B,
+            c: C,
+        }
+
+        #[derive(JsonSchema, Schema)]
+        #[allow(dead_code)]
+        struct B {
+            c: C,
+        }
+
+        #[derive(JsonSchema, Schema)]
+        #[allow(dead_code)]
+        struct C {
+            a: Option<Box<A>>,
+            b: Option<Box<B>>,
+            d: Vec<A>,
+        }
+
+        validate_output::<A>();
+        validate_output::<B>();
+        validate_output::<C>();
+    }
+
+    #[test]
+    fn test_invalid_pointer_type() {
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct A {
+            a: Option<Box<A>>,
+        }
+
+        let schema = schemars::schema_for!(A);
+        let mut type_space = TypeSpace::new(
+            TypeSpaceSettings::default()
+                .with_pointer_type(PointerType::Custom("not a path".to_string())),
+        );
+        assert!(matches!(
+            type_space.add_root_schema(schema),
+            Err(Error::InvalidSchema { type_name, reason })
+                if type_name.as_deref() == Some("A")
+                    && reason == "invalid pointer type \"not a path\"",
+        ));
+    }
 }
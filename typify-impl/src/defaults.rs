@@ -7,10 +7,11 @@ use quote::{format_ident, quote};
 
 use crate::{
     convert::STD_NUM_NONZERO_PREFIX,
+    structs::is_double_option,
     type_entry::{
-        DefaultKind, EnumTagType, StructProperty, StructPropertyRename, StructPropertyState,
-        TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype, TypeEntryStruct, Variant,
-        VariantDetails, WrappedValue,
+        DefaultKind, EnumRepr, EnumTagType, StructProperty, StructPropertyRename,
+        StructPropertyState, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryNewtype,
+        TypeEntryStruct, Variant, VariantDetails, WrappedValue,
     },
     util::{sanitize, Case},
     DefaultImpl, Error, Result, TypeId, TypeSpace,
@@ -147,6 +148,15 @@ impl TypeEntry {
         default: &serde_json::Value,
     ) -> Result<DefaultKind> {
         match &self.details {
+            TypeEntryDetails::Enum(
+                details @ TypeEntryEnum {
+                    repr: EnumRepr::Integer | EnumRepr::Literal,
+                    ..
+                },
+            ) => details
+                .literal_variant(default)
+                .map(|_| DefaultKind::Specific)
+                .ok_or_else(Error::invalid_value),
             TypeEntryDetails::Enum(TypeEntryEnum {
                 tag_type, variants, ..
             }) => match tag_type {
@@ -180,15 +190,21 @@ impl TypeEntry {
                 Ok(DefaultKind::Specific)
             }
             TypeEntryDetails::Option(type_id) => {
+                // A null `Option<Option<T>>` is `Some(None)` rather than the
+                // intrinsic `None`.
                 if let serde_json::Value::Null = default {
-                    Ok(DefaultKind::Intrinsic)
+                    if is_double_option(type_space, type_id) {
+                        Ok(DefaultKind::Specific)
+                    } else {
+                        Ok(DefaultKind::Intrinsic)
+                    }
                 } else {
                     // Make sure the default is valid for the sub-type.
                     let _ = validate_type_id(type_id, type_space, default)?;
                     Ok(DefaultKind::Specific)
                 }
             }
-            TypeEntryDetails::Box(type_id) => validate_type_id(type_id, type_space, default),
+            TypeEntryDetails::Box(type_id, _) => validate_type_id(type_id, type_space, default),
 
             TypeEntryDetails::Vec(type_id) => {
                 if let serde_json::Value::Array(v) = default {
@@ -672,7 +688,7 @@ mod tests {
         let (type_space, type_id) = get_type::<Option<u32>>();
 
         let type_entry = TypeEntry {
-            details: crate::type_entry::TypeEntryDetails::Box(type_id),
+            details: crate::type_entry::TypeEntryDetails::Box(type_id, crate::PointerType::Box),
             extra_derives: Default::default(),
             extra_attrs: Default::default(),
         };
@@ -2,7 +2,8 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 
-use heck::{ToKebabCase, ToPascalCase};
+use heck::{ToKebabCase, ToPascalCase, ToSnakeCase};
+use log::{info, warn};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use schemars::schema::{
@@ -10,17 +11,19 @@ use schemars::schema::{
 };
 
 use crate::{
+    naming::NameFrame,
     output::OutputSpace,
     structs::generate_serde_attr,
     type_entry::{
-        EnumTagType, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryStruct, Variant,
-        VariantDetails,
+        Annotations, EnumRepr, EnumTagType, StructProperty, StructPropertyRename,
+        StructPropertyState, TypeEntry, TypeEntryDetails, TypeEntryEnum, TypeEntryStruct,
+        UnknownVariant, Variant, VariantDetails,
     },
     util::{
-        constant_string_value, get_object, get_type_name, metadata_description,
-        metadata_title_and_description, schema_is_named,
+        constant_string_value, get_object, metadata_description, metadata_title_and_description,
+        sanitize, schema_is_named,
     },
-    Name, Result, TypeSpace,
+    Case, Name, PointerType, Result, TypeId, TypeSpace,
 };
 
 impl TypeSpace {
@@ -62,11 +65,14 @@ impl TypeSpace {
             Simple {
                 name: &'a str,
                 description: Option<String>,
+                annotations: Annotations,
             },
             Typed {
                 name: &'a str,
                 schema: &'a Schema,
+                variant_schema: &'a Schema,
                 description: Option<String>,
+                annotations: Annotations,
             },
         }
 
@@ -114,6 +120,7 @@ impl TypeSpace {
                                 value.as_str().map(|variant_name| ProtoVariant::Simple {
                                     name: variant_name,
                                     description: metadata_description(metadata),
+                                    annotations: Annotations::from_schema(schema),
                                 })
                             })
                             .collect()
@@ -141,6 +148,7 @@ impl TypeSpace {
                         std::iter::once(value.as_str().map(|variant_name| ProtoVariant::Simple {
                             name: variant_name,
                             description: metadata_description(metadata),
+                            annotations: Annotations::from_schema(schema),
                         }))
                         .collect()
                     }
@@ -174,7 +182,9 @@ impl TypeSpace {
                             Some(vec![ProtoVariant::Typed {
                                 name: prop_name,
                                 schema: prop_type,
+                                variant_schema: schema,
                                 description: metadata_description(metadata),
+                                annotations: Annotations::from_schema(schema),
                             }])
                         }
                         _ => None,
@@ -206,25 +216,42 @@ impl TypeSpace {
                 ProtoVariant::Simple {
                     name: variant_name,
                     description,
+                    annotations,
                 } => Some(Variant::new(
                     variant_name.to_string(),
                     description,
+                    annotations,
                     VariantDetails::Simple,
                 )),
 
                 ProtoVariant::Typed {
                     name: variant_name,
                     schema,
+                    variant_schema,
                     description,
+                    annotations,
                 } => {
                     // Append the variant name to the type_name for our new
                     // type name hint.
+                    let frame = NameFrame::subschema(
+                        original_schema,
+                        variant_schema,
+                        type_name.clone().into_option().as_deref(),
+                    )
+                    .with_property(variant_name);
                     let (details, deny) = self
-                        .external_variant(type_name.append(variant_name), schema)
+                        .in_subschema(frame, |type_space| {
+                            type_space.external_variant(type_name.append(variant_name), schema)
+                        })
                         .ok()?;
                     deny_unknown_fields |= deny;
 
-                    Some(Variant::new(variant_name.to_string(), description, details))
+                    Some(Variant::new(
+                        variant_name.to_string(),
+                        description,
+                        annotations,
+                        details,
+                    ))
                 }
             })
             .collect::<Option<Vec<_>>>()?;
@@ -273,6 +300,12 @@ impl TypeSpace {
                         default: _, // TODO arguably we should look at this
                         properties,
                         deny_unknown_fields,
+                        unknown_properties: _,
+                        // TODO struct variants don't reject forbidden
+                        // properties.
+                        forbidden_properties: _,
+                        pointer_types: _,
+                        bases: _,
                         schema: _,
                     }),
                 ..
@@ -368,12 +401,18 @@ impl TypeSpace {
                     _ => unreachable!(),
                 }
 
-                self.internal_variant(
-                    get_type_name(&type_name, metadata),
-                    metadata,
-                    validation,
-                    tag,
-                )
+                let variant_type_name = self.resolve_type_name(&type_name, metadata);
+                let frame =
+                    NameFrame::subschema(original_schema, schema, variant_type_name.as_deref());
+                self.in_subschema(frame, |type_space| {
+                    type_space.internal_variant(
+                        variant_type_name,
+                        metadata,
+                        Annotations::from_schema(schema),
+                        validation,
+                        tag,
+                    )
+                })
             })
             .collect::<Result<Vec<_>>>()
             .ok()?;
@@ -393,6 +432,7 @@ impl TypeSpace {
         &mut self,
         enum_type_name: Option<String>,
         metadata: &Option<Box<schemars::schema::Metadata>>,
+        annotations: Annotations,
         validation: &ObjectValidation,
         tag: &str,
     ) -> Result<Variant> {
@@ -407,6 +447,7 @@ impl TypeSpace {
             Ok(Variant::new(
                 variant_name.to_string(),
                 None,
+                annotations,
                 VariantDetails::Simple,
             ))
         } else {
@@ -422,6 +463,7 @@ impl TypeSpace {
             Ok(Variant::new(
                 variant_name.to_string(),
                 metadata_title_and_description(metadata),
+                annotations,
                 VariantDetails::Struct(properties),
             ))
         }
@@ -496,8 +538,22 @@ impl TypeSpace {
                 let Some((metadata, validation)) = get_object(schema) else {
                     unreachable!();
                 };
-                let (variant, deny) =
-                    self.adjacent_variant(type_name.clone(), metadata, validation, &tag, &content)?;
+                let frame = NameFrame::subschema(
+                    original_schema,
+                    schema,
+                    type_name.clone().into_option().as_deref(),
+                )
+                .with_property(&content);
+                let (variant, deny) = self.in_subschema(frame, |type_space| {
+                    type_space.adjacent_variant(
+                        type_name.clone(),
+                        metadata,
+                        Annotations::from_schema(schema),
+                        validation,
+                        &tag,
+                        &content,
+                    )
+                })?;
                 deny_unknown_fields |= deny;
                 Ok(variant)
             })
@@ -519,6 +575,7 @@ impl TypeSpace {
         &mut self,
         enum_type_name: Name,
         metadata: &Option<Box<schemars::schema::Metadata>>,
+        annotations: Annotations,
         validation: &ObjectValidation,
         tag: &str,
         content: &str,
@@ -531,7 +588,12 @@ impl TypeSpace {
             assert_eq!(tag_name, tag);
             assert_eq!(validation.required.len(), 1);
 
-            let variant = Variant::new(variant_name.to_string(), None, VariantDetails::Simple);
+            let variant = Variant::new(
+                variant_name.to_string(),
+                None,
+                annotations,
+                VariantDetails::Simple,
+            );
             Ok((variant, false))
         } else {
             let tag_schema = validation.properties.get(tag).unwrap();
@@ -561,6 +623,7 @@ impl TypeSpace {
             let variant = Variant::new(
                 variant_name.to_string(),
                 metadata_title_and_description(metadata),
+                annotations,
                 details,
             );
             Ok((variant, deny))
@@ -603,7 +666,7 @@ impl TypeSpace {
         metadata: &Option<Box<schemars::schema::Metadata>>,
         subschemas: &[Schema],
     ) -> Result<TypeEntry> {
-        let tmp_type_name = get_type_name(&type_name, metadata);
+        let tmp_type_name = self.resolve_type_name(&type_name, metadata);
 
         let mut deny_unknown_fields = false;
 
@@ -662,21 +725,24 @@ impl TypeSpace {
                 }
                 .append(&variant_name);
 
-                let (details, deny) = self.external_variant(prop_type_name, schema)?;
+                let frame = NameFrame::subschema(original_schema, schema, tmp_type_name.as_deref());
+                let (details, deny) = self.in_subschema(frame, |type_space| {
+                    type_space.external_variant(prop_type_name, schema)
+                })?;
                 // Note that this is really only relevant for in-line schemas;
                 // referenced schemas will enforce their own policy on their
                 // generated types.
                 deny_unknown_fields |= deny;
 
-                Ok((details, variant_name))
+                Ok((details, variant_name, Annotations::from_schema(schema)))
             })
             .collect::<Result<Vec<_>>>()?;
 
         let variants = variant_details
             .into_iter()
-            .map(|(details, variant_name)| {
+            .map(|(details, variant_name, annotations)| {
                 assert!(!variant_name.is_empty());
-                Variant::new(variant_name, None, details)
+                Variant::new(variant_name, None, annotations, details)
             })
             .collect();
 
@@ -691,6 +757,197 @@ impl TypeSpace {
             original_schema.clone(),
         ))
     }
+    /// An integer schema with enumerated values may be represented as a
+    /// fieldless `#[repr(i64)]` enum provided that every value is an `i64`.
+    /// Variant names come from an `x-enum-varnames` or `x-enumNames`
+    /// extension if they're valid and unique, and are otherwise derived from
+    /// the values.
+    pub(crate) fn maybe_integer_enum(
+        &mut self,
+        type_name: Name,
+        original_schema: &Schema,
+        schema: &SchemaObject,
+        enum_values: &[serde_json::Value],
+    ) -> Option<TypeEntry> {
+        if schema.instance_type != Some(SingleOrVec::Single(Box::new(InstanceType::Integer))) {
+            return None;
+        }
+
+        let values = enum_values
+            .iter()
+            .map(serde_json::Value::as_i64)
+            .collect::<Option<Vec<_>>>()?;
+        if values.is_empty() || values.iter().collect::<BTreeSet<_>>().len() != values.len() {
+            return None;
+        }
+
+        let ext_names = ["x-enum-varnames", "x-enumNames"]
+            .into_iter()
+            .find_map(|key| schema.extensions.get(key))
+            .and_then(serde_json::Value::as_array)
+            .filter(|names| names.len() == values.len())
+            .and_then(|names| {
+                names
+                    .iter()
+                    .map(|name| Some(sanitize(name.as_str()?, Case::Pascal)))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .filter(|names| names.iter().collect::<BTreeSet<_>>().len() == names.len());
+        let names = ext_names.unwrap_or_else(|| {
+            values
+                .iter()
+                .map(|value| {
+                    if *value < 0 {
+                        format!("ValueNeg{}", value.unsigned_abs())
+                    } else {
+                        format!("Value{}", value)
+                    }
+                })
+                .collect()
+        });
+
+        // The variants are named first, and then given their values as their
+        // raw names.
+        let variants = names
+            .iter()
+            .map(|name| {
+                Variant::new(
+                    name.clone(),
+                    None,
+                    Annotations::default(),
+                    VariantDetails::Simple,
+                )
+            })
+            .collect();
+
+        let mut type_entry = TypeEntryEnum::from_metadata(
+            self,
+            type_name,
+            &schema.metadata,
+            EnumTagType::External,
+            variants,
+            false,
+            original_schema.clone(),
+        );
+
+        let TypeEntryDetails::Enum(details) = &mut type_entry.details else {
+            unreachable!()
+        };
+        details.repr = EnumRepr::Integer;
+        details.unknown_variant = None;
+        details
+            .variants
+            .iter_mut()
+            .zip(values)
+            .zip(names)
+            .for_each(|((variant, value), name)| {
+                variant.raw_name = value.to_string();
+                variant.ident_name = Some(name);
+            });
+
+        Some(type_entry)
+    }
+
+    /// Enumerated values of mixed types may be represented as an enum of
+    /// simple variants, each standing for one value, provided that the values
+    /// are all strings, numbers, or booleans.
+    pub(crate) fn maybe_literal_enum(
+        &mut self,
+        type_name: Name,
+        original_schema: &Schema,
+        metadata: &Option<Box<Metadata>>,
+        enum_values: &[serde_json::Value],
+    ) -> Option<TypeEntry> {
+        let mut values = Vec::<&serde_json::Value>::new();
+        for value in enum_values {
+            match value {
+                serde_json::Value::Bool(_)
+                | serde_json::Value::Number(_)
+                | serde_json::Value::String(_) => {}
+                _ => return None,
+            }
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        let string_names = values
+            .iter()
+            .filter_map(|value| Some(sanitize(value.as_str()?, Case::Pascal)))
+            .collect::<BTreeSet<_>>();
+        let mut names = values
+            .iter()
+            .map(|value| match value {
+                serde_json::Value::String(s) => sanitize(s, Case::Pascal),
+                serde_json::Value::Number(n) => format!(
+                    "Number{}",
+                    n.to_string()
+                        .replace('e', "E")
+                        .replace('-', "Neg")
+                        .replace('.', "Point")
+                        .replace('+', "")
+                ),
+                serde_json::Value::Bool(b) => {
+                    let name = if *b { "True" } else { "False" };
+                    if string_names.contains(name) {
+                        format!("Boolean{}", name)
+                    } else {
+                        name.to_string()
+                    }
+                }
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        if names.iter().collect::<BTreeSet<_>>().len() != names.len() {
+            names = (0..names.len())
+                .map(|ii| format!("Variant{}", ii))
+                .collect();
+        }
+
+        // The variants are named first, and then given the JSON text of their
+        // values as their raw names.
+        let variants = names
+            .iter()
+            .map(|name| {
+                Variant::new(
+                    name.clone(),
+                    None,
+                    Annotations::default(),
+                    VariantDetails::Simple,
+                )
+            })
+            .collect();
+
+        let mut type_entry = TypeEntryEnum::from_metadata(
+            self,
+            type_name,
+            metadata,
+            EnumTagType::External,
+            variants,
+            false,
+            original_schema.clone(),
+        );
+
+        let TypeEntryDetails::Enum(details) = &mut type_entry.details else {
+            unreachable!()
+        };
+        details.repr = EnumRepr::Literal;
+        details.unknown_variant = None;
+
+        // The serde impls of literal enums go through serde_json::Value.
+        self.uses_serde_json = true;
+        details
+            .variants
+            .iter_mut()
+            .zip(values)
+            .zip(names)
+            .for_each(|((variant, value), name)| {
+                variant.raw_name = value.to_string();
+                variant.ident_name = Some(name);
+            });
+
+        Some(type_entry)
+    }
 }
 
 /// Check if the schema can only be satisfied by a null. Since this is JSON
@@ -752,19 +1009,25 @@ pub(crate) fn output_variant(
     type_space: &TypeSpace,
     output: &mut OutputSpace,
     type_name: &str,
+    serde_derived: bool,
 ) -> TokenStream {
     let ident_name = variant.ident_name.as_ref().unwrap();
     let variant_name = format_ident!("{}", ident_name);
-    let doc = variant.description.as_ref().map(|s| {
-        quote! { #[doc = #s] }
-    });
-    let serde = (&variant.raw_name != ident_name).then(|| {
+    let doc = variant
+        .annotations
+        .doc(variant.description.as_deref())
+        .map(|s| {
+            quote! { #[doc = #s] }
+        });
+    let deprecated = variant.annotations.deprecated_attr();
+    let serde = (serde_derived && &variant.raw_name != ident_name).then(|| {
         let s = &variant.raw_name;
         quote! { #[serde(rename = #s)] }
     });
     match &variant.details {
         VariantDetails::Simple => quote! {
             #doc
+            #deprecated
             #serde
             #variant_name,
         },
@@ -777,6 +1040,7 @@ pub(crate) fn output_variant(
 
             quote! {
                 #doc
+                #deprecated
                 #serde
                 #variant_name(#item_type_ident),
             }
@@ -794,6 +1058,7 @@ pub(crate) fn output_variant(
             if tuple.len() != 1 {
                 quote! {
                     #doc
+                    #deprecated
                     #serde
                     #variant_name(#(#types),*),
                 }
@@ -804,6 +1069,7 @@ pub(crate) fn output_variant(
                 // Note in particular the extra parentheses and trailing comma.
                 quote! {
                     #doc
+                    #deprecated
                     #serde
                     #variant_name((#(#types,)*)),
                 }
@@ -811,31 +1077,10 @@ pub(crate) fn output_variant(
         }
 
         VariantDetails::Struct(props) => {
-            let prop_streams = props.iter().map(|prop| {
-                let prop_doc = prop.description.as_ref().map(|s| quote! { #[doc = #s] });
-
-                let prop_type_entry = type_space.id_to_entry.get(&prop.type_id).unwrap();
-                let (prop_serde, _) = generate_serde_attr(
-                    &format!("{}{}", type_name, variant.ident_name.as_ref().unwrap()),
-                    &prop.name,
-                    &prop.rename,
-                    &prop.state,
-                    prop_type_entry,
-                    type_space,
-                    output,
-                );
-
-                let prop_name = format_ident!("{}", prop.name);
-                let prop_type = prop_type_entry.type_ident(type_space, &None);
-
-                quote! {
-                    #prop_doc
-                    #prop_serde
-                    #prop_name: #prop_type,
-                }
-            });
+            let prop_streams = variant_properties(variant, props, type_space, output, type_name);
             quote! {
                 #doc
+                #deprecated
                 #serde
                 #variant_name {
                     #(#prop_streams)*
@@ -845,6 +1090,700 @@ pub(crate) fn output_variant(
     }
 }
 
+fn variant_properties(
+    variant: &Variant,
+    props: &[StructProperty],
+    type_space: &TypeSpace,
+    output: &mut OutputSpace,
+    type_name: &str,
+) -> Vec<TokenStream> {
+    props
+        .iter()
+        .map(|prop| {
+            let prop_doc = prop
+                .annotations
+                .doc(prop.description.as_deref())
+                .map(|s| quote! { #[doc = #s] });
+            let prop_deprecated = prop.annotations.deprecated_attr();
+
+            let prop_type_entry = type_space.id_to_entry.get(&prop.type_id).unwrap();
+            let (prop_serde, _) = generate_serde_attr(
+                &format!("{}{}", type_name, variant.ident_name.as_ref().unwrap()),
+                prop,
+                prop_type_entry,
+                type_space,
+                output,
+            );
+
+            let prop_name = format_ident!("{}", prop.name);
+            let prop_type = prop_type_entry.type_ident(type_space, &None);
+
+            quote! {
+                #prop_doc
+                #prop_deprecated
+                #prop_serde
+                #prop_name: #prop_type,
+            }
+        })
+        .collect()
+}
+
+/// Generate a `Deserialize` impl for an untagged enum that tries each variant
+/// in turn and, if none matches, reports why each of them failed.
+pub(crate) fn output_untagged_deserialize(
+    type_space: &TypeSpace,
+    enum_details: &TypeEntryEnum,
+) -> TokenStream {
+    let TypeEntryEnum {
+        name,
+        variants,
+        deny_unknown_fields,
+        ..
+    } = enum_details;
+    let type_name = format_ident!("{}", name);
+    let deny_unknown_fields = deny_unknown_fields.then(|| quote! { #[serde(deny_unknown_fields)] });
+
+    let attempts = variants.iter().map(|variant| {
+        let ident_name = variant.ident_name.as_ref().unwrap();
+        let variant_name = format_ident!("{}", ident_name);
+        let error_fmt = format!("{}: {{}}", ident_name);
+
+        let (decl, attempt) = match &variant.details {
+            VariantDetails::Simple => (
+                None,
+                quote! {
+                    <() as ::serde::Deserialize>::deserialize(&value).map(|()| Self::#variant_name)
+                },
+            ),
+            VariantDetails::Item(type_id) => {
+                let item_type_ident = type_space
+                    .id_to_entry
+                    .get(type_id)
+                    .unwrap()
+                    .type_ident(type_space, &None);
+                let attempt = quote! {
+                    <#item_type_ident as ::serde::Deserialize>::deserialize(&value)
+                        .map(Self::#variant_name)
+                };
+                (None, attempt)
+            }
+            VariantDetails::Tuple(tuple) => {
+                let types = tuple.iter().map(|type_id| {
+                    type_space
+                        .id_to_entry
+                        .get(type_id)
+                        .unwrap()
+                        .type_ident(type_space, &None)
+                });
+                let attempt = if tuple.len() != 1 {
+                    let ii = (0..tuple.len()).map(syn::Index::from);
+                    quote! {
+                        <(#(#types),*) as ::serde::Deserialize>::deserialize(&value)
+                            .map(|v| Self::#variant_name(#(v.#ii),*))
+                    }
+                } else {
+                    quote! {
+                        <(#(#types,)*) as ::serde::Deserialize>::deserialize(&value)
+                            .map(Self::#variant_name)
+                    }
+                };
+                (None, attempt)
+            }
+            VariantDetails::Struct(props) => {
+                // Any default functions were already emitted along with the
+                // enum itself.
+                let prop_streams = variant_properties(
+                    variant,
+                    props,
+                    type_space,
+                    &mut OutputSpace::default(),
+                    name,
+                );
+                let prop_names = props
+                    .iter()
+                    .map(|prop| format_ident!("{}", prop.name))
+                    .collect::<Vec<_>>();
+                let decl = quote! {
+                    #[derive(::serde::Deserialize)]
+                    #deny_unknown_fields
+                    struct __Variant {
+                        #(#prop_streams)*
+                    }
+                };
+                let attempt = quote! {
+                    <__Variant as ::serde::Deserialize>::deserialize(&value).map(|v| {
+                        Self::#variant_name {
+                            #(#prop_names: v.#prop_names,)*
+                        }
+                    })
+                };
+                (Some(decl), attempt)
+            }
+        };
+
+        let attempt = quote! {
+            match #attempt {
+                Ok(v) => return Ok(v),
+                Err(e) => errors.push(format!(#error_fmt, e)),
+            }
+        };
+
+        // The struct that describes a struct variant is scoped to its attempt.
+        match decl {
+            Some(decl) => quote! {
+                {
+                    #decl
+                    #attempt
+                }
+            },
+            None => attempt,
+        }
+    });
+
+    let error_fmt = format!(
+        "data did not match any variant of untagged enum {}: {{}}",
+        name
+    );
+
+    quote! {
+        impl<'de> ::serde::Deserialize<'de> for #type_name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let value =
+                    <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+                let mut errors = ::std::vec::Vec::new();
+                #(#attempts)*
+                Err(<D::Error as ::serde::de::Error>::custom(format!(
+                    #error_fmt,
+                    errors.join("; "),
+                )))
+            }
+        }
+    }
+}
+
+/// Generate `is_*`, `as_*`, and `into_*` methods, a `kind()` method and its
+/// fieldless enum, and `TryFrom<Enum>` impls for each of the `convertible`
+/// variants (i.e. those for which there's a `From<VariantType>` impl).
+pub(crate) fn output_accessors(
+    type_space: &TypeSpace,
+    enum_details: &TypeEntryEnum,
+    convertible: &[&Variant],
+) -> TokenStream {
+    let TypeEntryEnum {
+        name,
+        variants,
+        unknown_variant,
+        ..
+    } = enum_details;
+    let type_name = format_ident!("{}", name);
+
+    // A catch-all arm would be unreachable for an enum with one variant.
+    let has_others = variants.len() > 1 || unknown_variant.is_some();
+
+    let methods = variants.iter().map(|variant| {
+        let ident_name = variant.ident_name.as_ref().unwrap();
+        let variant_name = format_ident!("{}", ident_name);
+        let snake_name = ident_name.to_snake_case();
+        let is_name = format_ident!("is_{}", snake_name);
+        let deprecated = variant.annotations.deprecated_attr();
+
+        let is_doc = format!("Whether this is a [`{}::{}`].", name, ident_name);
+        let is_fn = quote! {
+            #[doc = #is_doc]
+            #deprecated
+            pub fn #is_name(&self) -> bool {
+                matches!(self, Self::#variant_name { .. })
+            }
+        };
+
+        // Single-item tuples are held as a tuple.
+        let types = match &variant.details {
+            VariantDetails::Item(type_id) => vec![type_id],
+            VariantDetails::Tuple(type_ids) if type_ids.len() > 1 => type_ids.iter().collect(),
+            VariantDetails::Tuple(type_ids) => {
+                let types = type_ids.iter().map(|type_id| {
+                    type_space
+                        .id_to_entry
+                        .get(type_id)
+                        .unwrap()
+                        .type_ident(type_space, &None)
+                });
+                return accessor_fns(
+                    name,
+                    ident_name,
+                    deprecated,
+                    is_fn,
+                    &[quote! { ( #( #types, )* ) }],
+                    None,
+                    has_others,
+                );
+            }
+            VariantDetails::Simple | VariantDetails::Struct(_) => return is_fn,
+        };
+        // Boxed data is referenced through the box.
+        if let [type_id] = types[..] {
+            let type_entry = type_space.id_to_entry.get(type_id).unwrap();
+            if let TypeEntryDetails::Box(
+                inner_id,
+                PointerType::Box | PointerType::Rc | PointerType::Arc,
+            ) = &type_entry.details
+            {
+                let inner_type = type_space
+                    .id_to_entry
+                    .get(inner_id)
+                    .unwrap()
+                    .type_ident(type_space, &None);
+                let type_ident = type_entry.type_ident(type_space, &None);
+                return accessor_fns(
+                    name,
+                    ident_name,
+                    deprecated,
+                    is_fn,
+                    &[type_ident],
+                    Some(inner_type),
+                    has_others,
+                );
+            }
+        }
+        let types = types
+            .into_iter()
+            .map(|type_id| {
+                type_space
+                    .id_to_entry
+                    .get(type_id)
+                    .unwrap()
+                    .type_ident(type_space, &None)
+            })
+            .collect::<Vec<_>>();
+        accessor_fns(
+            name, ident_name, deprecated, is_fn, &types, None, has_others,
+        )
+    });
+
+    let unknown_fns = unknown_variant.map(|unknown| {
+        let unknown_type = match unknown {
+            UnknownVariant::String => quote! { ::std::string::String },
+            UnknownVariant::Value => quote! { ::serde_json::Value },
+        };
+        let is_doc = format!("Whether this is a [`{}::Unknown`].", name);
+        let is_fn = quote! {
+            #[doc = #is_doc]
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+        };
+        accessor_fns(name, "Unknown", None, is_fn, &[unknown_type], None, true)
+    });
+
+    // A fieldless enum would be its own kind.
+    let kind = (unknown_variant.is_some()
+        || !variants
+            .iter()
+            .all(|variant| matches!(variant.details, VariantDetails::Simple)))
+    .then(|| {
+        let kind_name = format!("{}Kind", name);
+        if type_space.name_to_id.contains_key(&kind_name) {
+            info!("skipping kind for {}; {} already exists", name, kind_name);
+            return None;
+        }
+        let kind_ident = format_ident!("{}", kind_name);
+        let variant_names = variants
+            .iter()
+            .map(|variant| format_ident!("{}", variant.ident_name.as_ref().unwrap()))
+            .chain(unknown_variant.map(|_| format_ident!("Unknown")))
+            .collect::<Vec<_>>();
+        let kind_doc = format!("The kind of a [`{}`], without its data.", name);
+
+        Some(quote! {
+            #[doc = #kind_doc]
+            #[derive(
+                ::std::clone::Clone,
+                ::std::marker::Copy,
+                ::std::fmt::Debug,
+                ::std::cmp::PartialEq,
+                ::std::cmp::Eq,
+                ::std::hash::Hash,
+                ::std::cmp::PartialOrd,
+                ::std::cmp::Ord,
+            )]
+            pub enum #kind_ident {
+                #( #variant_names, )*
+            }
+
+            impl #type_name {
+                /// The kind of this value.
+                pub fn kind(&self) -> #kind_ident {
+                    match self {
+                        #( Self::#variant_names { .. } => #kind_ident::#variant_names, )*
+                    }
+                }
+            }
+        })
+    })
+    .flatten();
+
+    let try_from = convertible.iter().filter_map(|variant| {
+        let variant_name = format_ident!("{}", variant.ident_name.as_ref().unwrap());
+        let err_msg = format!("expected variant {}", variant.ident_name.as_ref().unwrap());
+        let catch_all = has_others.then(|| quote! { _ => Err(#err_msg.into()), });
+        match &variant.details {
+            VariantDetails::Item(type_id) => {
+                let variant_type = type_space.id_to_entry.get(type_id).unwrap();
+                // A `TryFrom` impl for `Box<Enum>` would conflict with the
+                // blanket impl via `From<T> for Box<T>` (as would one for the
+                // other pointer types).
+                if matches!(variant_type.details, TypeEntryDetails::Box(..)) {
+                    return None;
+                }
+                let variant_type_ident = variant_type.type_ident(type_space, &None);
+                Some(quote! {
+                    impl ::std::convert::TryFrom<#type_name> for #variant_type_ident {
+                        type Error = self::error::ConversionError;
+
+                        fn try_from(value: #type_name)
+                            -> ::std::result::Result<Self, self::error::ConversionError>
+                        {
+                            match value {
+                                #type_name::#variant_name(value) => Ok(value),
+                                #catch_all
+                            }
+                        }
+                    }
+                })
+            }
+            VariantDetails::Tuple(type_ids) if type_ids.len() > 1 => {
+                let variant_type_idents = type_ids.iter().map(|type_id| {
+                    type_space
+                        .id_to_entry
+                        .get(type_id)
+                        .unwrap()
+                        .type_ident(type_space, &None)
+                });
+                let field = (0..type_ids.len())
+                    .map(|ii| format_ident!("x{}", ii))
+                    .collect::<Vec<_>>();
+                Some(quote! {
+                    impl ::std::convert::TryFrom<#type_name> for ( #(#variant_type_idents),* ) {
+                        type Error = self::error::ConversionError;
+
+                        fn try_from(value: #type_name)
+                            -> ::std::result::Result<Self, self::error::ConversionError>
+                        {
+                            match value {
+                                #type_name::#variant_name( #(#field),* ) => Ok(( #(#field),* )),
+                                #catch_all
+                            }
+                        }
+                    }
+                })
+            }
+            _ => None,
+        }
+    });
+
+    quote! {
+        impl #type_name {
+            #( #methods )*
+            #unknown_fns
+        }
+
+        #kind
+        #( #try_from )*
+    }
+}
+
+/// The `as_*` and `into_*` methods for a variant with the given field types.
+/// If the variant holds a `Box`, `boxed` is its inner type, which `as_*`
+/// references.
+fn accessor_fns(
+    type_name: &str,
+    ident_name: &str,
+    deprecated: Option<TokenStream>,
+    is_fn: TokenStream,
+    types: &[TokenStream],
+    boxed: Option<TokenStream>,
+    has_others: bool,
+) -> TokenStream {
+    let variant_name = format_ident!("{}", ident_name);
+    let snake_name = ident_name.to_snake_case();
+    let as_name = format_ident!("as_{}", snake_name);
+    let into_name = format_ident!("into_{}", snake_name);
+    let as_doc = format!(
+        "A reference to the data of a [`{}::{}`], if this is one.",
+        type_name, ident_name,
+    );
+    let into_doc = format!(
+        "The data of a [`{}::{}`], if this is one.",
+        type_name, ident_name,
+    );
+
+    let (as_type, as_value, into_type, field, value) = if let [ty] = types {
+        let (as_type, as_value) = match boxed {
+            Some(inner) => (quote! { &#inner }, quote! { x.as_ref() }),
+            None => (quote! { &#ty }, quote! { x }),
+        };
+        (
+            as_type,
+            as_value,
+            quote! { #ty },
+            vec![format_ident!("x")],
+            quote! { x },
+        )
+    } else {
+        let field = (0..types.len())
+            .map(|ii| format_ident!("x{}", ii))
+            .collect::<Vec<_>>();
+        let value = quote! { ( #( #field ),* ) };
+        (
+            quote! { ( #( &#types ),* ) },
+            value.clone(),
+            quote! { ( #( #types ),* ) },
+            field,
+            value,
+        )
+    };
+    let catch_all = has_others.then(|| quote! { _ => None, });
+
+    quote! {
+        #is_fn
+
+        #[doc = #as_doc]
+        #deprecated
+        pub fn #as_name(&self) -> ::std::option::Option<#as_type> {
+            match self {
+                Self::#variant_name( #( #field ),* ) => Some(#as_value),
+                #catch_all
+            }
+        }
+
+        #[doc = #into_doc]
+        #deprecated
+        pub fn #into_name(self) -> ::std::option::Option<#into_type> {
+            match self {
+                Self::#variant_name( #( #field ),* ) => Some(#value),
+                #catch_all
+            }
+        }
+    }
+}
+
+/// The values that a variant of an untagged enum accepts, as far as we can
+/// tell.
+#[derive(Debug, PartialEq)]
+enum VariantShape {
+    /// Any value at all.
+    Any,
+    /// Only `null`.
+    Null,
+    /// An object with the given properties, keyed by their serialized names
+    /// with their types and whether they're required; if `closed`, no other
+    /// properties are permitted.
+    Object {
+        properties: BTreeMap<String, (TypeId, bool)>,
+        closed: bool,
+    },
+    /// The values of a particular type.
+    Type(TypeId),
+    /// Something we don't attempt to analyze.
+    Unknown,
+}
+
+impl VariantShape {
+    fn new(type_space: &TypeSpace, variant: &Variant, deny_unknown_fields: bool) -> Self {
+        match &variant.details {
+            VariantDetails::Simple => Self::Null,
+            VariantDetails::Struct(props) => {
+                Self::object(type_space, props, deny_unknown_fields).unwrap_or(Self::Unknown)
+            }
+            VariantDetails::Item(type_id) => {
+                let type_id = resolve_type_id(type_space, type_id);
+                match &type_space.id_to_entry.get(&type_id).unwrap().details {
+                    TypeEntryDetails::JsonValue => Self::Any,
+                    TypeEntryDetails::Struct(TypeEntryStruct {
+                        properties,
+                        deny_unknown_fields,
+                        ..
+                    }) => Self::object(type_space, properties, *deny_unknown_fields)
+                        .unwrap_or(Self::Unknown),
+                    TypeEntryDetails::Option(_) => Self::Unknown,
+                    _ => Self::Type(type_id),
+                }
+            }
+            VariantDetails::Tuple(_) => Self::Unknown,
+        }
+    }
+
+    fn object(type_space: &TypeSpace, props: &[StructProperty], closed: bool) -> Option<Self> {
+        let properties = props
+            .iter()
+            .map(|prop| {
+                let name = match &prop.rename {
+                    StructPropertyRename::None => prop.name.clone(),
+                    StructPropertyRename::Rename(rename) => rename.clone(),
+                    // Flattened properties may accept anything.
+                    StructPropertyRename::Flatten => return None,
+                };
+                let required = matches!(prop.state, StructPropertyState::Required);
+                // Compare the types of optional properties by the types they
+                // hold.
+                let mut type_id = resolve_type_id(type_space, &prop.type_id);
+                if !required {
+                    if let TypeEntryDetails::Option(inner_id) =
+                        &type_space.id_to_entry.get(&type_id).unwrap().details
+                    {
+                        type_id = resolve_type_id(type_space, inner_id);
+                    }
+                }
+                Some((name, (type_id, required)))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self::Object { properties, closed })
+    }
+
+    /// Whether every value that `other` accepts is also accepted by `self`
+    /// (so that, if `self` is tried first, `other` never matches).
+    fn shadows(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Any, Self::Unknown) => false,
+            (Self::Any, _) => true,
+            (Self::Null, Self::Null) => true,
+            (Self::Type(a), Self::Type(b)) => a == b,
+            (
+                Self::Object {
+                    properties: a_props,
+                    closed: a_closed,
+                },
+                Self::Object {
+                    properties: b_props,
+                    closed: b_closed,
+                },
+            ) => {
+                let props_accepted = a_props.iter().all(|(name, (a_type, a_required))| {
+                    match b_props.get(name) {
+                        // A property of both must have the same type, and the
+                        // other must require it if we do.
+                        Some((b_type, b_required)) => {
+                            a_type == b_type && (!a_required || *b_required)
+                        }
+                        // The other's values can't hold this property only if
+                        // they may not have unknown properties.
+                        None => !a_required && *b_closed,
+                    }
+                });
+                let unknown_accepted =
+                    !a_closed || (*b_closed && b_props.keys().all(|k| a_props.contains_key(k)));
+                props_accepted && unknown_accepted
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether there may be some value that both `self` and `other` accept.
+    fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unknown, _) | (_, Self::Unknown) => false,
+            (Self::Any, _) | (_, Self::Any) => true,
+            (
+                Self::Object {
+                    properties: a_props,
+                    closed: a_closed,
+                },
+                Self::Object {
+                    properties: b_props,
+                    closed: b_closed,
+                },
+            ) => {
+                object_permits(a_props, *a_closed, b_props)
+                    && object_permits(b_props, *b_closed, a_props)
+            }
+            (a, b) => a.shadows(b) || b.shadows(a),
+        }
+    }
+}
+
+/// Whether an object may hold the required properties of another, with the
+/// same types for properties that either requires.
+fn object_permits(
+    props: &BTreeMap<String, (TypeId, bool)>,
+    closed: bool,
+    other_props: &BTreeMap<String, (TypeId, bool)>,
+) -> bool {
+    other_props.iter().all(
+        |(name, (other_type, other_required))| match props.get(name) {
+            Some((this_type, this_required)) => {
+                this_type == other_type || !(*this_required || *other_required)
+            }
+            None => !other_required || !closed,
+        },
+    )
+}
+
+fn resolve_type_id(type_space: &TypeSpace, type_id: &TypeId) -> TypeId {
+    match &type_space.id_to_entry.get(type_id).unwrap().details {
+        TypeEntryDetails::Reference(inner_id) | TypeEntryDetails::Box(inner_id, _) => {
+            resolve_type_id(type_space, inner_id)
+        }
+        _ => type_id.clone(),
+    }
+}
+
+/// Serde tries the variants of an untagged enum in order and picks the first
+/// that matches, so a variant that accepts all the values of a later one
+/// shadows it. If reordering was requested, where a later variant is strictly
+/// more specific than an earlier one, we move it ahead; we then report
+/// variants that remain shadowed or that overlap with others.
+pub(crate) fn order_untagged_variants(
+    type_space: &TypeSpace,
+    type_name: &str,
+    variants: &mut Vec<Variant>,
+    deny_unknown_fields: bool,
+) {
+    let mut ordered = Vec::<(Variant, VariantShape)>::with_capacity(variants.len());
+    for variant in variants.drain(..) {
+        let shape = VariantShape::new(type_space, &variant, deny_unknown_fields);
+        let position = ordered
+            .iter()
+            .position(|(_, other_shape)| other_shape.shadows(&shape) && !shape.shadows(other_shape))
+            .filter(|_| type_space.settings.reorder_untagged_variants);
+        match position {
+            Some(position) => {
+                info!(
+                    "{}: moving variant {} ahead of {}, which would otherwise shadow it",
+                    type_name,
+                    variant.ident_name.as_ref().unwrap(),
+                    ordered[position].0.ident_name.as_ref().unwrap(),
+                );
+                ordered.insert(position, (variant, shape));
+            }
+            None => ordered.push((variant, shape)),
+        }
+    }
+
+    for (ii, (earlier, earlier_shape)) in ordered.iter().enumerate() {
+        for (later, later_shape) in &ordered[ii + 1..] {
+            let earlier_name = earlier.ident_name.as_ref().unwrap();
+            let later_name = later.ident_name.as_ref().unwrap();
+            if earlier_shape.shadows(later_shape) {
+                warn!(
+                    "{}: variant {} can never be deserialized; {} accepts all of its values",
+                    type_name, later_name, earlier_name,
+                );
+            } else if earlier_shape.overlaps(later_shape) {
+                info!(
+                    "{}: variants {} and {} may both match some values; {} is tried first",
+                    type_name, earlier_name, later_name, earlier_name,
+                );
+            }
+        }
+    }
+
+    variants.extend(ordered.into_iter().map(|(variant, _)| variant));
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -860,7 +1799,7 @@ mod tests {
     use crate::{
         output::OutputSpace,
         test_util::{validate_output, validate_output_for_untagged_enm},
-        type_entry::{EnumTagType, TypeEntryEnum, Variant, VariantDetails},
+        type_entry::{EnumRepr, EnumTagType, TypeEntryEnum, Variant, VariantDetails},
         Name, RefKey, TypeEntryDetails, TypeId, TypeSpace, TypeSpaceSettings,
     };
 
@@ -1055,6 +1994,8 @@ mod tests {
                 variants,
                 deny_unknown_fields: _,
                 bespoke_impls: _,
+                unknown_variant: None,
+                repr: EnumRepr::Derived,
                 schema: _,
             }) => {
                 assert_eq!(name, "UntaggedEnum");
@@ -1334,10 +2275,7 @@ mod tests {
 
         match &type_entry.details {
             TypeEntryDetails::Enum(TypeEntryEnum {
-                tag_type,
-                variants,
-                deny_unknown_fields: _,
-                ..
+                tag_type, variants, ..
             }) => {
                 assert_eq!(tag_type, &EnumTagType::Untagged);
                 //assert_eq!(deny_unknown_fields, &true);
@@ -1472,7 +2410,7 @@ mod tests {
             )
             .unwrap();
         let mut output = OutputSpace::default();
-        type_entry.output(&type_space, &mut output);
+        type_entry.output(&type_space, &mut output, None);
         let actual = output.into_stream();
         let expected = quote! {
             #[doc = "`ResultX`"]
@@ -1511,7 +2449,7 @@ mod tests {
             )
             .unwrap();
         let mut output = OutputSpace::default();
-        type_entry.output(&type_space, &mut output);
+        type_entry.output(&type_space, &mut output, None);
         let actual = output.into_stream();
         let expected = quote! {
             #[doc = "`ResultX`"]
@@ -1541,4 +2479,68 @@ mod tests {
 
         validate_output::<Hobsons>();
     }
+
+    #[test]
+    fn test_untagged_variant_order() {
+        let schema_json = r##"
+        {
+            "definitions": {
+                "base": {
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string" }
+                    }
+                },
+                "detailed": {
+                    "type": "object",
+                    "required": ["name", "size"],
+                    "properties": {
+                        "name": { "type": "string" },
+                        "size": { "type": "integer" }
+                    }
+                },
+                "item": {
+                    "oneOf": [
+                        { "$ref": "#/definitions/base" },
+                        { "type": "string" },
+                        { "$ref": "#/definitions/detailed" }
+                    ]
+                }
+            }
+        }
+        "##;
+
+        let variant_names = |settings: &TypeSpaceSettings| {
+            let schema: RootSchema = serde_json::from_str(schema_json).unwrap();
+
+            let mut type_space = TypeSpace::new(settings);
+            type_space.add_ref_types(schema.definitions).unwrap();
+
+            let type_id = type_space
+                .ref_to_id
+                .get(&RefKey::Def("item".to_string()))
+                .unwrap();
+            match &type_space.id_to_entry.get(type_id).unwrap().details {
+                TypeEntryDetails::Enum(TypeEntryEnum { variants, .. }) => variants
+                    .iter()
+                    .map(|variant| variant.ident_name.clone().unwrap())
+                    .collect::<Vec<_>>(),
+                details => panic!("{:#?}", details),
+            }
+        };
+
+        // By default the variants keep the order of the schema.
+        assert_eq!(
+            variant_names(&TypeSpaceSettings::default()),
+            ["Base", "String", "Detailed"]
+        );
+
+        // Every "detailed" value would also deserialize as a "base", so the
+        // former must be tried first.
+        assert_eq!(
+            variant_names(TypeSpaceSettings::default().with_reorder_untagged_variants(true)),
+            ["Detailed", "Base", "String"]
+        );
+    }
 }
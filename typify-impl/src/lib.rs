@@ -4,7 +4,10 @@
 
 #![deny(missing_docs)]
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
 
 use conversions::SchemaCache;
 use log::{debug, info};
@@ -18,25 +21,30 @@ use type_entry::{
     WrappedValue,
 };
 
+use crate::naming::{NameFrame, TypeNamerImpl};
 use crate::util::{sanitize, Case};
 
+pub use crate::naming::{TypeNameContext, TypeNamer, TypeNaming};
 pub use crate::util::accept_as_ident;
 
 #[cfg(test)]
 mod test_util;
 
+mod arbitrary;
 mod conversions;
 mod convert;
 mod cycles;
 mod defaults;
 mod enums;
 mod merge;
+mod naming;
 mod output;
 mod rust_extension;
 mod structs;
 mod type_entry;
 mod util;
 mod validate;
+mod validation;
 mod value;
 
 #[allow(missing_docs)]
@@ -86,6 +94,7 @@ pub enum TypeDetails<'a> {
     Vec(TypeId),
     Map(TypeId, TypeId),
     Set(TypeId),
+    /// A pointer to the given type; see [Type::pointer_type] for which kind.
     Box(TypeId),
     Tuple(Box<dyn Iterator<Item = TypeId> + 'a>),
     Array(TypeId, usize),
@@ -194,6 +203,10 @@ pub struct TypeSpace {
     name_to_id: BTreeMap<String, TypeId>,
     ref_to_id: BTreeMap<RefKey, TypeId>,
 
+    // The inner Options of `Option<Option<T>>` properties; see
+    // [TypeSpaceSettings::with_double_option].
+    double_options: BTreeSet<TypeId>,
+
     uses_chrono: bool,
     uses_uuid: bool,
     uses_serde_json: bool,
@@ -205,6 +218,12 @@ pub struct TypeSpace {
 
     // Shared functions for generating default values
     defaults: BTreeSet<DefaultImpl>,
+
+    // Path to the schema being converted, for naming its type
+    name_frames: Vec<NameFrame>,
+
+    // Names from the type namer and the suggested names they replaced
+    namer_names: RefCell<BTreeMap<String, String>>,
 }
 
 impl Default for TypeSpace {
@@ -214,6 +233,7 @@ impl Default for TypeSpace {
             definitions: Default::default(),
             id_to_entry: Default::default(),
             type_to_id: Default::default(),
+            double_options: Default::default(),
             name_to_id: Default::default(),
             ref_to_id: Default::default(),
             uses_chrono: Default::default(),
@@ -223,6 +243,8 @@ impl Default for TypeSpace {
             settings: Default::default(),
             cache: Default::default(),
             defaults: Default::default(),
+            name_frames: Default::default(),
+            namer_names: Default::default(),
         }
     }
 }
@@ -326,16 +348,131 @@ pub struct TypeSpaceSettings {
     extra_derives: Vec<String>,
     extra_attrs: Vec<String>,
     struct_builder: bool,
+    struct_builder_style: StructBuilderStyle,
+    structured_builder_errors: bool,
+    struct_constructor: bool,
+    enum_accessors: bool,
+    unknown_variants: bool,
+    integer_enums: bool,
+    preserve_unknown_properties: bool,
+    validation_methods: bool,
+    detailed_untagged_errors: bool,
+    reorder_untagged_variants: bool,
+    forbidden_property_checks: bool,
+    proptest_arbitrary: bool,
+    doc_examples: bool,
+    example_tests: bool,
+    skip_read_write_only: bool,
+    request_response_types: bool,
 
     unknown_crates: UnknownPolicy,
     crates: BTreeMap<String, CrateSpec>,
     map_type: MapType,
+    property_order: PropertyOrder,
+    double_option: bool,
+    base_conversions: bool,
+    base_traits: bool,
+    any_of_strategy: AnyOfStrategy,
+    pointer_type: PointerType,
+    type_namer: Option<TypeNamerImpl>,
 
     patch: BTreeMap<String, TypeSpacePatch>,
     replace: BTreeMap<String, TypeSpaceReplace>,
     convert: Vec<TypeSpaceConversion>,
 }
 
+/// Order of the properties of generated structs, and therefore of their
+/// serialized form.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PropertyOrder {
+    /// Properties are sorted by name.
+    #[default]
+    Alphabetical,
+    /// Properties appear in the order in which the schema lists them. This
+    /// requires the `preserve_order` feature (which enables that of
+    /// `schemars`); without it, schemas hold their properties sorted by name.
+    Schema,
+}
+
+/// Representation of an `anyOf` whose subschemas are not mutually exclusive,
+/// i.e. where a value may match several of them at once.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AnyOfStrategy {
+    /// A struct with an optional, flattened member for each subschema.
+    #[default]
+    Flatten,
+    /// An untagged enum whose variants are ordered from the most to the least
+    /// specific subschema (by the number of required and then of total
+    /// properties) so that deserialization prefers the closest match.
+    UntaggedEnum,
+    /// A single struct whose properties are the union of the properties of
+    /// all subschemas, each of them optional. Falls back to
+    /// [AnyOfStrategy::Flatten] if any subschema is not an object.
+    MergedStruct,
+}
+
+/// Style of the builder types generated for structs.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StructBuilderStyle {
+    /// Each builder field holds a `Result`; a missing required property is
+    /// reported by the `TryFrom` conversion into the built type.
+    #[default]
+    Fallible,
+    /// The builder's type parameters track which required properties have
+    /// been set; `build()` is only available once all are, and is
+    /// infallible.
+    Typestate,
+}
+
+/// Pointer type used to break containment cycles, i.e. the indirection that
+/// lets a type such as a tree node contain itself.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PointerType {
+    /// `::std::boxed::Box`.
+    #[default]
+    Box,
+    /// `::std::rc::Rc`. Note that serializing and deserializing an `Rc`
+    /// requires the `rc` feature of `serde`.
+    Rc,
+    /// `::std::sync::Arc`. Note that serializing and deserializing an `Arc`
+    /// requires the `rc` feature of `serde`.
+    Arc,
+    /// The path of a generic type, e.g. `::my_crate::Ptr`. It must provide a
+    /// `new` associated function and implement `Deref` as well as the traits
+    /// derived for the generated types (including `Serialize` and
+    /// `Deserialize`). Conversion fails with [Error::InvalidSchema] if the
+    /// path is needed to break a cycle but isn't valid.
+    Custom(String),
+}
+
+impl PointerType {
+    /// Check that a custom path is valid before using it to break a cycle in
+    /// the named type.
+    fn validate(&self, type_name: Option<&String>) -> Result<()> {
+        match self {
+            PointerType::Custom(path) if syn::parse_str::<syn::Path>(path).is_err() => {
+                Err(Error::InvalidSchema {
+                    type_name: type_name.cloned(),
+                    reason: format!("invalid pointer type {:?}", path),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Custom paths are validated before we use them to break cycles.
+    fn path(&self) -> TokenStream {
+        match self {
+            PointerType::Box => quote! { ::std::boxed::Box },
+            PointerType::Rc => quote! { ::std::rc::Rc },
+            PointerType::Arc => quote! { ::std::sync::Arc },
+            PointerType::Custom(path) => syn::parse_str::<syn::Path>(path)
+                .expect("validated pointer type")
+                .to_token_stream(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct CrateSpec {
     version: CrateVers,
@@ -394,6 +531,10 @@ pub struct TypeSpacePatch {
     rename: Option<String>,
     derives: Vec<String>,
     attrs: Vec<String>,
+    unknown_variant: Option<bool>,
+    preserve_unknown_properties: Option<bool>,
+    any_of_strategy: Option<AnyOfStrategy>,
+    pointer_types: BTreeMap<String, PointerType>,
 }
 
 /// Contains the attributes of a replacement of an existing type.
@@ -466,6 +607,167 @@ impl TypeSpaceSettings {
         self
     }
 
+    /// Select the style of builder generated when
+    /// [`Self::with_struct_builder`] is enabled.
+    pub fn with_struct_builder_style(&mut self, style: StructBuilderStyle) -> &mut Self {
+        self.struct_builder_style = style;
+        self
+    }
+
+    /// Have builders report the failures of all properties, including those
+    /// of nested builders, rather than only the first. `error::ConversionError`
+    /// then holds the path (e.g. `spec.containers[0].image`, using the
+    /// properties' names in the schema) and message of each failure, and
+    /// builders of array properties get `<property>_items` setters that
+    /// convert each item. Values passed to the setters must have errors that
+    /// are `'static`. This applies to [StructBuilderStyle::Fallible] builders.
+    pub fn with_structured_builder_errors(&mut self, structured_builder_errors: bool) -> &mut Self {
+        self.structured_builder_errors = structured_builder_errors;
+        self
+    }
+
+    /// For structs, include a `new()` constructor that takes the required
+    /// properties (if there are any) and sets the others to their default
+    /// values, along with
+    /// chainable `with_<field>` setters for the non-required properties.
+    pub fn with_struct_constructor(&mut self, struct_constructor: bool) -> &mut Self {
+        self.struct_constructor = struct_constructor;
+        self
+    }
+
+    /// For enums, include `is_<variant>()` predicates, `as_<variant>()` and
+    /// `into_<variant>()` accessors for variants with data, and a `kind()`
+    /// method that returns a fieldless `<name>Kind` enum. Also emit
+    /// `TryFrom<Enum>` for the types of variants that have a corresponding
+    /// `From` impl.
+    pub fn with_enum_accessors(&mut self, enum_accessors: bool) -> &mut Self {
+        self.enum_accessors = enum_accessors;
+        self
+    }
+
+    /// Mark string and tagged enums `#[non_exhaustive]` and add an `Unknown`
+    /// variant to hold values not described by the schema so that they can
+    /// be deserialized and round-tripped. Enums of simple strings hold the
+    /// raw string; tagged enums hold a `serde_json::Value` (which requires
+    /// serde 1.0.181 or later).
+    pub fn with_unknown_variants(&mut self, unknown_variants: bool) -> &mut Self {
+        self.unknown_variants = unknown_variants;
+        self
+    }
+
+    /// Generate a fieldless `#[repr(i64)]` enum for an integer schema with
+    /// enumerated values rather than a newtype that checks its value. Variant
+    /// names come from an `x-enum-varnames` or `x-enumNames` extension if
+    /// present, and are otherwise derived from the values (e.g. `Value1`).
+    pub fn with_integer_enums(&mut self, integer_enums: bool) -> &mut Self {
+        self.integer_enums = integer_enums;
+        self
+    }
+
+    /// Add a flattened `extra` map to structs whose schema permits
+    /// additional properties without describing them (i.e. when
+    /// `additionalProperties` is absent or `true`) so that properties not
+    /// described by the schema survive deserialization and serialization.
+    /// The map is of the type set with [Self::with_map_type]; it is
+    /// skipped when empty and each such struct gets
+    /// `extra_property` and `set_extra_property` methods.
+    pub fn with_preserve_unknown_properties(
+        &mut self,
+        preserve_unknown_properties: bool,
+    ) -> &mut Self {
+        self.preserve_unknown_properties = preserve_unknown_properties;
+        self
+    }
+
+    /// Generate a `validate()` method for each type that checks constraints
+    /// that aren't enforced by deserialization such as `minProperties`,
+    /// `dependentRequired`, `uniqueItems`, and `contains`. The generated
+    /// code may require the `serde_json` crate (see
+    /// [TypeSpace::uses_serde_json]). `not` constraints and schema
+    /// dependencies are not checked; they are logged as warnings.
+    pub fn with_validation_methods(&mut self, validation_methods: bool) -> &mut Self {
+        self.validation_methods = validation_methods;
+        self
+    }
+
+    /// Serde tries the variants of an untagged enum in order, so a variant
+    /// that accepts all the values of a later one shadows it. Move variants
+    /// ahead of the ones that would otherwise shadow them. Note that this
+    /// changes the order of variants and therefore any derived `PartialOrd`
+    /// and `Ord`. Either way, variants that remain shadowed are reported as
+    /// warnings.
+    pub fn with_reorder_untagged_variants(&mut self, reorder_untagged_variants: bool) -> &mut Self {
+        self.reorder_untagged_variants = reorder_untagged_variants;
+        self
+    }
+
+    /// Properties whose schema is `false` get no field in the generated
+    /// struct and are therefore ignored by deserialization. Instead generate
+    /// a `Deserialize` impl for such structs that fails if any of those
+    /// properties is present. The generated code buffers the input as a
+    /// `serde_json::Value` so it requires the `serde_json` crate and a
+    /// self-describing format.
+    pub fn with_forbidden_property_checks(&mut self, forbidden_property_checks: bool) -> &mut Self {
+        self.forbidden_property_checks = forbidden_property_checks;
+        self
+    }
+
+    /// Generate a `Deserialize` impl for untagged enums that tries each
+    /// variant in order and, if none matches, reports each variant's error
+    /// rather than serde's generic message. The generated code requires the
+    /// `serde_json` crate.
+    pub fn with_detailed_untagged_errors(&mut self, detailed_untagged_errors: bool) -> &mut Self {
+        self.detailed_untagged_errors = detailed_untagged_errors;
+        self
+    }
+
+    /// Implement `proptest::arbitrary::Arbitrary` for each type, producing
+    /// values that respect the schema's constraints such as string patterns
+    /// and lengths, numeric bounds, and enumerated values. The generated code
+    /// requires the `proptest` and `serde_json` crates.
+    pub fn with_proptest_arbitrary(&mut self, proptest_arbitrary: bool) -> &mut Self {
+        self.proptest_arbitrary = proptest_arbitrary;
+        self
+    }
+
+    /// Include the schema's `examples` for each type as JSON code blocks in
+    /// its documentation.
+    pub fn with_doc_examples(&mut self, doc_examples: bool) -> &mut Self {
+        self.doc_examples = doc_examples;
+        self
+    }
+
+    /// Generate a `#[cfg(test)] mod typify_examples` with a test for each
+    /// type that has `examples` in its schema; the test checks that each
+    /// example deserializes into the type and serializes back to the same
+    /// JSON value. The generated tests require the `serde_json` crate.
+    pub fn with_example_tests(&mut self, example_tests: bool) -> &mut Self {
+        self.example_tests = example_tests;
+        self
+    }
+
+    /// Omit `readOnly` properties when serializing and ignore `writeOnly`
+    /// properties when deserializing, so that the same types can model both
+    /// request and response bodies. A `writeOnly` property is only skipped if
+    /// its type has a default value.
+    pub fn with_skip_read_write_only(&mut self, skip_read_write_only: bool) -> &mut Self {
+        self.skip_read_write_only = skip_read_write_only;
+        self
+    }
+
+    /// For each struct with `readOnly` or `writeOnly` properties, also
+    /// generate `{Name}Request` without the `readOnly` properties and
+    /// `{Name}Response` without the `writeOnly` properties, with conversions
+    /// from the full type. Conversions to the full type are generated when
+    /// the omitted properties have defaults, and via the builder (if enabled)
+    /// otherwise. The split is shallow: properties whose types have their own
+    /// `readOnly` or `writeOnly` properties keep the full type rather than
+    /// its `Request` or `Response` type.
+    pub fn with_request_response_types(&mut self, request_response_types: bool) -> &mut Self {
+        self.request_response_types = request_response_types;
+        self
+    }
+
     /// Replace a referenced type with a named type. This causes the referenced
     /// type *not* to be generated. If the same `type_name` is specified multiple times,
     /// the last one is honored.
@@ -587,6 +889,66 @@ impl TypeSpaceSettings {
         self.map_type = map_type.into();
         self
     }
+
+    /// Specify the order of the properties of generated structs. The default
+    /// is [PropertyOrder::Alphabetical].
+    pub fn with_property_order(&mut self, property_order: PropertyOrder) -> &mut Self {
+        self.property_order = property_order;
+        self
+    }
+
+    /// Represent an optional property whose schema permits `null` as an
+    /// `Option<Option<T>>` so that an absent property (`None`) can be told
+    /// apart from a `null` one (`Some(None)`), e.g. for PATCH-style requests.
+    /// By default both are `None`.
+    pub fn with_double_option(&mut self, double_option: bool) -> &mut Self {
+        self.double_option = double_option;
+        self
+    }
+
+    /// For a struct that is included by others through `allOf` subschemas
+    /// that reference it, generate conversions from each of those structs to
+    /// it and, if they have no other properties, from it to each of them;
+    /// the latter are fallible if they require properties that it doesn't.
+    pub fn with_base_conversions(&mut self, base_conversions: bool) -> &mut Self {
+        self.base_conversions = base_conversions;
+        self
+    }
+
+    /// For a struct that is included by others through `allOf` subschemas
+    /// that reference it, generate a `{Name}Fields` trait with an accessor
+    /// for each of its properties, implemented by it and by those structs.
+    pub fn with_base_traits(&mut self, base_traits: bool) -> &mut Self {
+        self.base_traits = base_traits;
+        self
+    }
+
+    /// Select how an `anyOf` whose subschemas are not mutually exclusive is
+    /// represented; see [AnyOfStrategy].
+    pub fn with_any_of_strategy(&mut self, any_of_strategy: AnyOfStrategy) -> &mut Self {
+        self.any_of_strategy = any_of_strategy;
+        self
+    }
+
+    /// Select the pointer type used to break containment cycles; the default
+    /// is [PointerType::Box]. Cycles are broken at as few fields as possible,
+    /// favoring optional fields and those that refer back to an enclosing
+    /// type; fields whose types are already behind a `Vec` or map are never
+    /// part of a containment cycle.
+    pub fn with_pointer_type(&mut self, pointer_type: PointerType) -> &mut Self {
+        self.pointer_type = pointer_type;
+        self
+    }
+
+    /// Set the strategy for naming the types of inline schemas, i.e. those
+    /// whose names would otherwise be derived from those of the types that
+    /// contain them; see [TypeNamer] and its built-in strategies in
+    /// [TypeNaming]. The names of definitions, and those set explicitly, are
+    /// not affected.
+    pub fn with_type_namer<T: TypeNamer + 'static>(&mut self, type_namer: T) -> &mut Self {
+        self.type_namer = Some(TypeNamerImpl(std::sync::Arc::new(type_namer)));
+        self
+    }
 }
 
 impl TypeSpacePatch {
@@ -607,6 +969,47 @@ impl TypeSpacePatch {
         self.attrs.push(attr.to_string());
         self
     }
+
+    /// Specify whether the patched type, if it's an enum, should have an
+    /// `Unknown` variant; this overrides
+    /// [TypeSpaceSettings::with_unknown_variants].
+    pub fn with_unknown_variant(&mut self, unknown_variant: bool) -> &mut Self {
+        self.unknown_variant = Some(unknown_variant);
+        self
+    }
+
+    /// Specify whether the patched type, if it's a struct, should preserve
+    /// properties not described by the schema; this overrides
+    /// [TypeSpaceSettings::with_preserve_unknown_properties].
+    pub fn with_preserve_unknown_properties(
+        &mut self,
+        preserve_unknown_properties: bool,
+    ) -> &mut Self {
+        self.preserve_unknown_properties = Some(preserve_unknown_properties);
+        self
+    }
+
+    /// Specify how the patched type, if it's a non-exclusive `anyOf`, is
+    /// represented; this overrides [TypeSpaceSettings::with_any_of_strategy].
+    pub fn with_any_of_strategy(&mut self, any_of_strategy: AnyOfStrategy) -> &mut Self {
+        self.any_of_strategy = Some(any_of_strategy);
+        self
+    }
+
+    /// Specify the pointer type for the given property of the patched type,
+    /// if it's a struct, should the property break a containment cycle; this
+    /// overrides [TypeSpaceSettings::with_pointer_type]. Properties with an
+    /// explicit pointer type are preferred as the points at which cycles are
+    /// broken.
+    pub fn with_property_pointer_type<S: ToString>(
+        &mut self,
+        property: S,
+        pointer_type: PointerType,
+    ) -> &mut Self {
+        self.pointer_types
+            .insert(property.to_string(), pointer_type);
+        self
+    }
 }
 
 impl TypeSpace {
@@ -696,12 +1099,17 @@ impl TypeSpace {
 
             match maybe_replace {
                 None => {
-                    let type_name = if let RefKey::Def(name) = ref_name {
-                        Name::Required(name.clone())
+                    let (type_name, frame) = if let RefKey::Def(name) = ref_name {
+                        (
+                            Name::Required(name.clone()),
+                            NameFrame::new(&["definitions", &name], None),
+                        )
                     } else {
-                        Name::Unknown
+                        (Name::Unknown, NameFrame::new::<&str>(&[], None))
                     };
-                    self.convert_ref_type(type_name, schema, type_id)?
+                    self.in_subschema(frame, |type_space| {
+                        type_space.convert_ref_type(type_name, schema, type_id)
+                    })?
                 }
 
                 Some(replace_type) => {
@@ -716,13 +1124,13 @@ impl TypeSpace {
 
         // Eliminate cycles. It's sufficient to only start from referenced
         // types as a reference is required to make a cycle.
-        self.break_cycles(base_id..base_id + def_len);
+        self.break_cycles(base_id..base_id + def_len)?;
 
         // Finalize all created types.
         for index in base_id..self.next_id {
             let type_id = TypeId(index);
             let mut type_entry = self.id_to_entry.get(&type_id).unwrap().clone();
-            debug!("finalizing type entry: {} {:#?}", index, &type_entry);
+            debug!("finalizing type entry: {} {:#?}", index, type_entry);
             type_entry.finalize(self)?;
             self.id_to_entry.insert(type_id, type_entry);
         }
@@ -907,49 +1315,51 @@ impl TypeSpace {
         output.add_item(
             output::OutputSpaceMod::Error,
             "",
-            quote! {
-                /// Error from a `TryFrom` or `FromStr` implementation.
-                pub struct ConversionError(::std::borrow::Cow<'static, str>);
-
-                impl ::std::error::Error for ConversionError {}
-                impl ::std::fmt::Display for ConversionError {
-                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
-                        -> Result<(), ::std::fmt::Error>
-                    {
-                        ::std::fmt::Display::fmt(&self.0, f)
-                    }
-                }
-
-                impl ::std::fmt::Debug for ConversionError {
-                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
-                        -> Result<(), ::std::fmt::Error>
-                    {
-                        ::std::fmt::Debug::fmt(&self.0, f)
-                    }
-                }
-                impl From<&'static str> for ConversionError {
-                    fn from(value: &'static str) -> Self {
-                        Self(value.into())
-                    }
-                }
-                impl From<String> for ConversionError {
-                    fn from(value: String) -> Self {
-                        Self(value.into())
-                    }
-                }
-            },
+            conversion_error(
+                self.settings.struct_builder && self.settings.structured_builder_errors,
+            ),
         );
 
+        if self.settings.validation_methods {
+            output.add_item(
+                output::OutputSpaceMod::Error,
+                "",
+                validation::validation_errors(),
+            );
+        }
+
         // Add all types.
         self.id_to_entry
-            .values()
-            .for_each(|type_entry| type_entry.output(self, &mut output));
+            .iter()
+            .for_each(|(type_id, type_entry)| type_entry.output(self, &mut output, Some(type_id)));
 
         // Add all shared default functions.
         self.defaults
             .iter()
             .for_each(|x| output.add_item(output::OutputSpaceMod::Defaults, "", x.into()));
 
+        // Serde treats a null as the absence of an outer Option; this keeps
+        // it as `Some(None)` for properties that are `Option<Option<T>>`.
+        if self.settings.double_option {
+            output.add_item(
+                output::OutputSpaceMod::Serde,
+                "",
+                quote! {
+                    /// Deserialize a property that is present (though
+                    /// possibly `null`) as `Some`.
+                    pub fn deserialize_some<'de, D, T>(
+                        deserializer: D,
+                    ) -> ::std::result::Result<::std::option::Option<T>, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                        T: ::serde::Deserialize<'de>,
+                    {
+                        T::deserialize(deserializer).map(::std::option::Option::Some)
+                    }
+                },
+            );
+        }
+
         output.into_stream()
     }
 
@@ -1034,14 +1444,26 @@ impl TypeSpace {
         self.assign_type(TypeEntryDetails::Option(id.clone()).into())
     }
 
+    /// Create the inner Option<T> of an `Option<Option<T>>` property. Each
+    /// gets its own ID so that other nested Options, which we flatten, don't
+    /// share it.
+    fn id_to_double_option_inner(&mut self, id: &TypeId) -> TypeId {
+        let type_id = self.assign();
+        self.id_to_entry
+            .insert(type_id.clone(), TypeEntryDetails::Option(id.clone()).into());
+        self.double_options.insert(type_id.clone());
+        type_id
+    }
+
     // Create an Option<T> from a TypeEntry by assigning it type.
     fn type_to_option(&mut self, ty: TypeEntry) -> TypeEntry {
         TypeEntryDetails::Option(self.assign_type(ty)).into()
     }
 
-    /// Create a Box<T> from a pre-assigned TypeId and assign it an ID.
-    fn id_to_box(&mut self, id: &TypeId) -> TypeId {
-        self.assign_type(TypeEntryDetails::Box(id.clone()).into())
+    /// Create a Box<T> (or other pointer) from a pre-assigned TypeId and
+    /// assign it an ID.
+    fn id_to_box(&mut self, id: &TypeId, pointer_type: PointerType) -> TypeId {
+        self.assign_type(TypeEntryDetails::Box(id.clone(), pointer_type).into())
     }
 }
 
@@ -1099,6 +1521,15 @@ impl Type<'_> {
         self.type_entry.describe()
     }
 
+    /// The kind of pointer if the type is a pointer (i.e. if its details are
+    /// [TypeDetails::Box]).
+    pub fn pointer_type(&self) -> Option<&PointerType> {
+        match &self.type_entry.details {
+            TypeEntryDetails::Box(_, pointer_type) => Some(pointer_type),
+            _ => None,
+        }
+    }
+
     /// Get details about the type.
     pub fn details(&self) -> TypeDetails<'_> {
         match &self.type_entry.details {
@@ -1114,7 +1545,7 @@ impl Type<'_> {
                 TypeDetails::Map(key_id.clone(), value_id.clone())
             }
             TypeEntryDetails::Set(type_id) => TypeDetails::Set(type_id.clone()),
-            TypeEntryDetails::Box(type_id) => TypeDetails::Box(type_id.clone()),
+            TypeEntryDetails::Box(type_id, _) => TypeDetails::Box(type_id.clone()),
             TypeEntryDetails::Tuple(types) => TypeDetails::Tuple(Box::new(types.iter().cloned())),
             TypeEntryDetails::Array(type_id, length) => {
                 TypeDetails::Array(type_id.clone(), *length)
@@ -1238,19 +1669,193 @@ impl TypeNewtype<'_> {
     }
 }
 
+/// The error type used for conversions. Structured errors hold the path and
+/// message of each failure so that builders can report all of them at once.
+fn conversion_error(structured: bool) -> TokenStream {
+    if !structured {
+        return quote! {
+            /// Error from a `TryFrom` or `FromStr` implementation.
+            pub struct ConversionError(::std::borrow::Cow<'static, str>);
+
+            impl ::std::error::Error for ConversionError {}
+            impl ::std::fmt::Display for ConversionError {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                    -> Result<(), ::std::fmt::Error>
+                {
+                    ::std::fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl ::std::fmt::Debug for ConversionError {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                    -> Result<(), ::std::fmt::Error>
+                {
+                    ::std::fmt::Debug::fmt(&self.0, f)
+                }
+            }
+            impl From<&'static str> for ConversionError {
+                fn from(value: &'static str) -> Self {
+                    Self(value.into())
+                }
+            }
+            impl From<String> for ConversionError {
+                fn from(value: String) -> Self {
+                    Self(value.into())
+                }
+            }
+        };
+    }
+
+    quote! {
+        /// Error from a `TryFrom` or `FromStr` implementation.
+        ///
+        /// This holds one or more failures, each with the path of the
+        /// property at which it occurred (e.g.
+        /// `spec.containers[0].image`); the path of a failure of the
+        /// value itself is empty.
+        #[derive(Clone)]
+        pub struct ConversionError(
+            ::std::vec::Vec<(
+                ::std::string::String,
+                ::std::borrow::Cow<'static, str>,
+            )>,
+        );
+
+        impl ConversionError {
+            /// The `(path, message)` pair for each failure.
+            pub fn errors(&self) -> impl ::std::iter::Iterator<Item = (&str, &str)> {
+                self.0
+                    .iter()
+                    .map(|(path, message)| (path.as_str(), message.as_ref()))
+            }
+
+            /// Prefix the path of each failure with a property name.
+            pub fn nest(mut self, name: &str) -> Self {
+                for (path, _) in &mut self.0 {
+                    *path = if path.is_empty() {
+                        name.to_string()
+                    } else if path.starts_with('[') {
+                        format!("{}{}", name, path)
+                    } else {
+                        format!("{}.{}", name, path)
+                    };
+                }
+                self
+            }
+
+            /// Prefix the path of each failure with an array index.
+            pub fn nest_index(mut self, index: usize) -> Self {
+                for (path, _) in &mut self.0 {
+                    *path = if path.is_empty() || path.starts_with('[') {
+                        format!("[{}]{}", index, path)
+                    } else {
+                        format!("[{}].{}", index, path)
+                    };
+                }
+                self
+            }
+
+            /// Convert an error, preserving the failures if it is
+            /// itself a `ConversionError` (e.g. from a nested builder).
+            pub fn from_error<E>(error: E) -> Self
+            where
+                E: ::std::fmt::Display + 'static,
+            {
+                match (&error as &dyn ::std::any::Any).downcast_ref::<Self>() {
+                    Some(error) => error.clone(),
+                    None => error.to_string().into(),
+                }
+            }
+
+            /// Convert each item, collecting the failures of all
+            /// items along with their indices.
+            pub fn collect<I, T>(
+                items: I,
+            ) -> ::std::result::Result<::std::vec::Vec<T>, Self>
+            where
+                I: ::std::iter::IntoIterator,
+                I::Item: ::std::convert::TryInto<T>,
+                <I::Item as ::std::convert::TryInto<T>>::Error:
+                    ::std::fmt::Display + 'static,
+            {
+                let mut values = ::std::vec::Vec::new();
+                let mut errors = ::std::vec::Vec::new();
+                for (index, item) in items.into_iter().enumerate() {
+                    match item.try_into() {
+                        Ok(value) => values.push(value),
+                        Err(error) => {
+                            errors.extend(Self::from_error(error).nest_index(index).0)
+                        }
+                    }
+                }
+                if errors.is_empty() {
+                    Ok(values)
+                } else {
+                    Err(Self(errors))
+                }
+            }
+        }
+
+        impl ::std::error::Error for ConversionError {}
+        impl ::std::fmt::Display for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                -> Result<(), ::std::fmt::Error>
+            {
+                for (ii, (path, message)) in self.0.iter().enumerate() {
+                    if ii > 0 {
+                        f.write_str("; ")?;
+                    }
+                    if !path.is_empty() {
+                        write!(f, "{}: ", path)?;
+                    }
+                    f.write_str(message)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl ::std::fmt::Debug for ConversionError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                -> Result<(), ::std::fmt::Error>
+            {
+                ::std::fmt::Debug::fmt(&self.to_string(), f)
+            }
+        }
+        impl From<&'static str> for ConversionError {
+            fn from(value: &'static str) -> Self {
+                Self(vec![(::std::string::String::new(), value.into())])
+            }
+        }
+        impl From<String> for ConversionError {
+            fn from(value: String) -> Self {
+                Self(vec![(::std::string::String::new(), value.into())])
+            }
+        }
+        impl ::std::iter::FromIterator<ConversionError> for ConversionError {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::std::iter::IntoIterator<Item = ConversionError>,
+            {
+                Self(iter.into_iter().flat_map(|error| error.0).collect())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use schema::Schema;
     use schemars::{schema_for, JsonSchema};
     use serde::Serialize;
     use serde_json::json;
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
 
     use crate::{
         output::OutputSpace,
         test_util::validate_output,
         type_entry::{TypeEntryEnum, VariantDetails},
-        MapType, Name, TypeEntryDetails, TypeSpace, TypeSpaceSettings,
+        Error, MapType, Name, TypeDetails, TypeEntryDetails, TypeNameContext, TypeSpace,
+        TypeSpaceSettings,
     };
 
     #[test]
@@ -1340,13 +1945,13 @@ mod tests {
         println!("{:#?}", ty);
 
         let mut output = OutputSpace::default();
-        ty.output(&type_space, &mut output);
+        ty.output(&type_space, &mut output, None);
         println!("{}", output.into_stream());
 
-        for ty in type_space.id_to_entry.values() {
+        for (type_id, ty) in &type_space.id_to_entry {
             println!("{:#?}", ty);
             let mut output = OutputSpace::default();
-            ty.output(&type_space, &mut output);
+            ty.output(&type_space, &mut output, Some(type_id));
             println!("{}", output.into_stream());
         }
     }
@@ -1424,7 +2029,7 @@ mod tests {
             }
             _ => {
                 let mut output = OutputSpace::default();
-                ty.output(&type_space, &mut output);
+                ty.output(&type_space, &mut output, None);
                 println!("{}", output.into_stream());
                 panic!();
             }
@@ -1549,4 +2154,229 @@ mod tests {
         let ty = type_space.get_type(&type_id).unwrap();
         assert!(ty.builder().is_none());
     }
+
+    #[test]
+    fn test_validation_uses_serde_json() {
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "uniqueItems": true
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut type_space = TypeSpace::default();
+        type_space.add_root_schema(schema).unwrap();
+        assert!(!type_space.uses_serde_json());
+
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "uniqueItems": true
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_validation_methods(true));
+        type_space.add_root_schema(schema).unwrap();
+        assert!(type_space.uses_serde_json());
+    }
+
+    #[test]
+    fn test_conditional_required_uses_serde_json() {
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Address": {
+                    "type": "object",
+                    "properties": {
+                        "country": { "type": "string" },
+                        "state": { "type": "string" }
+                    },
+                    "if": {
+                        "properties": { "country": { "const": "US" } }
+                    },
+                    "then": { "required": ["state"] }
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_validation_methods(true));
+        type_space.add_root_schema(schema).unwrap();
+        assert!(type_space.uses_serde_json());
+    }
+
+    #[test]
+    fn test_untagged_errors_use_serde_json() {
+        let schema = || {
+            serde_json::from_value(json!({
+                "definitions": {
+                    "IdOrName": {
+                        "anyOf": [
+                            { "type": "integer" },
+                            { "type": "string", "pattern": "^[a-z]+$" }
+                        ]
+                    }
+                }
+            }))
+            .unwrap()
+        };
+
+        let mut type_space = TypeSpace::default();
+        type_space.add_root_schema(schema()).unwrap();
+        assert!(!type_space.uses_serde_json());
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_detailed_untagged_errors(true));
+        type_space.add_root_schema(schema()).unwrap();
+        assert!(type_space.uses_serde_json());
+    }
+
+    #[test]
+    fn test_forbidden_property_checks_use_serde_json() {
+        let schema = || {
+            serde_json::from_value(json!({
+                "definitions": {
+                    "Settings": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "legacy": false
+                        }
+                    }
+                }
+            }))
+            .unwrap()
+        };
+
+        let mut type_space = TypeSpace::default();
+        type_space.add_root_schema(schema()).unwrap();
+        assert!(!type_space.uses_serde_json());
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_forbidden_property_checks(true));
+        type_space.add_root_schema(schema()).unwrap();
+        assert!(type_space.uses_serde_json());
+    }
+
+    #[test]
+    fn test_array_contains() {
+        let schema = |contains| {
+            serde_json::from_value(json!({
+                "definitions": {
+                    "Tags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "contains": contains
+                    }
+                }
+            }))
+            .unwrap()
+        };
+
+        // We only accept `contains` constraints that the generated validation
+        // methods check.
+        let mut type_space = TypeSpace::default();
+        assert!(type_space
+            .add_root_schema(schema(json!({ "const": "primary" })))
+            .is_err());
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_validation_methods(true));
+        assert!(type_space
+            .add_root_schema(schema(json!({ "const": "primary" })))
+            .is_ok());
+
+        let mut type_space =
+            TypeSpace::new(TypeSpaceSettings::default().with_validation_methods(true));
+        assert!(type_space
+            .add_root_schema(schema(json!({ "pattern": "^p" })))
+            .is_err());
+    }
+
+    #[test]
+    fn test_all_of_unresolved_reference() {
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Pet": {
+                    "allOf": [
+                        { "$ref": "#/definitions/Animl" },
+                        { "type": "object" }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut type_space = TypeSpace::default();
+        match type_space.add_root_schema(schema) {
+            Err(Error::InvalidSchema { type_name, reason }) => {
+                assert_eq!(type_name.as_deref(), Some("Pet"));
+                assert_eq!(reason, "unresolved reference #/definitions/Animl");
+            }
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_type_namer_conflict() {
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Foo": {
+                    "type": "object",
+                    "properties": {
+                        "a": {
+                            "type": "object",
+                            "properties": { "x": { "type": "string" } }
+                        },
+                        "b": {
+                            "type": "object",
+                            "properties": { "y": { "type": "integer" } }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut settings = TypeSpaceSettings::default();
+        settings.with_type_namer(|_: &TypeNameContext<'_>| "Same".to_string());
+        let mut type_space = TypeSpace::new(&settings);
+        type_space.add_root_schema(schema).unwrap();
+
+        let names = type_space
+            .iter_types()
+            .filter(|ty| matches!(ty.details(), TypeDetails::Struct(_)))
+            .map(|ty| ty.name())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            names,
+            ["Foo", "FooB", "Same"]
+                .into_iter()
+                .map(ToString::to_string)
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test_literal_enum_uses_serde_json() {
+        let schema = serde_json::from_value(json!({
+            "definitions": {
+                "Setting": { "enum": ["auto", 0, true] }
+            }
+        }))
+        .unwrap();
+
+        let mut type_space = TypeSpace::default();
+        type_space.add_root_schema(schema).unwrap();
+        assert!(type_space.uses_serde_json());
+    }
 }
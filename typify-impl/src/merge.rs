@@ -1,25 +1,220 @@
 // Copyright 2026 Oxide Computer Company
 
 use std::{
+    cell::RefCell,
     collections::{BTreeMap, BTreeSet},
     iter::repeat,
+    rc::Rc,
 };
 
-use log::debug;
+use log::{debug, warn};
 use schemars::schema::{
     ArrayValidation, InstanceType, NumberValidation, ObjectValidation, Schema, SchemaObject,
     SingleOrVec, StringValidation, SubschemaValidation,
 };
 
-use crate::{util::ref_key, validate::schema_value_validate, RefKey};
+use crate::{
+    util::{ref_key, required_only, Exclusions},
+    validate::schema_value_validate,
+    RefKey,
+};
 
 /// Merge all schemas in array of schemas. If the result is unsatisfiable, this
-/// returns `Schema::Bool(false)`.
-pub(crate) fn merge_all(schemas: &[Schema], defs: &BTreeMap<RefKey, Schema>) -> Schema {
-    try_merge_all(schemas, defs).unwrap_or(Schema::Bool(false))
+/// returns `Schema::Bool(false)`. If a reference can't be resolved, this
+/// returns an error describing it.
+pub(crate) fn merge_all(
+    schemas: &[Schema],
+    defs: &BTreeMap<RefKey, Schema>,
+) -> Result<Schema, String> {
+    let defs = Defs::new(defs);
+    let merged_schema = try_merge_all(schemas, &defs);
+    defs.check_resolved()?;
+    Ok(merged_schema.unwrap_or(Schema::Bool(false)))
+}
+
+/// The definitions used to resolve references while merging along with the
+/// references currently being resolved (and the schemas with which they're
+/// being merged). The latter lets us detect cycles in recursive types. We
+/// also note the first reference that we couldn't resolve; merging fails as
+/// though the schemas were incompatible, but that's an invalid schema rather
+/// than an unsatisfiable one.
+struct Defs<'a> {
+    definitions: &'a BTreeMap<RefKey, Schema>,
+    visiting: Vec<(String, Schema)>,
+    unresolved: Rc<RefCell<Option<String>>>,
 }
 
-fn try_merge_all(schemas: &[Schema], defs: &BTreeMap<RefKey, Schema>) -> Result<Schema, ()> {
+impl<'a> Defs<'a> {
+    fn new(definitions: &'a BTreeMap<RefKey, Schema>) -> Self {
+        Self {
+            definitions,
+            visiting: Default::default(),
+            unresolved: Default::default(),
+        }
+    }
+
+    fn check_resolved(&self) -> Result<(), String> {
+        match self.unresolved.borrow().as_ref() {
+            Some(ref_name) => Err(format!("unresolved reference {}", ref_name)),
+            None => Ok(()),
+        }
+    }
+
+    /// Resolve a reference that we're about to merge with `other`. If we're
+    /// already in the midst of merging that same reference with that same
+    /// schema then we've found a cycle; rather than recursing forever we
+    /// return the name of a reference to use as a placeholder (see
+    /// [Self::cycle_placeholder]).
+    fn resolve(
+        &self,
+        ref_name: &str,
+        other: &Schema,
+    ) -> Result<(&'a Schema, Defs<'a>), Unresolved> {
+        let Some(resolved) = self.definitions.get(&ref_key(ref_name)) else {
+            self.unresolved
+                .borrow_mut()
+                .get_or_insert_with(|| ref_name.to_string());
+            return Err(Unresolved::Missing);
+        };
+
+        if self
+            .visiting
+            .iter()
+            .any(|(name, schema)| name == ref_name && schema == other)
+        {
+            let placeholder = self.cycle_placeholder(ref_name, other);
+            debug!("cycle detected while merging; using {}", placeholder);
+            return Err(Unresolved::Cycle(placeholder));
+        }
+
+        let mut visiting = self.visiting.clone();
+        visiting.push((ref_name.to_string(), other.clone()));
+
+        Ok((
+            resolved,
+            Defs {
+                definitions: self.definitions,
+                visiting,
+                unresolved: self.unresolved.clone(),
+            },
+        ))
+    }
+
+    /// The merge of `ref_name` with `other` that led us around a cycle is
+    /// the very one that's underway further up the stack. If a definition is
+    /// exactly that intersection (i.e. the `allOf` of the reference and
+    /// `other`) we refer to it. Otherwise we refer to `ref_name` itself,
+    /// which loses any constraints that `other` adds.
+    fn cycle_placeholder(&self, ref_name: &str, other: &Schema) -> String {
+        let reference = Schema::Object(SchemaObject {
+            reference: Some(ref_name.to_string()),
+            ..Default::default()
+        });
+        let (prefix, _) = ref_name.rsplit_once('/').unwrap_or(("#", ""));
+
+        self.definitions
+            .iter()
+            .find_map(|(key, schema)| {
+                let RefKey::Def(name) = key else {
+                    return None;
+                };
+                let Schema::Object(SchemaObject {
+                    metadata: _,
+                    instance_type: None,
+                    format: None,
+                    enum_values: None,
+                    const_value: None,
+                    subschemas: Some(subschemas),
+                    number: None,
+                    string: None,
+                    array: None,
+                    object: None,
+                    reference: None,
+                    extensions: _,
+                }) = schema
+                else {
+                    return None;
+                };
+                let SubschemaValidation {
+                    all_of: Some(all_of),
+                    any_of: None,
+                    one_of: None,
+                    not: None,
+                    if_schema: None,
+                    then_schema: None,
+                    else_schema: None,
+                } = subschemas.as_ref()
+                else {
+                    return None;
+                };
+
+                matches!(
+                    all_of.as_slice(),
+                    [a, b] if (a == &reference && b == other) || (a == other && b == &reference)
+                )
+                .then(|| format!("{}/{}", prefix, name.replace('~', "~0").replace('/', "~1"),))
+            })
+            .unwrap_or_else(|| {
+                warn!(
+                    "recursive merge of {} drops constraints: {}",
+                    ref_name,
+                    serde_json::to_string(other).unwrap(),
+                );
+                ref_name.to_string()
+            })
+    }
+}
+
+/// Why we couldn't resolve a reference in order to merge it.
+enum Unresolved {
+    /// We're already merging the reference with the same schema; this is the
+    /// reference to use in its place.
+    Cycle(String),
+    /// There's no such definition.
+    Missing,
+}
+
+/// The instance types of a schema if it only permits some types, looking
+/// through references and `allOf` subschemas. For the latter we only need
+/// one subschema's types since the schema can't permit any others.
+fn schema_instance_types(
+    schema: &Schema,
+    definitions: &BTreeMap<RefKey, Schema>,
+    visiting: &mut Vec<String>,
+) -> Option<Vec<InstanceType>> {
+    let Schema::Object(schema_object) = schema else {
+        return None;
+    };
+
+    if let Some(instance_type) = &schema_object.instance_type {
+        return Some(match instance_type {
+            SingleOrVec::Single(it) => vec![**it],
+            SingleOrVec::Vec(types) => types.clone(),
+        });
+    }
+
+    if let Some(ref_name) = &schema_object.reference {
+        if visiting.contains(ref_name) {
+            return None;
+        }
+        visiting.push(ref_name.clone());
+        let types = definitions
+            .get(&ref_key(ref_name))
+            .and_then(|resolved| schema_instance_types(resolved, definitions, visiting));
+        visiting.pop();
+        return types;
+    }
+
+    schema_object
+        .subschemas
+        .as_ref()?
+        .all_of
+        .as_ref()?
+        .iter()
+        .find_map(|subschema| schema_instance_types(subschema, definitions, visiting))
+}
+
+fn try_merge_all(schemas: &[Schema], defs: &Defs<'_>) -> Result<Schema, ()> {
     debug!(
         "merge all {}",
         serde_json::to_string_pretty(schemas).unwrap(),
@@ -48,7 +243,7 @@ fn try_merge_all(schemas: &[Schema], defs: &BTreeMap<RefKey, Schema>) -> Result<
 fn merge_additional_items(
     a: Option<&Schema>,
     b: Option<&Schema>,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Option<Schema> {
     match (a, b) {
         (None, None) => Some(Schema::Bool(true)),
@@ -63,7 +258,7 @@ fn merge_additional_items(
 fn merge_additional_properties(
     a: Option<&Schema>,
     b: Option<&Schema>,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Option<Schema> {
     match (a, b) {
         (None, other) | (other, None) => other.cloned(),
@@ -71,14 +266,14 @@ fn merge_additional_properties(
     }
 }
 
-fn merge_schema(a: &Schema, b: &Schema, defs: &BTreeMap<RefKey, Schema>) -> Schema {
+fn merge_schema(a: &Schema, b: &Schema, defs: &Defs<'_>) -> Schema {
     try_merge_schema(a, b, defs).unwrap_or(Schema::Bool(false))
 }
 
 /// Merge two schemas returning the resulting schema. If the two schemas are
 /// incompatible (i.e. if there is no data that can satisfy them both
 /// simultaneously) then this returns Err.
-fn try_merge_schema(a: &Schema, b: &Schema, defs: &BTreeMap<RefKey, Schema>) -> Result<Schema, ()> {
+fn try_merge_schema(a: &Schema, b: &Schema, defs: &Defs<'_>) -> Result<Schema, ()> {
     match (a, b) {
         (Schema::Bool(false), _) | (_, Schema::Bool(false)) => Err(()),
         (Schema::Bool(true), other) | (other, Schema::Bool(true)) => Ok(other.clone()),
@@ -98,13 +293,10 @@ fn try_merge_schema(a: &Schema, b: &Schema, defs: &BTreeMap<RefKey, Schema>) ->
             ..Default::default()
         })),
 
-        // Resolve references here before we start to merge the objects.
-        //
-        // TODO: need to mitigate circular references so we don't go into a
-        // spin loop. We can do this by wrapping defs in a structure that
-        // remembers what we've already looked up; if we hit a cycle we can
-        // consider the proper handling, but it might be to ignore it--a
-        // circular allOf chain is a bit hard to reason about.
+        // Resolve references here before we start to merge the objects. If
+        // this merge is already underway further up the stack we've found a
+        // cycle; we leave a reference in place which will be resolved to the
+        // type we're in the midst of converting (and boxed as needed).
         (
             ref_schema @ Schema::Object(SchemaObject {
                 reference: Some(ref_name),
@@ -119,11 +311,35 @@ fn try_merge_schema(a: &Schema, b: &Schema, defs: &BTreeMap<RefKey, Schema>) ->
                 ..
             }),
         ) => {
-            let key = ref_key(ref_name);
-            let resolved = defs
-                .get(&key)
-                .unwrap_or_else(|| panic!("unresolved reference: {}", ref_name));
-            let merged_schema = try_merge_schema(resolved, other, defs)?;
+            let (resolved, defs) = match defs.resolve(ref_name, other) {
+                Ok(resolution) => resolution,
+                Err(Unresolved::Cycle(placeholder)) => {
+                    return Ok(Schema::Object(SchemaObject {
+                        reference: Some(placeholder),
+                        ..Default::default()
+                    }))
+                }
+                Err(Unresolved::Missing) => return Err(()),
+            };
+
+            // A schema that only permits types to which the referenced
+            // schema is already limited (such as `{"type": "object"}` for a
+            // struct) adds nothing to it.
+            if let Schema::Object(other_object) = other {
+                if let Some(other_types) = only_instance_type(other_object) {
+                    let other_types = match other_types {
+                        SingleOrVec::Single(it) => std::slice::from_ref(it.as_ref()),
+                        SingleOrVec::Vec(types) => types.as_slice(),
+                    };
+                    if schema_instance_types(resolved, defs.definitions, &mut Vec::new())
+                        .is_some_and(|types| types.iter().all(|it| other_types.contains(it)))
+                    {
+                        return Ok(ref_schema.clone());
+                    }
+                }
+            }
+
+            let merged_schema = try_merge_schema(resolved, other, &defs)?;
 
             // If we merge a referenced schema with another schema **and**
             // the resulting schema is equivalent to the referenced schema
@@ -143,7 +359,7 @@ fn try_merge_schema(a: &Schema, b: &Schema, defs: &BTreeMap<RefKey, Schema>) ->
 fn merge_schema_object(
     a: &SchemaObject,
     b: &SchemaObject,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<SchemaObject, ()> {
     debug!(
         "merging {}\n{}",
@@ -194,8 +410,8 @@ fn merge_schema_object(
     // two schemas and then do the appropriate merge with subschemas (i.e.
     // potentially twice). This is effectively an `allOf` between the merged
     // "body" schema and the component subschemas.
-    merged_schema = try_merge_with_subschemas(merged_schema, a.subschemas.as_deref(), defs)?;
-    merged_schema = try_merge_with_subschemas(merged_schema, b.subschemas.as_deref(), defs)?;
+    merged_schema = try_merge_schema_with_subschemas(merged_schema, a.subschemas.as_deref(), defs)?;
+    merged_schema = try_merge_schema_with_subschemas(merged_schema, b.subschemas.as_deref(), defs)?;
 
     assert_ne!(merged_schema, Schema::Bool(false).into_object());
 
@@ -207,7 +423,9 @@ fn merge_schema_object(
         let enum_values = Some(
             enum_values
                 .into_iter()
-                .filter(|value| schema_value_validate(&wrapped_schema, value, defs).is_ok())
+                .filter(|value| {
+                    schema_value_validate(&wrapped_schema, value, defs.definitions).is_ok()
+                })
                 .collect(),
         );
         let Schema::Object(new_merged_schema) = wrapped_schema else {
@@ -266,11 +484,23 @@ fn merge_so_enum_values(
 
 /// Merge the schema with a subschema validation object. It's important that
 /// the return value reduces the complexity of the problem so avoid infinite
-/// recursion.
+/// recursion. If the result is unsatisfiable, this returns `None`; if a
+/// reference can't be resolved, this returns an error describing it.
 pub(crate) fn try_merge_with_subschemas(
-    mut schema_object: SchemaObject,
+    schema_object: SchemaObject,
     maybe_subschemas: Option<&SubschemaValidation>,
     defs: &BTreeMap<RefKey, Schema>,
+) -> Result<Option<SchemaObject>, String> {
+    let defs = Defs::new(defs);
+    let merged_schema = try_merge_schema_with_subschemas(schema_object, maybe_subschemas, &defs);
+    defs.check_resolved()?;
+    Ok(merged_schema.ok())
+}
+
+fn try_merge_schema_with_subschemas(
+    mut schema_object: SchemaObject,
+    maybe_subschemas: Option<&SubschemaValidation>,
+    defs: &Defs<'_>,
 ) -> Result<SchemaObject, ()> {
     let Some(SubschemaValidation {
         all_of,
@@ -355,7 +585,7 @@ pub(crate) fn try_merge_with_subschemas(
 fn try_merge_with_each_subschema(
     schema_object: &SchemaObject,
     subschemas: &[Schema],
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Vec<Schema> {
     let schema = Schema::Object(schema_object.clone());
     // First we do a pairwise merge the schemas; if the result is invalid /
@@ -412,18 +642,17 @@ fn try_merge_with_each_subschema(
     joined_schemas
 }
 
-fn merge_schema_not(
-    schema: &Schema,
-    not_schema: &Schema,
-    defs: &BTreeMap<RefKey, Schema>,
-) -> Schema {
+fn merge_schema_not(schema: &Schema, not_schema: &Schema, defs: &Defs<'_>) -> Schema {
     match (schema, not_schema) {
         (_, Schema::Bool(true)) | (Schema::Bool(false), _) => Schema::Bool(false),
 
         (any, Schema::Bool(false)) => any.clone(),
 
-        // TODO I don't know how to subtract something from nothing...
-        (Schema::Bool(true), Schema::Object(_)) => todo!(),
+        // Subtracting from everything is the same as subtracting from the
+        // permissive schema.
+        (Schema::Bool(true), not_schema @ Schema::Object(_)) => {
+            merge_schema_not(&Schema::Object(SchemaObject::default()), not_schema, defs)
+        }
 
         (Schema::Object(schema_object), any_not) => {
             match try_merge_schema_not(schema_object.clone(), any_not, defs) {
@@ -442,7 +671,7 @@ fn merge_schema_not(
 fn try_merge_schema_not(
     schema_object: SchemaObject,
     not_schema: &Schema,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<SchemaObject, ()> {
     debug!(
         "try_merge_schema_not {}\n not:{}",
@@ -462,7 +691,7 @@ fn try_merge_schema_not(
 fn try_merge_with_subschemas_not(
     schema_object: SchemaObject,
     not_subschemas: &SubschemaValidation,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<SchemaObject, ()> {
     debug!("try_merge_with_subschemas_not");
     match not_subschemas {
@@ -546,33 +775,115 @@ fn try_merge_with_subschemas_not(
             Err(_) => Ok(schema_object),
         },
 
-        _ => todo!(
-            "{}\nnot: {}",
-            serde_json::to_string_pretty(&schema_object).unwrap(),
-            serde_json::to_string_pretty(&not_subschemas).unwrap(),
-        ),
+        // We don't know how to subtract other combinations of subschemas so
+        // we leave the schema as it is; the result may be more permissive
+        // than the original.
+        _ => {
+            debug!(
+                "unable to subtract {}\nnot: {}",
+                serde_json::to_string_pretty(&schema_object).unwrap(),
+                serde_json::to_string_pretty(&not_subschemas).unwrap(),
+            );
+            Ok(schema_object)
+        }
     }
 }
 
 fn try_merge_schema_object_not(
     mut schema_object: SchemaObject,
     not_object: &SchemaObject,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<SchemaObject, ()> {
+    let not_schema = Schema::Object(not_object.clone());
+    let mut exclusions = Exclusions::default();
+
+    // A "not" schema that only specifies instance types removes those types.
+    if let Some(not_types) = only_instance_type(not_object) {
+        let instance_type =
+            subtract_instance_types(schema_object.instance_type.as_ref(), not_types)?;
+        if let Some(values) = &mut schema_object.enum_values {
+            let typed = Schema::Object(SchemaObject {
+                instance_type: instance_type.clone(),
+                ..Default::default()
+            });
+            values.retain(|value| schema_value_validate(&typed, value, defs.definitions).is_ok());
+            if values.is_empty() {
+                return Err(());
+            }
+        }
+        schema_object.instance_type = instance_type;
+    }
+
     // Examine enum values
-    match (&mut schema_object.enum_values, &not_object.enum_values) {
+    let not_values = match (&not_object.enum_values, &not_object.const_value) {
+        (None, None) => None,
+        (values, value) => Some(
+            values
+                .iter()
+                .flatten()
+                .chain(value)
+                .cloned()
+                .collect::<Vec<_>>(),
+        ),
+    };
+    if let (Some(value), Some(not_values)) = (&schema_object.const_value, &not_values) {
+        if not_values.contains(value) {
+            return Err(());
+        }
+    }
+    match (&mut schema_object.enum_values, not_values) {
         // Nothing to do.
         (_, None) => {}
-        // TODO not sure quite what to do, so we'll ignore for now.
-        (None, Some(_)) => {}
         (Some(values), Some(not_values)) => {
             values.retain(|value| !not_values.contains(value));
             if values.is_empty() {
                 return Err(());
             }
         }
+        // If the "not" schema constrains nothing else, we exclude the values
+        // that it rejects and that the schema would otherwise accept.
+        (None, Some(not_values)) if only_values(not_object) => {
+            let schema = Schema::Object(SchemaObject {
+                subschemas: None,
+                ..schema_object.clone()
+            });
+            exclusions.values = not_values
+                .into_iter()
+                .filter(|value| {
+                    schema_value_validate(&not_schema, value, defs.definitions).is_ok()
+                        && schema_value_validate(&schema, value, defs.definitions).is_ok()
+                })
+                .collect();
+        }
+        // TODO not sure quite what to do, so we'll ignore for now.
+        (None, Some(_)) => {}
+    }
+
+    // Examine string patterns. A "not" schema with a pattern but no type
+    // accepts every non-string value so only strings that don't match the
+    // pattern remain. With an explicit string type, we can only exclude the
+    // pattern from a schema that is already only a string.
+    if let Some(pattern) = only_pattern(not_object) {
+        if not_object.instance_type.is_none() {
+            schema_object.instance_type = merge_so_instance_type(
+                schema_object.instance_type.as_ref(),
+                Some(&InstanceType::String.into()),
+            )?;
+        }
+        if Exclusions::accepts_patterns(&schema_object) {
+            exclusions.patterns.push(pattern.clone());
+        }
     }
 
+    exclusions.exclude_from(&mut schema_object);
+
+    // A "not" schema that only requires properties applies only to objects,
+    // so we can treat an absent object validation as an empty one.
+    if schema_object.object.is_none() && required_only(&not_schema).is_some() {
+        schema_object.object = Some(Default::default());
+    }
+
+    let mut objects_excluded = false;
     match (&mut schema_object.object, &not_object.object) {
         // Nothing to do.
         (_, None) => {}
@@ -582,8 +893,15 @@ fn try_merge_schema_object_not(
 
         // In the interesting case, we need to "subtract" object attributes.
         (Some(obj), Some(not_obj)) => {
+            // A value satisfies a "not" schema with several properties if any
+            // one of them doesn't match so we can only subtract a single
+            // property's schema.
+            let not_properties = match not_obj.properties.len() {
+                1 => Some(&not_obj.properties),
+                _ => None,
+            };
             for (prop_name, prop_schema) in &mut obj.properties {
-                if let Some(not_prop_schema) = not_obj.properties.get(prop_name) {
+                if let Some(not_prop_schema) = not_properties.and_then(|p| p.get(prop_name)) {
                     // For properties in both, we merge those schemas. Note
                     // that if such a merging is unsatisfiable *and* the
                     // property is required, we'll take the appropriate action
@@ -605,13 +923,28 @@ fn try_merge_schema_object_not(
                 }
             }
 
-            for not_required in &not_obj.required {
-                if !not_obj.properties.contains_key(not_required) {
+            // Values satisfy a "not" schema that requires properties by
+            // lacking any one of them (or by not being objects). If the
+            // object already requires all but one, that one is forbidden; if
+            // it requires them all (and the "not" schema constrains nothing
+            // else) no object is permissible. Otherwise we can't express the
+            // constraint.
+            let unrequired = not_obj
+                .required
+                .iter()
+                .filter(|name| !obj.required.contains(*name))
+                .collect::<Vec<_>>();
+            match unrequired.as_slice() {
+                [] if required_only(&not_schema).is_some_and(|required| !required.is_empty()) => {
+                    objects_excluded = true;
+                }
+                [not_required] if !not_obj.properties.contains_key(*not_required) => {
                     // No value is permissible
                     let _ = obj
                         .properties
-                        .insert(not_required.clone(), Schema::Bool(false));
+                        .insert((*not_required).clone(), Schema::Bool(false));
                 }
+                _ => {}
             }
 
             // If any of the previous steps resulted in a required property
@@ -625,6 +958,16 @@ fn try_merge_schema_object_not(
         }
     }
 
+    // Only values of the other types remain; if the schema is only for
+    // objects, it's unsatisfiable.
+    if objects_excluded {
+        schema_object.instance_type = subtract_instance_types(
+            schema_object.instance_type.as_ref(),
+            &InstanceType::Object.into(),
+        )?;
+        schema_object.object = None;
+    }
+
     if let Some(not_subschemas) = &not_object.subschemas {
         schema_object = try_merge_with_subschemas_not(schema_object, not_subschemas, defs)?;
     }
@@ -632,6 +975,124 @@ fn try_merge_schema_object_not(
     Ok(schema_object)
 }
 
+/// If the schema only specifies instance types, return them.
+fn only_instance_type(schema_object: &SchemaObject) -> Option<&SingleOrVec<InstanceType>> {
+    match schema_object {
+        SchemaObject {
+            metadata: _,
+            instance_type: Some(instance_type),
+            format: None,
+            enum_values: None,
+            const_value: None,
+            subschemas: None,
+            number: None,
+            string: None,
+            array: None,
+            object: None,
+            reference: None,
+            extensions: _,
+        } => Some(instance_type),
+        _ => None,
+    }
+}
+
+/// Whether the schema only enumerates values (with an optional type).
+fn only_values(schema_object: &SchemaObject) -> bool {
+    matches!(
+        schema_object,
+        SchemaObject {
+            metadata: _,
+            instance_type: _,
+            format: None,
+            enum_values: _,
+            const_value: _,
+            subschemas: None,
+            number: None,
+            string: None,
+            array: None,
+            object: None,
+            reference: None,
+            extensions: _,
+        }
+    )
+}
+
+/// If the schema only specifies a string pattern (with an optional string
+/// type), return the pattern.
+fn only_pattern(schema_object: &SchemaObject) -> Option<&String> {
+    match schema_object {
+        SchemaObject {
+            metadata: _,
+            instance_type,
+            format: None,
+            enum_values: None,
+            const_value: None,
+            subschemas: None,
+            number: None,
+            string: Some(string),
+            array: None,
+            object: None,
+            reference: None,
+            extensions: _,
+        } if instance_type.is_none() || instance_type == &Some(InstanceType::String.into()) => {
+            match string.as_ref() {
+                StringValidation {
+                    max_length: None,
+                    min_length: None,
+                    pattern: Some(pattern),
+                } => Some(pattern),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Remove instance types from those permitted (where None means that all
+/// types are valid). An error result indicates that no types remain. Note
+/// that removing "number" also removes "integer", but we can't remove
+/// "integer" from "number".
+fn subtract_instance_types(
+    a: Option<&SingleOrVec<InstanceType>>,
+    not: &SingleOrVec<InstanceType>,
+) -> Result<Option<SingleOrVec<InstanceType>>, ()> {
+    let all = [
+        InstanceType::Null,
+        InstanceType::Boolean,
+        InstanceType::Object,
+        InstanceType::Array,
+        InstanceType::Number,
+        InstanceType::String,
+        InstanceType::Integer,
+    ];
+    let types = match a {
+        None => &all[..],
+        Some(SingleOrVec::Single(it)) => std::slice::from_ref(it.as_ref()),
+        Some(SingleOrVec::Vec(types)) => types.as_slice(),
+    };
+    let not_types = match not {
+        SingleOrVec::Single(it) => std::slice::from_ref(it.as_ref()),
+        SingleOrVec::Vec(types) => types.as_slice(),
+    };
+
+    let types = types
+        .iter()
+        .filter(|it| {
+            !not_types.contains(it)
+                && !(**it == InstanceType::Integer && not_types.contains(&InstanceType::Number))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    match types.len() {
+        0 => Err(()),
+        1 => Ok(Some(types.into_iter().next().unwrap().into())),
+        // If nothing was removed, leave the types unspecified.
+        n if a.is_none() && n == all.len() => Ok(None),
+        _ => Ok(Some(types.into())),
+    }
+}
+
 /// Merge instance types which could be None (meaning type is valid), a
 /// singleton type, or an array of types. An error result indicates that the
 /// types were non-overlappin and therefore incompatible.
@@ -808,7 +1269,7 @@ fn merge_so_string(
 fn merge_so_array(
     a: Option<&ArrayValidation>,
     b: Option<&ArrayValidation>,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<Option<Box<ArrayValidation>>, ()> {
     match (a, b) {
         (None, other) | (other, None) => Ok(other.cloned().map(Box::new)),
@@ -1001,7 +1462,7 @@ fn merge_items_array<'a>(
     items_iter: impl Iterator<Item = (&'a Schema, &'a Schema)>,
     min_items: Option<u32>,
     max_items: Option<u32>,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<(Vec<Schema>, bool), ()> {
     let mut items = Vec::new();
     for (a, b) in items_iter {
@@ -1040,7 +1501,7 @@ where
 fn merge_so_object(
     a: Option<&ObjectValidation>,
     b: Option<&ObjectValidation>,
-    defs: &BTreeMap<RefKey, Schema>,
+    defs: &Defs<'_>,
 ) -> Result<Option<Box<ObjectValidation>>, ()> {
     match (a, b) {
         (None, other) | (other, None) => Ok(other.cloned().map(Box::new)),
@@ -1333,7 +1794,7 @@ mod tests {
 
     use crate::{merge::merge_so_instance_type, RefKey};
 
-    use super::try_merge_schema;
+    use super::{try_merge_schema, Defs};
 
     #[test]
     fn test_simple_merge() {
@@ -1374,7 +1835,7 @@ mod tests {
         let b = serde_json::from_value(b).unwrap();
         let ab = serde_json::from_value(ab).unwrap();
 
-        let merged = try_merge_schema(&a, &b, &BTreeMap::default()).unwrap();
+        let merged = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default())).unwrap();
 
         assert_eq!(merged, ab);
     }
@@ -1494,7 +1955,7 @@ mod tests {
         let a = serde_json::from_value(a).unwrap();
         let b = serde_json::from_value(b).unwrap();
 
-        let merged = try_merge_schema(&a, &b, &BTreeMap::default()).unwrap();
+        let merged = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default())).unwrap();
 
         assert_eq!(merged, a);
     }
@@ -1594,7 +2055,7 @@ mod tests {
         let a = serde_json::from_value(a).unwrap();
         let b = serde_json::from_value(b).unwrap();
 
-        let ab = try_merge_schema(&a, &b, &Default::default());
+        let ab = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default()));
 
         assert!(ab.is_err());
 
@@ -1609,7 +2070,7 @@ mod tests {
         let a = serde_json::from_value(a).unwrap();
         let b = serde_json::from_value(b).unwrap();
 
-        let ab = try_merge_schema(&a, &b, &Default::default());
+        let ab = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default()));
 
         assert!(ab.is_err());
 
@@ -1624,7 +2085,7 @@ mod tests {
         let a = serde_json::from_value(a).unwrap();
         let b = serde_json::from_value(b).unwrap();
 
-        let ab = try_merge_schema(&a, &b, &Default::default());
+        let ab = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default()));
 
         assert!(
             ab.is_err(),
@@ -1655,7 +2116,7 @@ mod tests {
         let a = serde_json::from_value(a).unwrap();
         let b = serde_json::from_value(b).unwrap();
 
-        let ab = try_merge_schema(&a, &b, &Default::default());
+        let ab = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default()));
 
         assert!(
             ab.is_err(),
@@ -1699,7 +2160,7 @@ mod tests {
         let b = serde_json::from_value(b).unwrap();
         let ab = serde_json::from_value(ab).unwrap();
 
-        let merged = try_merge_schema(&a, &b, &BTreeMap::default()).unwrap();
+        let merged = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default())).unwrap();
         assert_eq!(
             merged,
             ab,
@@ -1742,7 +2203,7 @@ mod tests {
         let b = serde_json::from_value(b).unwrap();
         let ab = serde_json::from_value(ab).unwrap();
 
-        let merged = try_merge_schema(&a, &b, &BTreeMap::default()).unwrap();
+        let merged = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default())).unwrap();
         assert_eq!(
             merged,
             ab,
@@ -1784,7 +2245,7 @@ mod tests {
         let b = serde_json::from_value(b).unwrap();
         let ab = serde_json::from_value(ab).unwrap();
 
-        let merged = try_merge_schema(&a, &b, &BTreeMap::default()).unwrap();
+        let merged = try_merge_schema(&a, &b, &Defs::new(&BTreeMap::default())).unwrap();
         assert_eq!(
             merged,
             ab,
@@ -1823,7 +2284,7 @@ mod tests {
         let merged = try_merge_schema(
             &a,
             &b,
-            &[(RefKey::Def("x".to_string()), x)].into_iter().collect(),
+            &Defs::new(&[(RefKey::Def("x".to_string()), x)].into_iter().collect()),
         )
         .unwrap();
         assert_eq!(
@@ -1879,7 +2340,7 @@ mod tests {
         let merged = try_merge_schema(
             &a,
             &b,
-            &[(RefKey::Def("x".to_string()), x)].into_iter().collect(),
+            &Defs::new(&[(RefKey::Def("x".to_string()), x)].into_iter().collect()),
         )
         .unwrap();
         assert_eq!(
@@ -1904,7 +2365,7 @@ mod tests {
         .map(|v| serde_json::from_value(v).unwrap())
         .collect();
 
-        let merged = super::merge_all(&schemas, &BTreeMap::default());
+        let merged = super::merge_all(&schemas, &BTreeMap::default()).unwrap();
 
         let expected: schemars::schema::Schema = serde_json::from_value(json!({
             "type": "string",
@@ -1919,4 +2380,244 @@ mod tests {
             serde_json::to_string_pretty(&merged).unwrap(),
         );
     }
+
+    #[test]
+    fn test_merge_recursive_all_of() {
+        // Merging a type that refers back to itself through an allOf must
+        // terminate, leaving a reference to the recursive type in place.
+        let base = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "children": {
+                    "type": "array",
+                    "items": { "type": "object" }
+                }
+            },
+            "required": ["name"]
+        });
+        let extension = json!({
+            "type": "object",
+            "properties": {
+                "children": {
+                    "type": "array",
+                    "items": { "$ref": "#/definitions/Node" }
+                }
+            }
+        });
+        let node = json!({
+            "allOf": [
+                { "$ref": "#/definitions/Base" },
+                extension
+            ]
+        });
+        let expected = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "children": {
+                    "type": "array",
+                    "items": { "$ref": "#/definitions/Node" }
+                }
+            },
+            "required": ["name"]
+        });
+
+        let definitions = [
+            (
+                RefKey::Def("Base".to_string()),
+                serde_json::from_value(base).unwrap(),
+            ),
+            (
+                RefKey::Def("Node".to_string()),
+                serde_json::from_value(node).unwrap(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let schemas: Vec<schemars::schema::Schema> =
+            [json!({ "$ref": "#/definitions/Base" }), extension]
+                .into_iter()
+                .map(|v| serde_json::from_value(v).unwrap())
+                .collect();
+
+        let merged = super::merge_all(&schemas, &definitions).unwrap();
+        let expected: schemars::schema::Schema = serde_json::from_value(expected).unwrap();
+
+        assert_eq!(
+            merged,
+            expected,
+            "{}",
+            serde_json::to_string_pretty(&merged).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_merge_recursive_cycle() {
+        // Here the base's items constrain more than the type so merging them
+        // with the reference goes around the cycle; the placeholder refers to
+        // the definition that is the intersection in question.
+        let base = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "children": {
+                    "type": "array",
+                    "items": { "type": "object", "required": ["name"] }
+                }
+            }
+        });
+        let extension = json!({
+            "type": "object",
+            "properties": {
+                "children": {
+                    "type": "array",
+                    "items": { "$ref": "#/definitions/Node" }
+                }
+            }
+        });
+        let node = json!({
+            "allOf": [
+                { "$ref": "#/definitions/Base" },
+                extension
+            ]
+        });
+
+        let definitions = [
+            (
+                RefKey::Def("Base".to_string()),
+                serde_json::from_value(base).unwrap(),
+            ),
+            (
+                RefKey::Def("Node".to_string()),
+                serde_json::from_value(node).unwrap(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let schemas: Vec<schemars::schema::Schema> =
+            [json!({ "$ref": "#/definitions/Base" }), extension]
+                .into_iter()
+                .map(|v| serde_json::from_value(v).unwrap())
+                .collect();
+
+        let merged = super::merge_all(&schemas, &definitions).unwrap();
+        let expected: schemars::schema::Schema = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "children": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "children": {
+                                "type": "array",
+                                "items": { "$ref": "#/definitions/Node" }
+                            }
+                        },
+                        "required": ["name"]
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            merged,
+            expected,
+            "{}",
+            serde_json::to_string_pretty(&merged).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_merge_unresolved_reference() {
+        let schemas: Vec<schemars::schema::Schema> = [
+            json!({ "$ref": "#/definitions/Missing" }),
+            json!({ "type": "object" }),
+        ]
+        .into_iter()
+        .map(|v| serde_json::from_value(v).unwrap())
+        .collect();
+
+        assert_eq!(
+            super::merge_all(&schemas, &BTreeMap::default()),
+            Err("unresolved reference #/definitions/Missing".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_merge_not() {
+        let cases = [
+            // Instance types are removed.
+            (
+                json!({"type": ["string", "boolean", "null"]}),
+                json!({"type": "null"}),
+                json!({"type": ["string", "boolean"]}),
+            ),
+            // Excluded values remain as a `not` subschema...
+            (
+                json!({"type": "string"}),
+                json!({"enum": ["a", 1]}),
+                json!({"type": "string", "not": {"enum": ["a"]}}),
+            ),
+            // ... as do excluded patterns, alongside them.
+            (
+                json!({"type": "string", "not": {"enum": ["a"]}}),
+                json!({"pattern": "^_"}),
+                json!({
+                    "type": "string",
+                    "not": {
+                        "anyOf": [
+                            {"enum": ["a"]},
+                            {"type": "string", "pattern": "^_"}
+                        ]
+                    }
+                }),
+            ),
+            // A property that is not required is forbidden.
+            (
+                json!({"type": "object", "required": ["a"]}),
+                json!({"required": ["a", "b"]}),
+                json!({"type": "object", "required": ["a"], "properties": {"b": false}}),
+            ),
+        ];
+
+        for (schema, not, expected) in cases {
+            let schema: schemars::schema::Schema = serde_json::from_value(schema).unwrap();
+            let not: schemars::schema::Schema = serde_json::from_value(not).unwrap();
+            let expected: schemars::schema::Schema = serde_json::from_value(expected).unwrap();
+
+            let merged = super::merge_schema_not(&schema, &not, &Defs::new(&BTreeMap::default()));
+            assert_eq!(
+                merged,
+                expected,
+                "{}",
+                serde_json::to_string_pretty(&merged).unwrap(),
+            );
+        }
+
+        // Subtracting an object's only required property leaves only values
+        // that aren't objects...
+        let schema = serde_json::from_value(json!({"required": ["a"]})).unwrap();
+        let not = serde_json::from_value(json!({"required": ["a"]})).unwrap();
+        let expected = serde_json::from_value::<schemars::schema::Schema>(json!({
+            "type": ["null", "boolean", "array", "number", "string", "integer"]
+        }))
+        .unwrap();
+        assert_eq!(
+            super::merge_schema_not(&schema, &not, &Defs::new(&BTreeMap::default())),
+            expected,
+        );
+
+        // ... of which there are none if the schema is only for objects.
+        let schema = serde_json::from_value(json!({"type": "object", "required": ["a"]})).unwrap();
+        let not = serde_json::from_value(json!({"required": ["a"]})).unwrap();
+        assert_eq!(
+            super::merge_schema_not(&schema, &not, &Defs::new(&BTreeMap::default())),
+            schemars::schema::Schema::Bool(false),
+        );
+    }
 }
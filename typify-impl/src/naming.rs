@@ -0,0 +1,338 @@
+// Copyright 2025 Oxide Computer Company
+
+use std::sync::Arc;
+
+use schemars::schema::{Metadata, Schema, SchemaObject};
+
+use log::warn;
+
+use crate::{
+    util::{get_type_name, metadata_title, sanitize, Case},
+    Name, RefKey, TypeSpace,
+};
+
+/// Strategy for naming the types generated for inline schemas, i.e. schemas
+/// other than definitions whose names typify would otherwise derive from the
+/// names of the types that contain them (e.g. `FooBarItem` for the items of
+/// the `bar` property of `Foo`). Names are converted to Pascal case, and the
+/// same context must always produce the same name. A name that belongs to
+/// another type is ignored in favor of the suggested name.
+///
+/// Any `Fn(&TypeNameContext<'_>) -> String` is a `TypeNamer`; see also the
+/// built-in strategies of [TypeNaming].
+pub trait TypeNamer {
+    /// The name for the type described by the context.
+    fn name(&self, context: &TypeNameContext<'_>) -> String;
+}
+
+impl<F> TypeNamer for F
+where
+    F: Fn(&TypeNameContext<'_>) -> String,
+{
+    fn name(&self, context: &TypeNameContext<'_>) -> String {
+        self(context)
+    }
+}
+
+/// Context for naming the type of an inline schema; see [TypeNamer].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TypeNameContext<'a> {
+    /// The location of the schema as a JSON Pointer from the root schema,
+    /// e.g. `/definitions/Foo/properties/bar/items`. The pointer stops short
+    /// for schemas that typify derives from others (e.g. when simplifying an
+    /// `allOf`), in which case it's that of the schema from which they were
+    /// derived.
+    pub path: &'a str,
+    /// The name of the type from whose name the suggested name was derived.
+    pub parent: Option<&'a str>,
+    /// The name of the property, if the schema is that of a property.
+    pub property: Option<&'a str>,
+    /// For types that typify derives from the schema at `path` rather than
+    /// generating for the schema itself, what sort of type it is: `extra` for
+    /// the map of a struct's additional properties, or `inner` for the type
+    /// wrapped by another (e.g. by a newtype that restricts its values).
+    pub derived: Option<&'a str>,
+    /// The `title` of the schema.
+    pub title: Option<&'a str>,
+    /// The name typify would use otherwise: the title if there is one and the
+    /// name derived from that of the parent type if not.
+    pub suggested: &'a str,
+}
+
+/// Built-in [TypeNamer] strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeNaming {
+    /// Use the suggested name, but drop the words at its start that repeat
+    /// the end of the parent type's name, e.g. `PetPetType` (for the
+    /// `pet_type` property of `Pet`) becomes `PetType`, and
+    /// `ServerConfigConfigFile` becomes `ServerConfigFile`.
+    Shorten,
+    /// Name types for their locations, ignoring their titles, e.g.
+    /// `FooPropertiesBarItems` for `/definitions/Foo/properties/bar/items`.
+    /// These names are stable: they don't change as titles are added or
+    /// removed.
+    Path,
+}
+
+impl TypeNamer for TypeNaming {
+    fn name(&self, context: &TypeNameContext<'_>) -> String {
+        match self {
+            TypeNaming::Shorten => {
+                let Some(rest) = context
+                    .parent
+                    .filter(|_| context.title.is_none())
+                    .and_then(|parent| context.suggested.strip_prefix(parent))
+                    .filter(|rest| !rest.is_empty())
+                else {
+                    return context.suggested.to_string();
+                };
+                let parent = &context.suggested[..context.suggested.len() - rest.len()];
+                let parent_words = words(parent);
+                let rest_words = words(rest);
+
+                // Leave at least one word so as not to repeat the parent's name.
+                let overlap = (1..=parent_words.len().min(rest_words.len() - 1))
+                    .rev()
+                    .find(|n| parent_words[parent_words.len() - n..] == rest_words[..*n])
+                    .unwrap_or(0);
+
+                format!("{}{}", parent, rest_words[overlap..].concat())
+            }
+
+            TypeNaming::Path => {
+                let mut segments = context.path.split('/').skip(1).peekable();
+                if let Some(&("definitions" | "$defs")) = segments.peek() {
+                    let _ = segments.next();
+                }
+                let name = segments
+                    .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+                    .chain(context.derived.map(ToString::to_string))
+                    .map(|segment| sanitize(&segment, Case::Pascal))
+                    .collect::<String>();
+                if name.is_empty() {
+                    context.suggested.to_string()
+                } else {
+                    name
+                }
+            }
+        }
+    }
+}
+
+/// Split a Pascal case name into its words.
+fn words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (ii, ch) in name.char_indices().skip(1) {
+        if ch.is_uppercase() {
+            words.push(&name[start..ii]);
+            start = ii;
+        }
+    }
+    words.push(&name[start..]);
+    words
+}
+
+#[derive(Clone)]
+pub(crate) struct TypeNamerImpl(pub Arc<dyn TypeNamer>);
+
+impl std::fmt::Debug for TypeNamerImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TypeNamer")
+    }
+}
+
+/// A step from a schema to one of its subschemas during conversion.
+#[derive(Debug)]
+pub(crate) struct NameFrame {
+    segments: Vec<String>,
+    parent: Option<String>,
+    property: Option<String>,
+    derived: Option<String>,
+}
+
+impl NameFrame {
+    pub fn new<S: ToString>(segments: &[S], parent: Option<&str>) -> Self {
+        Self {
+            segments: segments.iter().map(ToString::to_string).collect(),
+            parent: parent.map(ToString::to_string),
+            property: None,
+            derived: None,
+        }
+    }
+
+    /// A type derived from the schema at the current location.
+    pub fn derived(kind: &str, parent: Option<&str>) -> Self {
+        Self {
+            derived: Some(kind.to_string()),
+            ..Self::new::<&str>(&[], parent)
+        }
+    }
+
+    pub fn property(prop_name: &str, parent: Option<&str>) -> Self {
+        Self {
+            property: Some(prop_name.to_string()),
+            ..Self::new(&["properties", prop_name], parent)
+        }
+    }
+
+    /// The step to the given subschema of `original_schema`, if it is one of
+    /// the subschemas of its `allOf`, `anyOf`, or `oneOf`.
+    pub fn subschema(original_schema: &Schema, schema: &Schema, parent: Option<&str>) -> Self {
+        let subschemas = match original_schema {
+            Schema::Object(SchemaObject {
+                subschemas: Some(subschemas),
+                ..
+            }) => Some(subschemas),
+            _ => None,
+        };
+        let segments = subschemas
+            .and_then(|subschemas| {
+                [
+                    ("allOf", &subschemas.all_of),
+                    ("anyOf", &subschemas.any_of),
+                    ("oneOf", &subschemas.one_of),
+                ]
+                .into_iter()
+                .find_map(|(keyword, schemas)| {
+                    let index = schemas.as_ref()?.iter().position(|s| s == schema)?;
+                    Some(vec![keyword.to_string(), index.to_string()])
+                })
+            })
+            .unwrap_or_default();
+
+        Self {
+            segments,
+            parent: parent.map(ToString::to_string),
+            property: None,
+            derived: None,
+        }
+    }
+
+    /// Append further segments, e.g. for a property of the subschema.
+    pub fn with_property(mut self, prop_name: &str) -> Self {
+        if !self.segments.is_empty() {
+            self.segments.push("properties".to_string());
+            self.segments.push(prop_name.to_string());
+        }
+        self
+    }
+}
+
+impl TypeSpace {
+    /// Convert the subschema reached by the given step.
+    pub(crate) fn in_subschema<T>(
+        &mut self,
+        frame: NameFrame,
+        convert: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        self.name_frames.push(frame);
+        let result = convert(self);
+        let _ = self.name_frames.pop();
+        result
+    }
+
+    /// The name for a type: the required name, or the suggested name (or the
+    /// title) as adjusted by the [TypeNamer] if there is one.
+    pub(crate) fn resolve_type_name(
+        &self,
+        type_name: &Name,
+        metadata: &Option<Box<Metadata>>,
+    ) -> Option<String> {
+        let suggested = get_type_name(type_name, metadata)?;
+        match (type_name, &self.settings.type_namer) {
+            (Name::Suggested(_), Some(TypeNamerImpl(namer))) => {
+                let path = self
+                    .name_frames
+                    .iter()
+                    .flat_map(|frame| &frame.segments)
+                    .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+                    .collect::<String>();
+                // Steps that lead nowhere leave the context of the last one.
+                let frame = self.name_frames.iter().rev().find(|frame| {
+                    !frame.segments.is_empty() || frame.parent.is_some() || frame.derived.is_some()
+                });
+                let parent = frame
+                    .and_then(|frame| frame.parent.as_deref())
+                    .map(|parent| sanitize(parent, Case::Pascal));
+                let title = metadata_title(metadata);
+                let context = TypeNameContext {
+                    path: &path,
+                    parent: parent.as_deref(),
+                    property: frame.and_then(|frame| frame.property.as_deref()),
+                    derived: frame.and_then(|frame| frame.derived.as_deref()),
+                    title: title.as_deref(),
+                    suggested: &suggested,
+                };
+                let name = sanitize(&namer.name(&context), Case::Pascal);
+
+                // A name that belongs to another type falls back to the
+                // suggested name; the namer may name the same type again.
+                if name != suggested && self.type_name_taken(&name, &suggested) {
+                    warn!(
+                        "the name {} is already taken; using {} for the type at {}",
+                        name, suggested, path,
+                    );
+                    return Some(suggested);
+                }
+                self.namer_names
+                    .borrow_mut()
+                    .insert(name.clone(), suggested);
+                Some(name)
+            }
+            _ => Some(suggested),
+        }
+    }
+
+    /// Whether the name from the [TypeNamer] for the type with the given
+    /// suggested name belongs to a different type.
+    fn type_name_taken(&self, name: &str, suggested: &str) -> bool {
+        match self.namer_names.borrow().get(name) {
+            Some(named) => named != suggested,
+            None => {
+                self.name_to_id.contains_key(name)
+                    || self.definitions.keys().any(|ref_key| {
+                        matches!(
+                            ref_key,
+                            RefKey::Def(def_name) if sanitize(def_name, Case::Pascal) == name
+                        )
+                    })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TypeNameContext, TypeNamer, TypeNaming};
+
+    fn context<'a>(parent: &'a str, suggested: &'a str) -> TypeNameContext<'a> {
+        TypeNameContext {
+            path: "",
+            parent: Some(parent),
+            property: None,
+            derived: None,
+            title: None,
+            suggested,
+        }
+    }
+
+    #[test]
+    fn test_shorten() {
+        let shorten = |parent, suggested| TypeNaming::Shorten.name(&context(parent, suggested));
+
+        assert_eq!(shorten("Pet", "PetPetType"), "PetType");
+        assert_eq!(
+            shorten("ServerConfig", "ServerConfigConfigFile"),
+            "ServerConfigFile"
+        );
+        assert_eq!(
+            shorten("ServerConfig", "ServerConfigServerConfig"),
+            "ServerConfigServerConfig"
+        );
+        assert_eq!(shorten("Pet", "PetTagsItem"), "PetTagsItem");
+        assert_eq!(shorten("Pet", "PetsItem"), "PetsItem");
+        assert_eq!(shorten("Pet", "Pet"), "Pet");
+    }
+}
@@ -3,7 +3,8 @@
 use std::collections::BTreeMap;
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Item};
 
 #[derive(Debug, Default)]
 pub struct OutputSpace {
@@ -16,6 +17,8 @@ pub enum OutputSpaceMod {
     Crate,
     Builder,
     Defaults,
+    Serde,
+    Examples,
 }
 
 impl OutputSpace {
@@ -31,6 +34,18 @@ impl OutputSpace {
             .extend(stream);
     }
 
+    /// Add all items from `other`, marking each with `#[allow(deprecated)]`
+    /// so that generated code can refer to deprecated types, fields, and
+    /// variants without warnings.
+    pub fn extend_allow_deprecated(&mut self, other: OutputSpace) {
+        for (key, stream) in other.items {
+            self.items
+                .entry(key)
+                .or_default()
+                .extend(allow_deprecated(stream));
+        }
+    }
+
     pub fn into_stream(self) -> TokenStream {
         let mods = self
             .items
@@ -60,6 +75,19 @@ impl OutputSpace {
                     #items
                 }
             },
+            OutputSpaceMod::Serde => quote! {
+                /// Helpers for serde.
+                pub mod serde_helpers {
+                    #items
+                }
+            },
+            OutputSpaceMod::Examples => quote! {
+                /// Tests that schema examples round-trip through their types.
+                #[cfg(test)]
+                mod typify_examples {
+                    #items
+                }
+            },
             OutputSpaceMod::Error => quote! {
                 /// Error types.
                 pub mod error {
@@ -74,9 +102,36 @@ impl OutputSpace {
     }
 }
 
+/// Prefix each item in the stream with `#[allow(deprecated)]`.
+fn allow_deprecated(stream: TokenStream) -> TokenStream {
+    let mut file = syn::parse2::<syn::File>(stream).expect("generated items should parse");
+    for item in &mut file.items {
+        let attrs = match item {
+            Item::Const(item) => &mut item.attrs,
+            Item::Enum(item) => &mut item.attrs,
+            Item::ExternCrate(item) => &mut item.attrs,
+            Item::Fn(item) => &mut item.attrs,
+            Item::ForeignMod(item) => &mut item.attrs,
+            Item::Impl(item) => &mut item.attrs,
+            Item::Macro(item) => &mut item.attrs,
+            Item::Mod(item) => &mut item.attrs,
+            Item::Static(item) => &mut item.attrs,
+            Item::Struct(item) => &mut item.attrs,
+            Item::Trait(item) => &mut item.attrs,
+            Item::TraitAlias(item) => &mut item.attrs,
+            Item::Type(item) => &mut item.attrs,
+            Item::Union(item) => &mut item.attrs,
+            Item::Use(item) => &mut item.attrs,
+            _ => continue,
+        };
+        attrs.insert(0, parse_quote! { #[allow(deprecated)] });
+    }
+    file.into_token_stream()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{OutputSpace, OutputSpaceMod};
+    use super::{allow_deprecated, OutputSpace, OutputSpaceMod};
 
     use quote::quote;
 
@@ -119,4 +174,42 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn test_allow_deprecated() {
+        let actual = allow_deprecated(quote! {
+            #[derive(Debug)]
+            pub struct A(pub u32);
+            impl A {
+                fn new() -> Self { Self(0) }
+            }
+            const B: A = A { 0: 1 };
+            impl From<&'static str> for A {
+                fn from(_: &'static str) -> Self { Self(2) }
+            }
+            struct C;
+        });
+
+        assert_eq!(
+            actual.to_string(),
+            quote! {
+                #[allow(deprecated)]
+                #[derive(Debug)]
+                pub struct A(pub u32);
+                #[allow(deprecated)]
+                impl A {
+                    fn new() -> Self { Self(0) }
+                }
+                #[allow(deprecated)]
+                const B: A = A { 0: 1 };
+                #[allow(deprecated)]
+                impl From<&'static str> for A {
+                    fn from(_: &'static str) -> Self { Self(2) }
+                }
+                #[allow(deprecated)]
+                struct C;
+            }
+            .to_string()
+        );
+    }
 }
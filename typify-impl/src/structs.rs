@@ -1,18 +1,20 @@
 // Copyright 2024 Oxide Computer Company
 
 use heck::ToSnakeCase;
+use log::info;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use schemars::schema::{InstanceType, Metadata, ObjectValidation, Schema, SchemaObject};
 
 use crate::{
+    naming::NameFrame,
     output::{OutputSpace, OutputSpaceMod},
     type_entry::{
-        StructProperty, StructPropertyRename, StructPropertyState, TypeEntry, TypeEntryStruct,
-        WrappedValue,
+        Annotations, StructProperty, StructPropertyRename, StructPropertyState, TypeEntry,
+        TypeEntryStruct, WrappedValue,
     },
-    util::{get_type_name, metadata_description, recase, Case},
-    Name, Result, TypeEntryDetails, TypeId, TypeSpace,
+    util::{metadata_description, recase, Case, TypePatch},
+    Name, PropertyOrder, Result, TypeEntryDetails, TypeId, TypeSpace, TypeSpaceImpl,
 };
 
 impl TypeSpace {
@@ -40,17 +42,13 @@ impl TypeSpace {
             .chain(required_unspecified)
             .filter_map(|(prop_name, schema)| {
                 match schema {
-                    // TODO We use the schema `false` to indicate an
-                    // unsatisfiable schema. We take a shortcut here and simply
-                    // ignore these. This is wrong in two subtle and important
-                    // ways that we'll need to address at some point. First,
-                    // there are other schemas in some non-trivial,
+                    // We use the schema `false` to indicate an unsatisfiable
+                    // schema. These properties get no field; instead the
+                    // struct's serde::Deserialize implementation fails in
+                    // their presence (see `forbidden_properties`).
+                    // TODO there are other schemas in some non-trivial,
                     // non-canonical form that might indicate the same thing.
-                    // We should handle those in the same way. In addition,
-                    // ignoring them isn't really right. We need to actively
-                    // exclude them. Specifically this would look like a custom
-                    // serde::Deserialize implementation that failed in the
-                    // presence of these values.
+                    // We should handle those in the same way.
                     Schema::Bool(false) => None,
                     _ => {
                         // Generate a name we can use for the type of this
@@ -59,19 +57,26 @@ impl TypeSpace {
                         let sub_type_name = type_name
                             .as_ref()
                             .map(|base| format!("{}_{}", base, prop_name.to_snake_case()));
-                        Some(self.struct_property(
-                            sub_type_name,
-                            &validation.required,
-                            prop_name,
-                            schema,
-                        ))
+                        let frame = NameFrame::property(prop_name, type_name.as_deref());
+                        Some(self.in_subschema(frame, |type_space| {
+                            type_space.struct_property(
+                                sub_type_name,
+                                &validation.required,
+                                prop_name,
+                                schema,
+                            )
+                        }))
                     }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // Sort parameters by name to ensure a deterministic result.
-        properties.sort_by(|a, b| a.name.cmp(&b.name));
+        // Sort parameters by name to ensure a deterministic result unless
+        // we've been asked to keep the order of the schema (which is itself
+        // deterministic).
+        if self.settings.property_order == PropertyOrder::Alphabetical {
+            properties.sort_by(|a, b| a.name.cmp(&b.name));
+        }
 
         // If there are additional properties tack them on, flattened, at the
         // end. Note that a `None` value for additional_properties is
@@ -97,17 +102,21 @@ impl TypeSpace {
             // quite right.
             additional_properties @ Some(_) => {
                 let sub_type_name = type_name.as_ref().map(|base| format!("{}_extra", base));
-                let map_type = self.make_map(
-                    sub_type_name,
-                    &validation.property_names,
-                    additional_properties,
-                )?;
+                let frame = NameFrame::derived("extra", type_name.as_deref());
+                let map_type = self.in_subschema(frame, |type_space| {
+                    type_space.make_map(
+                        sub_type_name,
+                        &validation.property_names,
+                        additional_properties,
+                    )
+                })?;
                 let map_type_id = self.assign_type(map_type);
                 let extra_prop = StructProperty {
                     name: "extra".to_string(),
                     rename: StructPropertyRename::Flatten,
                     state: StructPropertyState::Required,
                     description: None,
+                    annotations: Annotations::default(),
                     type_id: map_type_id,
                 };
 
@@ -119,6 +128,54 @@ impl TypeSpace {
         Ok((properties, deny_unknown_fields))
     }
 
+    /// Unknown properties of a struct with a permissive
+    /// `additionalProperties` schema are ignored (see above) unless
+    /// preserving them has been requested. In that case produce a flattened
+    /// `extra` map to hold them--just as we would for the equivalently
+    /// permissive schema `{}`--but one that is skipped when empty.
+    pub(crate) fn unknown_properties(
+        &mut self,
+        type_name: Option<String>,
+        validation: &ObjectValidation,
+        properties: &[StructProperty],
+    ) -> Result<Option<StructProperty>> {
+        let preserve = match &type_name {
+            Some(name) => TypePatch::new(self, name.clone()).preserve_unknown_properties,
+            None => self.settings.preserve_unknown_properties,
+        };
+        let permissive = match validation.additional_properties.as_deref() {
+            Some(schema) => schema == &Schema::Bool(true),
+            None => true,
+        };
+        if !preserve || !permissive {
+            return Ok(None);
+        }
+        if properties.iter().any(|prop| prop.name == "extra") {
+            info!(
+                "not preserving unknown properties of {:?}; it already has an \
+                 `extra` property",
+                type_name,
+            );
+            return Ok(None);
+        }
+
+        // Maps of JSON values are otherwise `serde_json::Map`; naming the
+        // value type natively gives us the configured map type.
+        let key_id = self.assign_type(TypeEntryDetails::String.into());
+        let value_id = self.assign_type(TypeEntry::new_native("::serde_json::Value", &[]));
+        let map_type_id = self.assign_type(TypeEntryDetails::Map(key_id, value_id).into());
+        self.uses_serde_json = true;
+
+        Ok(Some(StructProperty {
+            name: "extra".to_string(),
+            rename: StructPropertyRename::Flatten,
+            state: StructPropertyState::Optional,
+            description: Some("Properties not described by the schema.".to_string()),
+            annotations: Annotations::default(),
+            type_id: map_type_id,
+        }))
+    }
+
     fn struct_property(
         &mut self,
         type_name: Option<String>,
@@ -163,6 +220,20 @@ impl TypeSpace {
                     type_id = self.id_to_option(&type_id);
                     StructPropertyState::Optional
                 }
+                // An optional property that may be null has three states:
+                // absent, null, and some value.
+                StructPropertyState::Optional if self.settings.double_option => {
+                    if let Some(TypeEntry {
+                        details: TypeEntryDetails::Option(inner_id),
+                        ..
+                    }) = self.id_to_entry.get(&type_id)
+                    {
+                        let inner_id = inner_id.clone();
+                        let option_id = self.id_to_double_option_inner(&inner_id);
+                        type_id = self.id_to_option(&option_id);
+                    }
+                    StructPropertyState::Optional
+                }
                 other => other,
             }
         };
@@ -178,6 +249,7 @@ impl TypeSpace {
             rename,
             state,
             description: metadata_description(metadata),
+            annotations: Annotations::from_schema(schema),
             type_id,
         })
     }
@@ -200,7 +272,10 @@ impl TypeSpace {
                     Some(name) => Name::Suggested(format!("{}Key", name)),
                     None => Name::Unknown,
                 };
-                self.id_for_schema_string(key_type_name, obj)?
+                let frame = NameFrame::new(&["propertyNames"], type_name.as_deref());
+                self.in_subschema(frame, |type_space| {
+                    type_space.id_for_schema_string(key_type_name, obj)
+                })?
             }
         };
 
@@ -210,7 +285,10 @@ impl TypeSpace {
                     Some(name) => Name::Suggested(format!("{}Value", name)),
                     None => Name::Unknown,
                 };
-                self.id_for_schema(value_type_name, value_schema)?
+                let frame = NameFrame::new(&["additionalProperties"], type_name.as_deref());
+                self.in_subschema(frame, |type_space| {
+                    type_space.id_for_schema(value_type_name, value_schema)
+                })?
             }
 
             None => self.id_for_schema(Name::Unknown, &Schema::Bool(true))?,
@@ -277,12 +355,16 @@ impl TypeSpace {
             .iter()
             .enumerate()
             .map(|(idx, schema)| {
-                let type_name = match get_type_name(&type_name, metadata) {
+                let parent = self.resolve_type_name(&type_name, metadata);
+                let type_name = match &parent {
                     Some(name) => Name::Suggested(format!("{}Subtype{}", name, idx)),
                     None => Name::Unknown,
                 };
 
-                let (mut type_id, _) = self.id_for_schema(type_name, schema)?;
+                let frame = NameFrame::subschema(original_schema, schema, parent.as_deref());
+                let (mut type_id, _) = self.in_subschema(frame, |type_space| {
+                    type_space.id_for_schema(type_name, schema)
+                })?;
                 if optional {
                     type_id = self.id_to_option(&type_id);
                 }
@@ -300,6 +382,7 @@ impl TypeSpace {
                         StructPropertyState::Required
                     },
                     description: None,
+                    annotations: Annotations::default(),
                     type_id,
                 })
             })
@@ -312,6 +395,7 @@ impl TypeSpace {
                 metadata,
                 properties,
                 false,
+                false,
                 original_schema.clone(),
             ),
             metadata,
@@ -325,6 +409,13 @@ pub(crate) enum DefaultFunction {
     Custom(String),
 }
 
+/// Whether an Option whose inner type is `inner_id` is the `Option<Option<T>>`
+/// of a property rather than a nested Option that we flatten; see
+/// [crate::TypeSpaceSettings::with_double_option].
+pub(crate) fn is_double_option(type_space: &TypeSpace, inner_id: &TypeId) -> bool {
+    type_space.double_options.contains(inner_id)
+}
+
 /// Generate the serde attribute parameters for the given property.
 ///
 /// This may include a default value that requires a generated function to
@@ -335,29 +426,37 @@ pub(crate) enum DefaultFunction {
 /// legibility.
 pub(crate) fn generate_serde_attr(
     type_name: &str,
-    prop_name: &str,
-    naming: &StructPropertyRename,
-    state: &StructPropertyState,
+    prop: &StructProperty,
     prop_type: &TypeEntry,
     type_space: &TypeSpace,
     output: &mut OutputSpace,
 ) -> (TokenStream, DefaultFunction) {
     let mut serde_options = Vec::new();
-    match naming {
+    match &prop.rename {
         StructPropertyRename::Rename(s) => serde_options.push(quote! { rename = #s }),
         StructPropertyRename::Flatten => serde_options.push(quote! { flatten }),
         StructPropertyRename::None => (),
     }
 
-    let default_fn = match (state, &prop_type.details) {
-        (StructPropertyState::Optional, TypeEntryDetails::Option(_)) => {
+    let mut skip_serializing_if = None;
+    let default_fn = match (&prop.state, &prop_type.details) {
+        (StructPropertyState::Optional, TypeEntryDetails::Option(inner_id)) => {
             serde_options.push(quote! { default });
-            serde_options.push(quote! { skip_serializing_if = "::std::option::Option::is_none" });
+            skip_serializing_if = Some(quote! {
+                skip_serializing_if = "::std::option::Option::is_none"
+            });
+            if is_double_option(type_space, inner_id) {
+                serde_options.push(quote! {
+                    deserialize_with = "serde_helpers::deserialize_some"
+                });
+            }
             DefaultFunction::Default
         }
         (StructPropertyState::Optional, TypeEntryDetails::Vec(_)) => {
             serde_options.push(quote! { default });
-            serde_options.push(quote! { skip_serializing_if = "::std::vec::Vec::is_empty" });
+            skip_serializing_if = Some(quote! {
+                skip_serializing_if = "::std::vec::Vec::is_empty"
+            });
             DefaultFunction::Default
         }
         (StructPropertyState::Optional, TypeEntryDetails::Map(key_id, value_id)) => {
@@ -376,12 +475,12 @@ pub(crate) fn generate_serde_attr(
             if key_ty.details == TypeEntryDetails::String
                 && value_ty.details == TypeEntryDetails::JsonValue
             {
-                serde_options.push(quote! {
+                skip_serializing_if = Some(quote! {
                     skip_serializing_if = "::serde_json::Map::is_empty"
                 });
             } else {
                 let is_empty = format!("{}::is_empty", map_to_use);
-                serde_options.push(quote! {
+                skip_serializing_if = Some(quote! {
                     skip_serializing_if = #is_empty
                 });
             }
@@ -394,7 +493,7 @@ pub(crate) fn generate_serde_attr(
 
         (StructPropertyState::Default(WrappedValue(value)), _) => {
             let (fn_name, default_fn) =
-                prop_type.default_fn(value, type_space, type_name, prop_name);
+                prop_type.default_fn(value, type_space, type_name, &prop.name);
             serde_options.push(quote! { default = #fn_name });
 
             if let Some(default_fn) = default_fn {
@@ -406,6 +505,22 @@ pub(crate) fn generate_serde_attr(
         (StructPropertyState::Required, _) => DefaultFunction::None,
     };
 
+    // Read-only properties are never sent; write-only properties are never
+    // received. Skipping deserialization requires a value to fill in.
+    let skip_read_write_only = type_space.settings.skip_read_write_only;
+    if skip_read_write_only && prop.annotations.read_only {
+        serde_options.push(quote! { skip_serializing });
+    } else if let Some(skip_serializing_if) = skip_serializing_if {
+        serde_options.push(skip_serializing_if);
+    }
+    if skip_read_write_only
+        && prop.annotations.write_only
+        && (!matches!(default_fn, DefaultFunction::None)
+            || prop_type.has_impl(type_space, TypeSpaceImpl::Default))
+    {
+        serde_options.push(quote! { skip_deserializing });
+    }
+
     let serde = if serde_options.is_empty() {
         quote! {}
     } else {
@@ -417,6 +532,299 @@ pub(crate) fn generate_serde_attr(
     (serde, default_fn)
 }
 
+/// Pair each property of a base with that of a struct merged from it (see
+/// [output_base_conversions]), noting which the merged struct requires (i.e.
+/// the property of the base is the Option of that of the merged struct).
+/// Returns `None` if some property of the base doesn't correspond.
+fn base_properties<'a>(
+    type_space: &TypeSpace,
+    base: &'a TypeEntryStruct,
+    merged: &TypeEntryStruct,
+) -> Option<Vec<(&'a StructProperty, bool)>> {
+    base.properties
+        .iter()
+        .map(|base_prop| {
+            let prop = merged
+                .properties
+                .iter()
+                .find(|prop| prop.name == base_prop.name)?;
+            if prop.type_id == base_prop.type_id {
+                return Some((base_prop, false));
+            }
+            match type_space.id_to_entry.get(&base_prop.type_id) {
+                Some(TypeEntry {
+                    details: TypeEntryDetails::Option(id),
+                    ..
+                }) if id == &prop.type_id => Some((base_prop, true)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Conversions between a struct merged from the subschemas of an `allOf` and
+/// each struct referenced by one of those subschemas (its bases). The merged
+/// struct can be converted into a base by discarding the properties that the
+/// base lacks so long as each property of the base has the same type as that
+/// of the merged struct--or is the Option of that type when the merged
+/// struct requires the property. A base with the same properties as the
+/// merged struct can be converted into the merged struct; fallibly if the
+/// merged struct requires properties that the base does not.
+pub(crate) fn output_base_conversions(
+    type_space: &TypeSpace,
+    struct_details: &TypeEntryStruct,
+) -> TokenStream {
+    let type_name = format_ident!("{}", struct_details.name);
+
+    struct_details
+        .bases
+        .iter()
+        .filter_map(|base_id| {
+            let base_entry = type_space.id_to_entry.get(base_id)?;
+            let TypeEntryDetails::Struct(base) = &base_entry.details else {
+                return None;
+            };
+            if base.name == struct_details.name || base.properties.is_empty() {
+                return None;
+            }
+            let base_ident = base_entry.type_ident(type_space, &None);
+
+            let props = base_properties(type_space, base, struct_details)?
+                .into_iter()
+                .map(|(prop, required)| {
+                    let serialized = match &prop.rename {
+                        StructPropertyRename::Rename(raw_name) => raw_name.as_str(),
+                        _ => prop.name.as_str(),
+                    };
+                    (format_ident!("{}", prop.name), serialized, required)
+                })
+                .collect::<Vec<_>>();
+
+            let into_base_props = props.iter().map(|(ident, _, required)| {
+                if *required {
+                    quote! { #ident: ::std::option::Option::Some(value.#ident) }
+                } else {
+                    quote! { #ident: value.#ident }
+                }
+            });
+            let into_base = quote! {
+                impl ::std::convert::From<#type_name> for #base_ident {
+                    fn from(value: #type_name) -> Self {
+                        Self {
+                            #( #into_base_props, )*
+                        }
+                    }
+                }
+            };
+
+            let from_base = (props.len() == struct_details.properties.len()).then(|| {
+                let (direct, required): (Vec<_>, Vec<_>) =
+                    props.iter().partition(|(_, _, required)| !*required);
+                let direct = direct.iter().map(|(ident, _, _)| ident);
+                let (required, required_names): (Vec<_>, Vec<&str>) = required
+                    .iter()
+                    .map(|(ident, name, _)| (ident, *name))
+                    .unzip();
+
+                if required.is_empty() {
+                    return quote! {
+                        impl ::std::convert::From<#base_ident> for #type_name {
+                            fn from(value: #base_ident) -> Self {
+                                Self {
+                                    #( #direct: value.#direct, )*
+                                }
+                            }
+                        }
+                    };
+                }
+
+                quote! {
+                    impl ::std::convert::TryFrom<#base_ident> for #type_name {
+                        type Error = self::error::ConversionError;
+
+                        fn try_from(value: #base_ident) ->
+                            ::std::result::Result<Self, self::error::ConversionError>
+                        {
+                            match ( #( value.#required, )* ) {
+                            ( #( ::std::option::Option::Some(#required), )* ) => Ok(Self {
+                                #( #direct: value.#direct, )*
+                                #( #required, )*
+                            }),
+                            ( #( #required, )* ) => Err(format!(
+                                "no value supplied for {}",
+                                [
+                                    #( #required.is_none().then_some(#required_names), )*
+                                ]
+                                .into_iter()
+                                .flatten()
+                                .collect::<::std::vec::Vec<_>>()
+                                .join(", "),
+                            )
+                            .into()),
+                            }
+                        }
+                    }
+                }
+            });
+
+            Some(quote! {
+                #into_base
+                #from_base
+            })
+        })
+        .collect()
+}
+
+/// A trait with an accessor for each property of a struct that is the base of
+/// other structs (see [output_base_conversions]), implemented by the base and
+/// by each of those structs.
+pub(crate) fn output_base_trait(
+    type_space: &TypeSpace,
+    struct_details: &TypeEntryStruct,
+) -> TokenStream {
+    let name = &struct_details.name;
+    if struct_details.properties.is_empty() {
+        return TokenStream::new();
+    }
+
+    let merged = type_space
+        .id_to_entry
+        .values()
+        .filter_map(|type_entry| match &type_entry.details {
+            TypeEntryDetails::Struct(merged)
+                if &merged.name != name
+                    && merged.bases.iter().any(|base_id| {
+                        matches!(
+                            type_space.id_to_entry.get(base_id),
+                            Some(TypeEntry {
+                                details: TypeEntryDetails::Struct(base),
+                                ..
+                            }) if &base.name == name
+                        )
+                    }) =>
+            {
+                let props = base_properties(type_space, struct_details, merged)?;
+                Some((format_ident!("{}", merged.name), props))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if merged.is_empty() {
+        return TokenStream::new();
+    }
+
+    let trait_name = format!("{}Fields", name);
+    if type_space.name_to_id.contains_key(&trait_name) {
+        info!(
+            "not generating a {} trait for {}; the name is taken",
+            trait_name, name,
+        );
+        return TokenStream::new();
+    }
+    let trait_ident = format_ident!("{}", trait_name);
+    let type_name = format_ident!("{}", name);
+
+    // The accessor's return type, and whether the property is a String; we
+    // return those as `&str` (and a Vec as a slice).
+    let signatures = struct_details
+        .properties
+        .iter()
+        .map(|prop| {
+            let prop_ident = format_ident!("{}", prop.name);
+            let doc = prop.description.as_ref().map(|d| quote! { #[doc = #d] });
+            let type_entry = type_space.id_to_entry.get(&prop.type_id).unwrap();
+            let (ty, optional, string) = match &type_entry.details {
+                TypeEntryDetails::Option(id) => {
+                    let inner = type_space.id_to_entry.get(id).unwrap();
+                    let string = inner.details == TypeEntryDetails::String;
+                    let inner_ty = if string {
+                        quote! { &str }
+                    } else {
+                        let ident = inner.type_ident(type_space, &None);
+                        quote! { &#ident }
+                    };
+                    (quote! { ::std::option::Option<#inner_ty> }, true, string)
+                }
+                TypeEntryDetails::String => (quote! { &str }, false, true),
+                TypeEntryDetails::Vec(id) => {
+                    let inner = type_space.id_to_entry.get(id).unwrap();
+                    let ident = inner.type_ident(type_space, &None);
+                    (quote! { &[#ident] }, false, false)
+                }
+                _ => {
+                    let ident = type_entry.type_ident(type_space, &None);
+                    (quote! { &#ident }, false, false)
+                }
+            };
+            (prop_ident, doc, ty, optional, string)
+        })
+        .collect::<Vec<_>>();
+
+    let trait_fns = signatures.iter().map(|(prop_ident, doc, ty, _, _)| {
+        quote! {
+            #doc
+            fn #prop_ident(&self) -> #ty;
+        }
+    });
+
+    let impl_fns = |required: &[bool]| {
+        signatures
+            .iter()
+            .zip(required)
+            .map(|((prop_ident, _, ty, optional, string), required)| {
+                let body = match (optional, required, string) {
+                    (true, true, true) => {
+                        quote! { ::std::option::Option::Some(self.#prop_ident.as_str()) }
+                    }
+                    (true, true, false) => {
+                        quote! { ::std::option::Option::Some(&self.#prop_ident) }
+                    }
+                    (true, false, true) => quote! { self.#prop_ident.as_deref() },
+                    (true, false, false) => quote! { self.#prop_ident.as_ref() },
+                    (false, _, _) => quote! { &self.#prop_ident },
+                };
+                quote! {
+                    fn #prop_ident(&self) -> #ty {
+                        #body
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let base_fns = impl_fns(&vec![false; signatures.len()]);
+    let merged_impls = merged.iter().map(|(merged_ident, props)| {
+        let required = props
+            .iter()
+            .map(|(_, required)| *required)
+            .collect::<Vec<_>>();
+        let fns = impl_fns(&required);
+        quote! {
+            impl #trait_ident for #merged_ident {
+                #( #fns )*
+            }
+        }
+    });
+
+    let doc = format!(
+        "Accessors for the properties of [`{}`] and of the types that include it.",
+        name,
+    );
+
+    quote! {
+        #[doc = #doc]
+        pub trait #trait_ident {
+            #( #trait_fns )*
+        }
+
+        impl #trait_ident for #type_name {
+            #( #base_fns )*
+        }
+
+        #( #merged_impls )*
+    }
+}
+
 /// See if this type is a type that we can omit with a serde directive; note
 /// that the type id lookup will fail only for references (and only during
 /// initial reference processing).
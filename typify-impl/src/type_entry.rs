@@ -2,23 +2,35 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use heck::ToPascalCase;
+use log::info;
 use proc_macro2::{Punct, Spacing, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
-use schemars::schema::{Metadata, Schema};
+use schemars::schema::{Metadata, Schema, SchemaObject};
 use syn::Path;
 use unicode_ident::is_xid_continue;
 
 use crate::{
-    enums::output_variant,
+    arbitrary::{enum_arbitrary_impl, newtype_arbitrary_impl, struct_arbitrary_impl},
+    enums::{
+        order_untagged_variants, output_accessors, output_untagged_deserialize, output_variant,
+    },
     output::{OutputSpace, OutputSpaceMod},
     sanitize,
-    structs::{generate_serde_attr, DefaultFunction},
-    util::{get_type_name, metadata_description, unique, TypePatch},
-    Case, DefaultImpl, Name, Result, TypeId, TypeSpace, TypeSpaceImpl,
+    structs::{
+        generate_serde_attr, is_double_option, output_base_conversions, output_base_trait,
+        DefaultFunction,
+    },
+    util::{metadata_description, unique, TypePatch},
+    validation::{
+        enum_validate_impl, newtype_validate_impl, struct_validate_impl, validation_uses_serde_json,
+    },
+    Case, DefaultImpl, Name, PointerType, Result, StructBuilderStyle, TypeId, TypeSpace,
+    TypeSpaceImpl,
 };
 
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct SchemaWrapper(Schema);
+pub(crate) struct SchemaWrapper(pub Schema);
 
 impl Eq for SchemaWrapper {}
 
@@ -43,9 +55,25 @@ pub(crate) struct TypeEntryEnum {
     pub variants: Vec<Variant>,
     pub deny_unknown_fields: bool,
     pub bespoke_impls: BTreeSet<TypeEntryEnumImpl>,
+    pub unknown_variant: Option<UnknownVariant>,
+    pub repr: EnumRepr,
     pub schema: SchemaWrapper,
 }
 
+/// How the values of an enum are represented.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum EnumRepr {
+    /// Variants are serialized according to the tag type by derived impls.
+    #[default]
+    Derived,
+    /// A `#[repr(i64)]` enum of simple variants whose raw names are their
+    /// integer values.
+    Integer,
+    /// An enum of simple variants whose raw names are the JSON text of the
+    /// literal values they represent.
+    Literal,
+}
+
 /// Cached attributes that (mostly) result in customized impl generation.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum TypeEntryEnumImpl {
@@ -58,6 +86,15 @@ pub(crate) enum TypeEntryEnumImpl {
     UntaggedFromStringIrrefutable,
 }
 
+/// The catch-all variant of a non-exhaustive enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum UnknownVariant {
+    /// Holds the raw string of an enum of simple variants.
+    String,
+    /// Holds the raw value of a tagged enum.
+    Value,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct TypeEntryStruct {
     pub name: String,
@@ -66,6 +103,21 @@ pub(crate) struct TypeEntryStruct {
     pub default: Option<WrappedValue>,
     pub properties: Vec<StructProperty>,
     pub deny_unknown_fields: bool,
+    /// Whether the `extra` property holds properties not described by the
+    /// schema; see [TypeSpace::unknown_properties].
+    pub unknown_properties: bool,
+    /// Properties that may not be present (i.e. whose schema is `false`);
+    /// deserialization fails if any of these appear.
+    pub forbidden_properties: Vec<String>,
+    /// Pointer types for properties (by their names in the schema) that
+    /// override [TypeSpaceSettings::with_pointer_type] should the property
+    /// break a containment cycle.
+    ///
+    /// [TypeSpaceSettings::with_pointer_type]: crate::TypeSpaceSettings::with_pointer_type
+    pub pointer_types: BTreeMap<String, PointerType>,
+    /// Types referenced by the `allOf` subschemas that were merged to
+    /// produce this struct.
+    pub bases: Vec<TypeId>,
     pub schema: SchemaWrapper,
 }
 
@@ -89,6 +141,7 @@ pub(crate) enum TypeEntryNewtypeConstraints {
         max_length: Option<u32>,
         min_length: Option<u32>,
         pattern: Option<String>,
+        not_pattern: Option<String>,
     },
 }
 
@@ -152,7 +205,8 @@ pub(crate) enum TypeEntryDetails {
 
     // Types from core and std.
     Option(TypeId),
-    Box(TypeId),
+    /// A `Box` or another pointer type inserted to break a containment cycle.
+    Box(TypeId, PointerType),
     Vec(TypeId),
     Map(TypeId, TypeId),
     Set(TypeId),
@@ -188,6 +242,7 @@ pub(crate) struct Variant {
     pub raw_name: String,
     pub ident_name: Option<String>,
     pub description: Option<String>,
+    pub annotations: Annotations,
     pub details: VariantDetails,
 }
 
@@ -205,9 +260,20 @@ pub(crate) struct StructProperty {
     pub rename: StructPropertyRename,
     pub state: StructPropertyState,
     pub description: Option<String>,
+    pub annotations: Annotations,
     pub type_id: TypeId,
 }
 
+/// Annotation keywords that have no bearing on validation, but that we reflect
+/// in generated attributes and documentation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Annotations {
+    pub deprecated: bool,
+    pub read_only: bool,
+    pub write_only: bool,
+    pub comment: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum StructPropertyRename {
     None,
@@ -287,12 +353,36 @@ impl TypeEntryEnum {
             panic!("Failed to make unique variant names for [{}]", dups);
         }
 
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
 
         let type_patch = TypePatch::new(type_space, name);
 
+        let unknown_variant = if !type_patch.unknown_variant
+            || tag_type == EnumTagType::Untagged
+            || variants.is_empty()
+        {
+            None
+        } else if variants
+            .iter()
+            .any(|variant| variant.ident_name.as_deref() == Some("Unknown"))
+        {
+            info!(
+                "not adding an Unknown variant to {}; one already exists",
+                type_patch.name,
+            );
+            None
+        } else if tag_type == EnumTagType::External
+            && variants
+                .iter()
+                .all(|variant| matches!(variant.details, VariantDetails::Simple))
+        {
+            Some(UnknownVariant::String)
+        } else {
+            Some(UnknownVariant::Value)
+        };
+
         let details = TypeEntryDetails::Enum(Self {
             name: type_patch.name,
             rename,
@@ -302,6 +392,8 @@ impl TypeEntryEnum {
             variants,
             deny_unknown_fields,
             bespoke_impls: Default::default(),
+            unknown_variant,
+            repr: EnumRepr::Derived,
             schema: SchemaWrapper(schema),
         });
 
@@ -312,10 +404,43 @@ impl TypeEntryEnum {
         }
     }
 
+    /// For an enum whose variants represent literal values, the variant for
+    /// the given value.
+    pub(crate) fn literal_variant(&self, value: &serde_json::Value) -> Option<&Variant> {
+        if self.repr == EnumRepr::Derived {
+            return None;
+        }
+        self.variants.iter().find(|variant| {
+            serde_json::from_str::<serde_json::Value>(&variant.raw_name)
+                .ok()
+                .as_ref()
+                == Some(value)
+        })
+    }
+
+    /// Whether the enum gets a hand-written Deserialize impl that reports the
+    /// failure of each variant; see [output_untagged_deserialize].
+    pub(crate) fn has_untagged_deserialize(&self, type_space: &TypeSpace) -> bool {
+        self.tag_type == EnumTagType::Untagged
+            && self.repr == EnumRepr::Derived
+            && self.unknown_variant != Some(UnknownVariant::String)
+            && type_space.settings.detailed_untagged_errors
+    }
+
     pub(crate) fn finalize(&mut self, type_space: &TypeSpace) {
+        if self.tag_type == EnumTagType::Untagged && self.repr == EnumRepr::Derived {
+            order_untagged_variants(
+                type_space,
+                &self.name,
+                &mut self.variants,
+                self.deny_unknown_fields,
+            );
+        }
+
         self.bespoke_impls = [
-            // Not untagged with all simple variants.
+            // Not untagged with all simple variants (and not integers).
             (self.tag_type != EnumTagType::Untagged
+                && self.repr == EnumRepr::Derived
                 && !self.variants.is_empty()
                 && self
                     .variants
@@ -354,17 +479,67 @@ impl Variant {
     pub(crate) fn new(
         raw_name: String,
         description: Option<String>,
+        annotations: Annotations,
         details: VariantDetails,
     ) -> Self {
         Self {
             raw_name,
             ident_name: None,
             description,
+            annotations,
             details,
         }
     }
 }
 
+impl Annotations {
+    pub(crate) fn from_schema(schema: &Schema) -> Self {
+        let Schema::Object(SchemaObject {
+            metadata,
+            extensions,
+            ..
+        }) = schema
+        else {
+            return Self::default();
+        };
+
+        let metadata = metadata.as_deref();
+        Self {
+            deprecated: metadata.is_some_and(|m| m.deprecated),
+            read_only: metadata.is_some_and(|m| m.read_only),
+            write_only: metadata.is_some_and(|m| m.write_only),
+            comment: extensions
+                .get("$comment")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+        }
+    }
+
+    /// Documentation that combines the description with the `$comment` and
+    /// any read/write restriction.
+    pub(crate) fn doc(&self, description: Option<&str>) -> Option<String> {
+        let paragraphs = description
+            .map(str::to_string)
+            .into_iter()
+            .chain(self.comment.clone())
+            .chain(
+                self.read_only
+                    .then(|| "This value is read-only.".to_string()),
+            )
+            .chain(
+                self.write_only
+                    .then(|| "This value is write-only.".to_string()),
+            )
+            .collect::<Vec<_>>();
+
+        (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"))
+    }
+
+    pub(crate) fn deprecated_attr(&self) -> Option<TokenStream> {
+        self.deprecated.then(|| quote! { #[deprecated] })
+    }
+}
+
 impl TypeEntryStruct {
     pub(crate) fn from_metadata(
         type_space: &TypeSpace,
@@ -372,9 +547,10 @@ impl TypeEntryStruct {
         metadata: &Option<Box<Metadata>>,
         properties: Vec<StructProperty>,
         deny_unknown_fields: bool,
+        unknown_properties: bool,
         schema: Schema,
     ) -> TypeEntry {
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
         let default = metadata
@@ -392,6 +568,10 @@ impl TypeEntryStruct {
             default,
             properties,
             deny_unknown_fields,
+            unknown_properties,
+            forbidden_properties: Vec::new(),
+            pointer_types: type_patch.pointer_types,
+            bases: Vec::new(),
             schema: SchemaWrapper(schema),
         });
 
@@ -411,7 +591,7 @@ impl TypeEntryNewtype {
         type_id: TypeId,
         schema: Schema,
     ) -> TypeEntry {
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
 
@@ -442,7 +622,7 @@ impl TypeEntryNewtype {
         enum_values: &[serde_json::Value],
         schema: Schema,
     ) -> TypeEntry {
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
 
@@ -475,7 +655,7 @@ impl TypeEntryNewtype {
         enum_values: &[serde_json::Value],
         schema: Schema,
     ) -> TypeEntry {
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
 
@@ -506,9 +686,10 @@ impl TypeEntryNewtype {
         metadata: &Option<Box<Metadata>>,
         type_id: TypeId,
         validation: &schemars::schema::StringValidation,
+        not_pattern: Option<String>,
         schema: Schema,
     ) -> TypeEntry {
-        let name = get_type_name(&type_name, metadata).unwrap();
+        let name = type_space.resolve_type_name(&type_name, metadata).unwrap();
         let rename = None;
         let description = metadata_description(metadata);
 
@@ -530,6 +711,7 @@ impl TypeEntryNewtype {
                 max_length,
                 min_length,
                 pattern,
+                not_pattern,
             },
             schema: SchemaWrapper(schema),
         });
@@ -596,6 +778,24 @@ impl TypeEntry {
     pub(crate) fn finalize(&mut self, type_space: &mut TypeSpace) -> Result<()> {
         if let TypeEntryDetails::Enum(enum_details) = &mut self.details {
             enum_details.finalize(type_space);
+
+            // That Deserialize impl buffers the input as a serde_json::Value.
+            if enum_details.has_untagged_deserialize(type_space) {
+                type_space.uses_serde_json = true;
+            }
+        }
+
+        // So does the Deserialize impl that rejects forbidden properties.
+        if let TypeEntryDetails::Struct(struct_details) = &self.details {
+            if type_space.settings.forbidden_property_checks
+                && !struct_details.forbidden_properties.is_empty()
+            {
+                type_space.uses_serde_json = true;
+            }
+        }
+
+        if type_space.settings.validation_methods && validation_uses_serde_json(self) {
+            type_space.uses_serde_json = true;
         }
 
         self.check_defaults(type_space)
@@ -670,8 +870,10 @@ impl TypeEntry {
                 _ => false,
             },
             TypeEntryDetails::Native(details) => details.impls.contains(&impl_name),
-            TypeEntryDetails::Box(type_id) => {
-                if impl_name == TypeSpaceImpl::Default {
+            TypeEntryDetails::Box(type_id, pointer_type) => {
+                if impl_name == TypeSpaceImpl::Default
+                    && !matches!(pointer_type, PointerType::Custom(_))
+                {
                     let type_entry = type_space.id_to_entry.get(type_id).unwrap();
                     type_entry.has_impl(type_space, impl_name)
                 } else {
@@ -733,7 +935,63 @@ impl TypeEntry {
         }
     }
 
-    pub(crate) fn output(&self, type_space: &TypeSpace, output: &mut OutputSpace) {
+    /// Generate the code for the type; the id is that of the entry if it's
+    /// in the type space.
+    pub(crate) fn output(
+        &self,
+        type_space: &TypeSpace,
+        output: &mut OutputSpace,
+        entry_id: Option<&TypeId>,
+    ) {
+        // Code generated for a type that refers to anything deprecated would
+        // otherwise trigger warnings.
+        if self.uses_deprecated(type_space) {
+            let mut scoped = OutputSpace::default();
+            self.output_items(type_space, &mut scoped, entry_id);
+            output.extend_allow_deprecated(scoped);
+        } else {
+            self.output_items(type_space, output, entry_id);
+        }
+    }
+
+    fn uses_deprecated(&self, type_space: &TypeSpace) -> bool {
+        let refers = |type_id: &TypeId| refers_to_deprecated(type_space, type_id);
+        let prop_uses =
+            |prop: &StructProperty| prop.annotations.deprecated || refers(&prop.type_id);
+
+        match &self.details {
+            TypeEntryDetails::Enum(TypeEntryEnum {
+                variants, schema, ..
+            }) => {
+                Annotations::from_schema(&schema.0).deprecated
+                    || variants.iter().any(|variant| {
+                        variant.annotations.deprecated
+                            || match &variant.details {
+                                VariantDetails::Simple => false,
+                                VariantDetails::Item(type_id) => refers(type_id),
+                                VariantDetails::Tuple(type_ids) => type_ids.iter().any(refers),
+                                VariantDetails::Struct(props) => props.iter().any(prop_uses),
+                            }
+                    })
+            }
+            TypeEntryDetails::Struct(TypeEntryStruct {
+                properties, schema, ..
+            }) => {
+                Annotations::from_schema(&schema.0).deprecated || properties.iter().any(prop_uses)
+            }
+            TypeEntryDetails::Newtype(TypeEntryNewtype {
+                type_id, schema, ..
+            }) => Annotations::from_schema(&schema.0).deprecated || refers(type_id),
+            _ => false,
+        }
+    }
+
+    fn output_items(
+        &self,
+        type_space: &TypeSpace,
+        output: &mut OutputSpace,
+        entry_id: Option<&TypeId>,
+    ) {
         let derive_set = [
             "::serde::Serialize",
             "::serde::Deserialize",
@@ -745,13 +1003,13 @@ impl TypeEntry {
 
         match &self.details {
             TypeEntryDetails::Enum(enum_details) => {
-                self.output_enum(type_space, output, enum_details, derive_set)
+                self.output_enum(type_space, output, enum_details, derive_set, entry_id)
             }
             TypeEntryDetails::Struct(struct_details) => {
-                self.output_struct(type_space, output, struct_details, derive_set)
+                self.output_struct(type_space, output, struct_details, derive_set, entry_id)
             }
             TypeEntryDetails::Newtype(newtype_details) => {
-                self.output_newtype(type_space, output, newtype_details, derive_set)
+                self.output_newtype(type_space, output, newtype_details, derive_set, entry_id)
             }
 
             // We should never get here as reference types should only be used
@@ -769,6 +1027,7 @@ impl TypeEntry {
         output: &mut OutputSpace,
         enum_details: &TypeEntryEnum,
         mut derive_set: BTreeSet<&str>,
+        entry_id: Option<&TypeId>,
     ) {
         let TypeEntryEnum {
             name,
@@ -779,10 +1038,13 @@ impl TypeEntry {
             variants,
             deny_unknown_fields,
             bespoke_impls,
-            schema: _,
+            unknown_variant,
+            repr,
+            schema,
         } = enum_details;
 
-        let doc = make_doc(name, description.as_ref());
+        let doc = make_doc(type_space, name, description.as_ref(), schema);
+        output_examples(type_space, output, name, schema);
 
         // TODO this is a one-off for some useful traits; this should move into
         // the creation of the enum type.
@@ -790,9 +1052,31 @@ impl TypeEntry {
             .iter()
             .all(|variant| matches!(variant.details, VariantDetails::Simple))
         {
-            derive_set.extend(["Copy", "PartialOrd", "Ord", "PartialEq", "Eq", "Hash"]);
+            match unknown_variant {
+                None => derive_set.extend(["Copy", "PartialOrd", "Ord", "PartialEq", "Eq", "Hash"]),
+                Some(UnknownVariant::String) => {
+                    derive_set.extend(["PartialOrd", "Ord", "PartialEq", "Eq", "Hash"])
+                }
+                Some(UnknownVariant::Value) => derive_set.extend(["PartialEq", "Eq"]),
+            }
+        }
+
+        // An enum of simple strings with an `Unknown` variant and an enum of
+        // literal values have hand-written serde impls.
+        let serde_derived =
+            *unknown_variant != Some(UnknownVariant::String) && *repr == EnumRepr::Derived;
+        if !serde_derived {
+            derive_set.remove("::serde::Serialize");
+            derive_set.remove("::serde::Deserialize");
         }
 
+        // Untagged enums may have a hand-written Deserialize impl that
+        // reports the failure of each variant.
+        let untagged_deserialize = enum_details.has_untagged_deserialize(type_space).then(|| {
+            derive_set.remove("::serde::Deserialize");
+            output_untagged_deserialize(type_space, enum_details)
+        });
+
         let mut serde_options = Vec::new();
         if let Some(old_name) = rename {
             serde_options.push(quote! { rename = #old_name });
@@ -814,16 +1098,35 @@ impl TypeEntry {
             serde_options.push(quote! { deny_unknown_fields });
         }
 
-        let serde = (!serde_options.is_empty()).then(|| {
+        let serde = (serde_derived && !serde_options.is_empty()).then(|| {
             quote! { #[serde( #( #serde_options ),* )] }
         });
 
         let type_name = format_ident!("{}", name);
 
-        let variants_decl = variants
-            .iter()
-            .map(|variant| output_variant(variant, type_space, output, name))
-            .collect::<Vec<_>>();
+        let mut variants_decl = if *repr == EnumRepr::Integer {
+            variants.iter().map(integer_variant).collect::<Vec<_>>()
+        } else {
+            variants
+                .iter()
+                .map(|variant| output_variant(variant, type_space, output, name, serde_derived))
+                .collect::<Vec<_>>()
+        };
+        let repr_attr = (*repr == EnumRepr::Integer).then(|| quote! { #[repr(i64)] });
+        let non_exhaustive = unknown_variant.map(|unknown| {
+            variants_decl.push(match unknown {
+                UnknownVariant::String => quote! {
+                    /// A value not described by the schema.
+                    Unknown(::std::string::String),
+                },
+                UnknownVariant::Value => quote! {
+                    /// A value not described by the schema.
+                    #[serde(untagged)]
+                    Unknown(::serde_json::Value),
+                },
+            });
+            quote! { #[non_exhaustive] }
+        });
 
         // It should not be possible to construct an untagged enum
         // with more than one simple variant--it would not be usable.
@@ -851,11 +1154,26 @@ impl TypeEntry {
                     })
                     .unzip();
 
+                let (display_unknown, from_str_unknown) = match unknown_variant {
+                    None => (None, quote! { _ => Err("invalid value".into()), }),
+                    Some(UnknownVariant::String) => (
+                        Some(quote! { Self::Unknown(ref value) => f.write_str(value), }),
+                        quote! { _ => Ok(Self::Unknown(value.to_string())), },
+                    ),
+                    Some(UnknownVariant::Value) => (
+                        Some(quote! {
+                            Self::Unknown(ref value) => ::std::fmt::Display::fmt(value, f),
+                        }),
+                        quote! { _ => Err("invalid value".into()), },
+                    ),
+                };
+
                 quote! {
                     impl ::std::fmt::Display for #type_name {
                         fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                             match *self {
                                 #(Self::#match_variants => f.write_str(#match_strs),)*
+                                #display_unknown
                             }
                         }
                     }
@@ -867,7 +1185,7 @@ impl TypeEntry {
                         {
                             match value {
                                 #(#match_strs => Ok(Self::#match_variants),)*
-                                _ => Err("invalid value".into()),
+                                #from_str_unknown
                             }
                         }
                     }
@@ -903,6 +1221,176 @@ impl TypeEntry {
             }
         });
 
+        // Integer enums convert to and from their values, and serialize as
+        // them.
+        let integer_impl = (*repr == EnumRepr::Integer).then(|| {
+            let (match_variants, match_values): (Vec<_>, Vec<_>) = variants
+                .iter()
+                .map(|variant| {
+                    let ident_name = variant.ident_name.as_ref().unwrap();
+                    (format_ident!("{}", ident_name), integer_value(variant))
+                })
+                .unzip();
+
+            quote! {
+                impl ::std::convert::From<#type_name> for i64 {
+                    fn from(value: #type_name) -> Self {
+                        value as i64
+                    }
+                }
+                impl ::std::convert::TryFrom<i64> for #type_name {
+                    type Error = self::error::ConversionError;
+
+                    fn try_from(value: i64) ->
+                        ::std::result::Result<Self, self::error::ConversionError>
+                    {
+                        match value {
+                            #(#match_values => Ok(Self::#match_variants),)*
+                            _ => Err("invalid value".into()),
+                        }
+                    }
+                }
+                impl ::serde::Serialize for #type_name {
+                    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        serializer.serialize_i64(*self as i64)
+                    }
+                }
+                impl<'de> ::serde::Deserialize<'de> for #type_name {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        Self::try_from(<i64 as ::serde::Deserialize>::deserialize(deserializer)?)
+                            .map_err(<D::Error as ::serde::de::Error>::custom)
+                    }
+                }
+            }
+        });
+
+        // Literal enums serialize as their values, and are deserialized by
+        // comparing with each.
+        let literal_impl = (*repr == EnumRepr::Literal).then(|| {
+            let mut variant_names = Vec::new();
+            let mut literals = Vec::new();
+            let mut matches = Vec::new();
+            variants.iter().for_each(|variant| {
+                let ident_name = variant.ident_name.as_ref().unwrap();
+                let value = serde_json::from_str(&variant.raw_name).unwrap();
+                let literal = match &value {
+                    serde_json::Value::String(s) => quote! { #s },
+                    _ => variant.raw_name.parse::<TokenStream>().unwrap(),
+                };
+                // Numbers match by value so that, for example, `1.0` matches
+                // the literal `1`.
+                matches.push(match &value {
+                    serde_json::Value::String(_) => quote! { value.as_str() == Some(#literal) },
+                    serde_json::Value::Bool(_) => quote! { value.as_bool() == Some(#literal) },
+                    serde_json::Value::Number(n) if n.is_f64() => {
+                        quote! { value.as_f64() == Some(#literal) }
+                    }
+                    serde_json::Value::Number(n) => {
+                        let as_int = if n.is_i64() {
+                            quote! { as_i64 }
+                        } else {
+                            quote! { as_u64 }
+                        };
+                        let float = n.as_f64().unwrap();
+                        let abs = proc_macro2::Literal::f64_unsuffixed(float.abs());
+                        let float = if float < 0.0 {
+                            quote! { -#abs }
+                        } else {
+                            quote! { #abs }
+                        };
+                        quote! {
+                            value.#as_int() == Some(#literal)
+                                || (value.is_f64() && value.as_f64() == Some(#float))
+                        }
+                    }
+                    _ => unreachable!(),
+                });
+                variant_names.push(format_ident!("{}", ident_name));
+                literals.push(quote! { ::serde_json::json!(#literal) });
+            });
+
+            quote! {
+                impl #type_name {
+                    /// The literal value of this variant.
+                    pub fn to_value(self) -> ::serde_json::Value {
+                        match self {
+                            #(Self::#variant_names => #literals,)*
+                        }
+                    }
+                }
+                impl ::serde::Serialize for #type_name {
+                    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        ::serde::Serialize::serialize(&self.to_value(), serializer)
+                    }
+                }
+                impl<'de> ::serde::Deserialize<'de> for #type_name {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        let value =
+                            <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+                        #(
+                            if #matches {
+                                Ok(Self::#variant_names)
+                            } else
+                        )*
+                        {
+                            Err(<D::Error as ::serde::de::Error>::custom(format!(
+                                "invalid value: {}",
+                                value,
+                            )))
+                        }
+                    }
+                }
+            }
+        });
+
+        // Serialize and deserialize through the raw string so that values not
+        // described by the schema are preserved.
+        let unknown_serde_impl = (*unknown_variant == Some(UnknownVariant::String)).then(|| {
+            let (match_variants, match_strs): (Vec<_>, Vec<_>) = variants
+                .iter()
+                .map(|variant| {
+                    let ident_name = variant.ident_name.as_ref().unwrap();
+                    (format_ident!("{}", ident_name), &variant.raw_name)
+                })
+                .unzip();
+
+            quote! {
+                impl ::serde::Serialize for #type_name {
+                    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer,
+                    {
+                        serializer.serialize_str(match self {
+                            #(Self::#match_variants => #match_strs,)*
+                            Self::Unknown(value) => value.as_str(),
+                        })
+                    }
+                }
+                impl<'de> ::serde::Deserialize<'de> for #type_name {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?
+                            .parse()
+                            .map_err(<D::Error as ::serde::de::Error>::custom)
+                    }
+                }
+            }
+        });
+
         let untagged_newtype_from_string_impl = bespoke_impls
             .contains(&TypeEntryEnumImpl::UntaggedFromStr)
             .then(|| {
@@ -967,6 +1455,9 @@ impl TypeEntry {
                 }
             });
 
+        // Variants for which there's a `From<VariantType>` impl.
+        let mut convertible = Vec::new();
+
         let convenience_from = {
             // Build a map whose key is the type ID or type IDs of the Item and
             // Tuple variants, and whose value is a tuple of the original index
@@ -1011,6 +1502,7 @@ impl TypeEntry {
                         // TODO Strings might conflict with the way we're
                         // dealing with TryFrom<String> right now.
                         (variant_type.details != TypeEntryDetails::String).then(|| {
+                            convertible.push(variant);
                             let variant_type_ident = variant_type.type_ident(type_space, &None);
                             let variant_name =
                                 format_ident!("{}", variant.ident_name.as_ref().unwrap());
@@ -1043,6 +1535,7 @@ impl TypeEntry {
                         let variant_name =
                             format_ident!("{}", variant.ident_name.as_ref().unwrap());
                         let ii = (0..type_ids.len()).map(syn::Index::from);
+                        convertible.push(variant);
                         Some(quote! {
                             impl ::std::convert::From<#variant_type_ident> for #type_name {
                                 fn from(value: #variant_type_ident) -> Self {
@@ -1057,6 +1550,7 @@ impl TypeEntry {
                 }
             });
 
+            let variant_from = variant_from.collect::<Vec<_>>();
             quote! {
                 #( #variant_from )*
             }
@@ -1075,17 +1569,47 @@ impl TypeEntry {
             #(#attrs)*
             #[derive(#(#derives),*)]
             #serde
+            #non_exhaustive
+            #repr_attr
             pub enum #type_name {
                 #(#variants_decl)*
             }
 
             #simple_enum_impl
+            #integer_impl
+            #literal_impl
+            #unknown_serde_impl
+            #untagged_deserialize
             #default_impl
             #untagged_newtype_from_string_impl
             #untagged_newtype_to_string_impl
             #convenience_from
         };
         output.add_item(OutputSpaceMod::Crate, name, item);
+
+        if type_space.settings.enum_accessors {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                output_accessors(type_space, enum_details, &convertible),
+            );
+        }
+
+        if type_space.settings.validation_methods {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                enum_validate_impl(type_space, enum_details),
+            );
+        }
+
+        if type_space.settings.proptest_arbitrary {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                enum_arbitrary_impl(type_space, entry_id, enum_details),
+            );
+        }
     }
 
     fn output_struct(
@@ -1094,6 +1618,7 @@ impl TypeEntry {
         output: &mut OutputSpace,
         struct_details: &TypeEntryStruct,
         mut derive_set: BTreeSet<&str>,
+        entry_id: Option<&TypeId>,
     ) {
         enum PropDefault {
             None(String),
@@ -1108,9 +1633,14 @@ impl TypeEntry {
             default,
             properties,
             deny_unknown_fields,
-            schema: _,
+            unknown_properties,
+            forbidden_properties,
+            pointer_types: _,
+            bases: _,
+            schema,
         } = struct_details;
-        let doc = make_doc(name, description.as_ref());
+        let doc = make_doc(type_space, name, description.as_ref(), schema);
+        output_examples(type_space, output, name, schema);
 
         // Generate the serde directives as needed.
         let mut serde_options = Vec::new();
@@ -1130,13 +1660,30 @@ impl TypeEntry {
         let mut prop_serde = Vec::new();
         let mut prop_default = Vec::new();
         let mut prop_name = Vec::new();
+        let mut prop_path = Vec::new();
         let mut prop_error = Vec::new();
         let mut prop_type = Vec::new();
         let mut prop_type_scoped = Vec::new();
 
         properties.iter().for_each(|prop| {
-            prop_doc.push(prop.description.as_ref().map(|d| quote! { #[doc = #d] }));
+            let doc = prop
+                .annotations
+                .doc(prop.description.as_deref())
+                .map(|d| quote! { #[doc = #d] });
+            let deprecated = prop.annotations.deprecated_attr();
+            prop_doc.push(quote! {
+                #doc
+                #deprecated
+            });
             prop_name.push(format_ident!("{}", prop.name));
+            // Structured errors identify properties by their serialized
+            // names; failures of flattened properties have no name of their
+            // own.
+            prop_path.push(match &prop.rename {
+                StructPropertyRename::None => Some(prop.name.clone()),
+                StructPropertyRename::Rename(raw_name) => Some(raw_name.clone()),
+                StructPropertyRename::Flatten => None,
+            });
             prop_error.push(format!(
                 "error converting supplied value for {}: {{e}}",
                 prop.name,
@@ -1147,15 +1694,8 @@ impl TypeEntry {
             prop_type_scoped
                 .push(prop_type_entry.type_ident(type_space, &Some("super".to_string())));
 
-            let (serde, default_fn) = generate_serde_attr(
-                name,
-                &prop.name,
-                &prop.rename,
-                &prop.state,
-                prop_type_entry,
-                type_space,
-                output,
-            );
+            let (serde, default_fn) =
+                generate_serde_attr(name, prop, prop_type_entry, type_space, output);
 
             prop_serde.push(serde);
             prop_default.push(match default_fn {
@@ -1168,6 +1708,9 @@ impl TypeEntry {
                         #default_fn()
                     })
                 }
+                DefaultFunction::None if type_space.settings.structured_builder_errors => {
+                    PropDefault::None("no value supplied".to_string())
+                }
                 DefaultFunction::None => {
                     let err_msg = format!("no value supplied for {}", prop.name);
                     PropDefault::None(err_msg)
@@ -1175,6 +1718,53 @@ impl TypeEntry {
             });
         });
 
+        let split_derive_set = derive_set.clone();
+
+        // Forbidden properties have no field so serde would ignore them. If
+        // requested, we instead deserialize via a copy of the struct using
+        // serde's "remote" support after checking that none of those
+        // properties is present.
+        let forbidden_deserialize = (type_space.settings.forbidden_property_checks
+            && !forbidden_properties.is_empty()
+            && derive_set.remove("::serde::Deserialize"))
+        .then(|| {
+            let type_name_str = type_name.to_string();
+            quote! {
+                impl<'de> ::serde::Deserialize<'de> for #type_name {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        #[derive(::serde::Deserialize)]
+                        #[serde(remote = #type_name_str)]
+                        #serde
+                        struct Remote {
+                            #(
+                                #prop_serde
+                                #prop_name: #prop_type,
+                            )*
+                        }
+
+                        let value = <::serde_json::Value as ::serde::Deserialize>::deserialize(
+                            deserializer,
+                        )?;
+                        for name in [#(#forbidden_properties),*] {
+                            if value.get(name).is_some() {
+                                return Err(<D::Error as ::serde::de::Error>::custom(
+                                    format!("property \"{}\" is not permitted", name),
+                                ));
+                            }
+                        }
+                        Remote::deserialize(value).map_err(|e| {
+                            <D::Error as ::serde::de::Error>::custom(e.to_string())
+                        })
+                    }
+                }
+            }
+        });
+
         // If there's no whole-type default value and every property's default
         // is the intrinsic `Default::default()`, the hand-written `impl Default`
         // would be exactly what `#[derive(Default)]` produces (and would trip
@@ -1214,6 +1804,94 @@ impl TypeEntry {
             },
         );
 
+        if let Some(forbidden_deserialize) = forbidden_deserialize {
+            output.add_item(OutputSpaceMod::Crate, name, forbidden_deserialize);
+        }
+
+        if type_space.settings.base_conversions {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                output_base_conversions(type_space, struct_details),
+            );
+        }
+        if type_space.settings.base_traits {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                output_base_trait(type_space, struct_details),
+            );
+        }
+
+        if *unknown_properties {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                quote! {
+                    impl #type_name {
+                        /// Get the value of a property not described by the
+                        /// schema.
+                        pub fn extra_property(
+                            &self,
+                            name: &str,
+                        ) -> ::std::option::Option<&::serde_json::Value> {
+                            self.extra.get(name)
+                        }
+
+                        /// Set the value of a property not described by the
+                        /// schema, returning its previous value. The name
+                        /// should not be that of a property described by the
+                        /// schema.
+                        pub fn set_extra_property<K>(
+                            &mut self,
+                            name: K,
+                            value: ::serde_json::Value,
+                        ) -> ::std::option::Option<::serde_json::Value>
+                        where
+                            K: ::std::convert::Into<::std::string::String>,
+                        {
+                            self.extra.insert(name.into(), value)
+                        }
+                    }
+                },
+            );
+        }
+
+        if type_space.settings.request_response_types {
+            let defaults = prop_default
+                .iter()
+                .map(|pd| match pd {
+                    PropDefault::None(_) => None,
+                    PropDefault::Default(token_stream) | PropDefault::Custom(token_stream) => {
+                        Some(token_stream)
+                    }
+                })
+                .collect::<Vec<_>>();
+            self.output_request_response(
+                type_space,
+                output,
+                struct_details,
+                &split_derive_set,
+                &defaults,
+            );
+        }
+
+        if type_space.settings.validation_methods {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                struct_validate_impl(type_space, struct_details),
+            );
+        }
+
+        if type_space.settings.proptest_arbitrary {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                struct_arbitrary_impl(type_space, entry_id, struct_details),
+            );
+        }
+
         // If there's a default value, generate an impl Default
         if let Some(value) = default {
             let default_stream = self.output_value(type_space, &value.0, &quote! {}).unwrap();
@@ -1258,6 +1936,74 @@ impl TypeEntry {
             )
         }
 
+        if type_space.settings.struct_constructor {
+            let mut param_name = Vec::new();
+            let mut param_type = Vec::new();
+            let mut field_init = Vec::new();
+            let mut setter = Vec::new();
+
+            for ((prop, ty), pd) in properties.iter().zip(&prop_type).zip(&prop_default) {
+                let prop_name = format_ident!("{}", prop.name);
+                let default = match pd {
+                    PropDefault::None(_) => {
+                        field_init.push(quote! { #prop_name });
+                        param_name.push(prop_name);
+                        param_type.push(ty);
+                        continue;
+                    }
+                    PropDefault::Default(default) | PropDefault::Custom(default) => default,
+                };
+                field_init.push(quote! { #prop_name: #default });
+
+                // Optional properties are set with their inner type.
+                let setter_name = format_ident!("with_{}", prop.name);
+                let deprecated = prop.annotations.deprecated_attr();
+                let prop_type_entry = type_space.id_to_entry.get(&prop.type_id).unwrap();
+                setter.push(match (&prop.state, &prop_type_entry.details) {
+                    (StructPropertyState::Optional, TypeEntryDetails::Option(inner_id)) => {
+                        let inner_type = type_space
+                            .id_to_entry
+                            .get(inner_id)
+                            .unwrap()
+                            .type_ident(type_space, &None);
+                        quote! {
+                            #deprecated
+                            pub fn #setter_name(mut self, value: #inner_type) -> Self {
+                                self.#prop_name = Some(value);
+                                self
+                            }
+                        }
+                    }
+                    _ => quote! {
+                        #deprecated
+                        pub fn #setter_name(mut self, value: #ty) -> Self {
+                            self.#prop_name = value;
+                            self
+                        }
+                    },
+                });
+            }
+
+            let too_many =
+                (param_name.len() > 7).then(|| quote! { #[allow(clippy::too_many_arguments)] });
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                quote! {
+                    impl #type_name {
+                        #too_many
+                        pub fn new(#( #param_name: #param_type ),*) -> Self {
+                            Self {
+                                #( #field_init, )*
+                            }
+                        }
+
+                        #( #setter )*
+                    }
+                },
+            );
+        }
+
         if type_space.settings.struct_builder {
             output.add_item(
                 OutputSpaceMod::Crate,
@@ -1279,8 +2025,164 @@ impl TypeEntry {
                 quote! { value }
             };
 
+            if type_space.settings.struct_builder_style == StructBuilderStyle::Typestate {
+                let prop_default = prop_default
+                    .iter()
+                    .map(|pd| match pd {
+                        PropDefault::None(_) => None,
+                        PropDefault::Default(default_fn) => Some(default_fn.clone()),
+                        PropDefault::Custom(custom_fn) => Some(quote! { super::#custom_fn }),
+                    })
+                    .collect::<Vec<_>>();
+                output.add_item(
+                    OutputSpaceMod::Builder,
+                    name,
+                    typestate_builder(&type_name, &prop_name, &prop_type_scoped, &prop_default),
+                );
+                return;
+            }
+
+            if !type_space.settings.structured_builder_errors {
+                let prop_default = prop_default.iter().map(|pd| match pd {
+                    PropDefault::None(err_msg) => quote! { Err(#err_msg.to_string()) },
+                    PropDefault::Default(default_fn) => quote! { Ok(#default_fn) },
+                    PropDefault::Custom(custom_fn) => quote! { Ok(super::#custom_fn) },
+                });
+
+                output.add_item(
+                    OutputSpaceMod::Builder,
+                    name,
+                    quote! {
+                        #[derive(Clone, Debug)]
+                        pub struct #type_name {
+                            #(
+                                #prop_name: ::std::result::Result<#prop_type_scoped, ::std::string::String>,
+                            )*
+                        }
+
+                        impl ::std::default::Default for #type_name {
+                            fn default() -> Self {
+                                Self {
+                                    #(
+                                        #prop_name: #prop_default,
+                                    )*
+                                }
+                            }
+                        }
+
+                        impl #type_name {
+                            #(
+                                pub fn #prop_name<T>(mut self, value: T) -> Self
+                                    where
+                                        T: ::std::convert::TryInto<#prop_type_scoped>,
+                                        T::Error: ::std::fmt::Display,
+                                {
+                                    self.#prop_name = value.try_into()
+                                        .map_err(|e| format!(#prop_error));
+                                    self
+                                }
+                            )*
+                        }
+
+                        // This is how the item is built.
+                        impl ::std::convert::TryFrom<#type_name>
+                            for super::#type_name
+                        {
+                            type Error = super::error::ConversionError;
+
+                            fn try_from(#value_ident: #type_name)
+                                -> ::std::result::Result<Self, super::error::ConversionError>
+                            {
+                                Ok(Self {
+                                    #(
+                                        #prop_name: value.#prop_name?,
+                                    )*
+                                })
+                            }
+                        }
+
+                        // Construct a builder from the item.
+                        impl ::std::convert::From<super::#type_name> for #type_name {
+                            fn from(#value_ident: super::#type_name) -> Self {
+                                Self {
+                                    #(
+                                        #prop_name: Ok(value.#prop_name),
+                                    )*
+                                }
+                            }
+                        }
+                    },
+                );
+                return;
+            }
+
+            // Array properties may also be set from a sequence of items, each
+            // of which is converted in turn so that failures are reported
+            // with the index of the offending item.
+            let items_setter = properties
+                .iter()
+                .zip(&prop_name)
+                .filter_map(|(prop, prop_name)| {
+                    let TypeEntryDetails::Vec(item_id) =
+                        &type_space.id_to_entry.get(&prop.type_id)?.details
+                    else {
+                        return None;
+                    };
+                    let setter_name = format!("{}_items", prop.name);
+                    if properties.iter().any(|p| p.name == setter_name) {
+                        return None;
+                    }
+                    let setter_name = format_ident!("{}", setter_name);
+                    let item_type = type_space
+                        .id_to_entry
+                        .get(item_id)
+                        .unwrap()
+                        .type_ident(type_space, &Some("super".to_string()));
+                    Some(quote! {
+                        pub fn #setter_name<I>(mut self, items: I) -> Self
+                            where
+                                I: ::std::iter::IntoIterator,
+                                I::Item: ::std::convert::TryInto<#item_type>,
+                                <I::Item as ::std::convert::TryInto<#item_type>>::Error:
+                                    ::std::fmt::Display + 'static,
+                        {
+                            self.#prop_name = super::error::ConversionError::collect(items);
+                            self
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            // Gather the failures from every property rather than stopping
+            // at the first.
+            let prop_failure =
+                prop_name
+                    .iter()
+                    .zip(&prop_path)
+                    .map(|(prop_name, path)| match path {
+                        Some(path) => quote! { #prop_name.err().map(|e| e.nest(#path)) },
+                        None => quote! { #prop_name.err() },
+                    });
+            let try_from_body = if prop_name.is_empty() {
+                quote! { Ok(Self {}) }
+            } else {
+                quote! {
+                    match ( #( value.#prop_name, )* ) {
+                        ( #( Ok(#prop_name), )* ) => Ok(Self {
+                            #( #prop_name, )*
+                        }),
+                        ( #( #prop_name, )* ) => Err([
+                            #( #prop_failure, )*
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .collect()),
+                    }
+                }
+            };
+
             let prop_default = prop_default.iter().map(|pd| match pd {
-                PropDefault::None(err_msg) => quote! { Err(#err_msg.to_string()) },
+                PropDefault::None(err_msg) => quote! { Err(#err_msg.into()) },
                 PropDefault::Default(default_fn) => quote! { Ok(#default_fn) },
                 PropDefault::Custom(custom_fn) => quote! { Ok(super::#custom_fn) },
             });
@@ -1292,7 +2194,10 @@ impl TypeEntry {
                     #[derive(Clone, Debug)]
                     pub struct #type_name {
                         #(
-                            #prop_name: ::std::result::Result<#prop_type_scoped, ::std::string::String>,
+                            #prop_name: ::std::result::Result<
+                                #prop_type_scoped,
+                                super::error::ConversionError,
+                            >,
                         )*
                     }
 
@@ -1311,13 +2216,14 @@ impl TypeEntry {
                             pub fn #prop_name<T>(mut self, value: T) -> Self
                                 where
                                     T: ::std::convert::TryInto<#prop_type_scoped>,
-                                    T::Error: ::std::fmt::Display,
+                                    T::Error: ::std::fmt::Display + 'static,
                             {
                                 self.#prop_name = value.try_into()
-                                    .map_err(|e| format!(#prop_error));
+                                    .map_err(super::error::ConversionError::from_error);
                                 self
                             }
                         )*
+                        #( #items_setter )*
                     }
 
                     // This is how the item is built.
@@ -1329,11 +2235,7 @@ impl TypeEntry {
                         fn try_from(#value_ident: #type_name)
                             -> ::std::result::Result<Self, super::error::ConversionError>
                         {
-                            Ok(Self {
-                                #(
-                                    #prop_name: value.#prop_name?,
-                                )*
-                            })
+                            #try_from_body
                         }
                     }
 
@@ -1352,12 +2254,242 @@ impl TypeEntry {
         }
     }
 
+    /// For structs with read-only or write-only properties, emit
+    /// `{name}Request` and `{name}Response` types that omit those properties
+    /// respectively, along with conversions to and from the full type.
+    fn output_request_response(
+        &self,
+        type_space: &TypeSpace,
+        output: &mut OutputSpace,
+        struct_details: &TypeEntryStruct,
+        derive_set: &BTreeSet<&str>,
+        defaults: &[Option<&TokenStream>],
+    ) {
+        let TypeEntryStruct {
+            name,
+            properties,
+            deny_unknown_fields,
+            ..
+        } = struct_details;
+
+        if !properties
+            .iter()
+            .any(|prop| prop.annotations.read_only || prop.annotations.write_only)
+        {
+            return;
+        }
+
+        type Omit = fn(&Annotations) -> bool;
+        let splits: [(_, _, Omit); 2] = [
+            (
+                "Request",
+                "read-only properties, for use in requests",
+                |annotations| annotations.read_only,
+            ),
+            (
+                "Response",
+                "write-only properties, for use in responses",
+                |annotations| annotations.write_only,
+            ),
+        ];
+
+        let type_name = format_ident!("{}", name);
+        let serde = deny_unknown_fields.then(|| quote! { #[serde(deny_unknown_fields)] });
+
+        for (suffix, omitted, omit) in splits {
+            let split_name = format!("{}{}", name, suffix);
+            if type_space.name_to_id.contains_key(&split_name) {
+                info!(
+                    "not generating {} as it would conflict with an existing type",
+                    split_name,
+                );
+                continue;
+            }
+            let split_ident = format_ident!("{}", split_name);
+            let doc = format!("`{}` without its {}.", name, omitted);
+
+            let mut field_decl = Vec::new();
+            let mut kept_name = Vec::new();
+            let mut omitted_name = Vec::new();
+            let mut omitted_default = Vec::new();
+            let mut builder_state = Vec::new();
+
+            for (prop, default) in properties.iter().zip(defaults) {
+                let prop_name = format_ident!("{}", prop.name);
+                let omitted = omit(&prop.annotations);
+
+                // The state of the typestate builder's parameter for each
+                // required property.
+                if default.is_none() {
+                    builder_state.push(if omitted {
+                        quote! { () }
+                    } else {
+                        type_space
+                            .id_to_entry
+                            .get(&prop.type_id)
+                            .unwrap()
+                            .type_ident(type_space, &Some("super".to_string()))
+                    });
+                }
+
+                if omitted {
+                    omitted_default.push(default.map(|default| quote! { #prop_name: #default }));
+                    omitted_name.push(prop_name);
+                    continue;
+                }
+
+                // Nested types are used as-is rather than as their own
+                // split types.
+                if refers_to_read_write_only(type_space, &prop.type_id) {
+                    info!(
+                        "{}.{} keeps its full type rather than a {} type",
+                        split_name, prop.name, suffix,
+                    );
+                }
+
+                let prop_doc = prop
+                    .annotations
+                    .doc(prop.description.as_deref())
+                    .map(|d| quote! { #[doc = #d] });
+                let prop_deprecated = prop.annotations.deprecated_attr();
+                let prop_type_entry = type_space.id_to_entry.get(&prop.type_id).unwrap();
+                let prop_type = prop_type_entry.type_ident(type_space, &None);
+
+                // The split types already omit the properties that would
+                // otherwise be skipped during (de)serialization.
+                let split_prop = StructProperty {
+                    annotations: Annotations {
+                        read_only: false,
+                        write_only: false,
+                        ..prop.annotations.clone()
+                    },
+                    ..prop.clone()
+                };
+                let (prop_serde, _) = generate_serde_attr(
+                    &split_name,
+                    &split_prop,
+                    prop_type_entry,
+                    type_space,
+                    output,
+                );
+
+                field_decl.push(quote! {
+                    #prop_doc
+                    #prop_deprecated
+                    #prop_serde
+                    pub #prop_name: #prop_type,
+                });
+                kept_name.push(prop_name);
+            }
+
+            let derives = strings_to_derives(
+                derive_set.clone(),
+                &self.extra_derives,
+                &type_space.settings.extra_derives,
+            );
+            let attrs = strings_to_attrs(&self.extra_attrs, &type_space.settings.extra_attrs);
+
+            let value_ident = if kept_name.is_empty() {
+                quote! { _value }
+            } else {
+                quote! { value }
+            };
+
+            // The full type can be constructed from the split type only if
+            // all omitted properties have default values.
+            let from_split = omitted_default
+                .iter()
+                .cloned()
+                .collect::<Option<Vec<_>>>()
+                .map(|omitted_default| {
+                    quote! {
+                        impl ::std::convert::From<#split_ident> for #type_name {
+                            fn from(#value_ident: #split_ident) -> Self {
+                                Self {
+                                    #( #kept_name: value.#kept_name, )*
+                                    #( #omitted_default, )*
+                                }
+                            }
+                        }
+                    }
+                });
+
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                quote! {
+                    #[doc = #doc]
+                    #(#attrs)*
+                    #[derive(#(#derives),*)]
+                    #serde
+                    pub struct #split_ident {
+                        #( #field_decl )*
+                    }
+
+                    impl ::std::convert::From<#type_name> for #split_ident {
+                        fn from(#value_ident: #type_name) -> Self {
+                            Self {
+                                #( #kept_name: value.#kept_name, )*
+                            }
+                        }
+                    }
+
+                    #from_split
+                },
+            );
+
+            // With a builder, the omitted properties can be supplied before
+            // converting to the full type.
+            if !type_space.settings.struct_builder {
+                continue;
+            }
+            let builder_from = match type_space.settings.struct_builder_style {
+                StructBuilderStyle::Fallible => {
+                    let rest =
+                        (!omitted_default.is_empty()).then(|| quote! { ..Default::default() });
+                    quote! {
+                        impl ::std::convert::From<super::#split_ident> for #type_name {
+                            fn from(#value_ident: super::#split_ident) -> Self {
+                                Self {
+                                    #( #kept_name: Ok(value.#kept_name), )*
+                                    #rest
+                                }
+                            }
+                        }
+                    }
+                }
+                StructBuilderStyle::Typestate => {
+                    let state =
+                        (!builder_state.is_empty()).then(|| quote! { <#(#builder_state),*> });
+                    let unset = (!omitted_name.is_empty()).then(|| {
+                        quote! {
+                            let unset = <#type_name as ::std::default::Default>::default();
+                        }
+                    });
+                    quote! {
+                        impl ::std::convert::From<super::#split_ident> for #type_name #state {
+                            fn from(#value_ident: super::#split_ident) -> Self {
+                                #unset
+                                Self {
+                                    #( #kept_name: value.#kept_name, )*
+                                    #( #omitted_name: unset.#omitted_name, )*
+                                }
+                            }
+                        }
+                    }
+                }
+            };
+            output.add_item(OutputSpaceMod::Builder, name, builder_from);
+        }
+    }
+
     fn output_newtype<'a>(
         &self,
         type_space: &'a TypeSpace,
         output: &mut OutputSpace,
         newtype_details: &TypeEntryNewtype,
         mut derive_set: BTreeSet<&'a str>,
+        entry_id: Option<&TypeId>,
     ) {
         let TypeEntryNewtype {
             name,
@@ -1366,9 +2498,10 @@ impl TypeEntry {
             default,
             type_id,
             constraints,
-            schema: _,
+            schema,
         } = newtype_details;
-        let doc = make_doc(name, description.as_ref());
+        let doc = make_doc(type_space, name, description.as_ref(), schema);
+        output_examples(type_space, output, name, schema);
 
         let type_name = format_ident!("{}", name);
         let inner_type = type_space.id_to_entry.get(type_id).unwrap();
@@ -1592,6 +2725,7 @@ impl TypeEntry {
                 max_length,
                 min_length,
                 pattern,
+                not_pattern,
             } => {
                 let max = max_length.map(|v| {
                     let v = v as usize;
@@ -1624,6 +2758,18 @@ impl TypeEntry {
                     }
                 });
 
+                let not_pat = not_pattern.as_ref().map(|p| {
+                    let err = format!("matches excluded pattern \"{}\"", p);
+                    quote! {
+                        static NOT_PATTERN: ::std::sync::LazyLock<::regress::Regex> = ::std::sync::LazyLock::new(|| {
+                            ::regress::Regex::new(#p).unwrap()
+                        });
+                        if NOT_PATTERN.find(value).is_some() {
+                            return Err(#err.into());
+                        }
+                    }
+                });
+
                 // We're going to impl Deserialize so we can remove it
                 // from the set of derived impls.
                 derive_set.remove("::serde::Deserialize");
@@ -1638,6 +2784,7 @@ impl TypeEntry {
                             #max
                             #min
                             #pat
+                            #not_pat
 
                             Ok(Self(value.to_string()))
                         }
@@ -1729,6 +2876,22 @@ impl TypeEntry {
             #constraint_impl
         };
         output.add_item(OutputSpaceMod::Crate, name, item);
+
+        if type_space.settings.validation_methods {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                newtype_validate_impl(type_space, newtype_details),
+            );
+        }
+
+        if type_space.settings.proptest_arbitrary {
+            output.add_item(
+                OutputSpaceMod::Crate,
+                name,
+                newtype_arbitrary_impl(type_space, entry_id, newtype_details),
+            );
+        }
     }
 
     pub(crate) fn type_name(&self, type_space: &TypeSpace) -> String {
@@ -1766,20 +2929,21 @@ impl TypeEntry {
                 // Flatten nested Option types. This would only happen if the
                 // schema encoded it; it's an odd construction.
                 match &inner_ty.details {
-                    TypeEntryDetails::Option(_) => inner_ident,
+                    TypeEntryDetails::Option(_) if !is_double_option(type_space, id) => inner_ident,
                     _ => quote! { ::std::option::Option<#inner_ident> },
                 }
             }
 
-            TypeEntryDetails::Box(id) => {
+            TypeEntryDetails::Box(id, pointer_type) => {
                 let inner_ty = type_space
                     .id_to_entry
                     .get(id)
                     .expect("unresolved type id for box");
 
                 let item = inner_ty.type_ident(type_space, type_mod);
+                let pointer = pointer_type.path();
 
-                quote! { ::std::boxed::Box<#item> }
+                quote! { #pointer<#item> }
             }
 
             TypeEntryDetails::Vec(id) => {
@@ -1927,7 +3091,7 @@ impl TypeEntry {
             | TypeEntryDetails::Vec(_)
             | TypeEntryDetails::Map(..)
             | TypeEntryDetails::Set(_)
-            | TypeEntryDetails::Box(_)
+            | TypeEntryDetails::Box(..)
             | TypeEntryDetails::Native(_)
             | TypeEntryDetails::Array(..)
             | TypeEntryDetails::JsonValue => {
@@ -1947,7 +3111,7 @@ impl TypeEntry {
                 // Flatten nested Option types. This would only happen if the
                 // schema encoded it; it's an odd construction.
                 match &inner_ty.details {
-                    TypeEntryDetails::Option(_) => inner_ident,
+                    TypeEntryDetails::Option(_) if !is_double_option(type_space, id) => inner_ident,
                     _ => quote! { Option<#inner_ident> },
                 }
             }
@@ -1998,7 +3162,10 @@ impl TypeEntry {
                 format!("map {} {}", key_id.0, value_id.0)
             }
             TypeEntryDetails::Set(type_id) => format!("set {}", type_id.0),
-            TypeEntryDetails::Box(type_id) => format!("box {}", type_id.0),
+            TypeEntryDetails::Box(type_id, PointerType::Box) => format!("box {}", type_id.0),
+            TypeEntryDetails::Box(type_id, pointer_type) => {
+                format!("pointer {} {}", pointer_type.path(), type_id.0)
+            }
             TypeEntryDetails::Tuple(type_ids) => {
                 format!(
                     "tuple ({})",
@@ -2027,13 +3194,289 @@ impl TypeEntry {
     }
 }
 
-fn make_doc(name: &str, description: Option<&String>) -> TokenStream {
+fn make_doc(
+    type_space: &TypeSpace,
+    name: &str,
+    description: Option<&String>,
+    schema: &SchemaWrapper,
+) -> TokenStream {
     let desc = match description {
         Some(desc) => desc,
         None => &format!("`{}`", name),
     };
+    let annotations = Annotations::from_schema(&schema.0);
+    let desc = &annotations.doc(Some(desc)).unwrap();
+    let deprecated = annotations.deprecated_attr();
+
+    let examples = schema_examples(schema);
+    if !type_space.settings.doc_examples || examples.is_empty() {
+        return quote! {
+            #[doc = #desc]
+            #deprecated
+        };
+    }
 
-    quote! { #[doc = #desc] }
+    let examples = examples
+        .iter()
+        .map(|example| {
+            format!(
+                "```json\n{}\n```",
+                serde_json::to_string_pretty(example).unwrap()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let desc = format!("{}\n\n# Examples\n\n{}", desc, examples);
+
+    quote! {
+        #[doc = #desc]
+        #deprecated
+    }
+}
+
+/// The value of a variant of an integer enum as an (unsuffixed) literal.
+fn integer_value(variant: &Variant) -> TokenStream {
+    let value = variant.raw_name.parse::<i64>().unwrap();
+    let lit = proc_macro2::Literal::u64_unsuffixed(value.unsigned_abs());
+    if value < 0 {
+        quote! { -#lit }
+    } else {
+        quote! { #lit }
+    }
+}
+
+/// The declaration of a variant of an integer enum with its discriminant.
+fn integer_variant(variant: &Variant) -> TokenStream {
+    let variant_name = format_ident!("{}", variant.ident_name.as_ref().unwrap());
+    let doc = variant
+        .annotations
+        .doc(variant.description.as_deref())
+        .map(|s| quote! { #[doc = #s] });
+    let value = integer_value(variant);
+    quote! {
+        #doc
+        #variant_name = #value,
+    }
+}
+
+/// Produce a builder whose type parameters track which required properties
+/// have been set: each is `()` until the corresponding setter is called, and
+/// `build()` exists only once all are set. Properties with defaults (i.e. for
+/// which `prop_default` is `Some`) may be set in any state.
+fn typestate_builder(
+    type_name: &syn::Ident,
+    prop_name: &[syn::Ident],
+    prop_type: &[TokenStream],
+    prop_default: &[Option<TokenStream>],
+) -> TokenStream {
+    let mut param = Vec::new();
+    let mut set_type = Vec::new();
+    let mut field_type = Vec::new();
+    let mut field_init = Vec::new();
+    let mut setter = Vec::new();
+
+    for ((name, ty), default) in prop_name.iter().zip(prop_type).zip(prop_default) {
+        match default {
+            Some(default) => {
+                field_type.push(ty.clone());
+                field_init.push(default.clone());
+            }
+            None => {
+                let state = format_ident!("{}State", name.to_string().to_pascal_case());
+                field_type.push(state.to_token_stream());
+                field_init.push(quote! { () });
+                param.push(state);
+                set_type.push(ty.clone());
+            }
+        }
+    }
+
+    let generics = (!param.is_empty()).then(|| quote! { <#(#param),*> });
+    let generics_decl = (!param.is_empty()).then(|| quote! { <#(#param = ()),*> });
+    let unset = (!param.is_empty()).then(|| {
+        let unit = param.iter().map(|_| quote! { () });
+        quote! { <#(#unit),*> }
+    });
+    let set = (!param.is_empty()).then(|| quote! { <#(#set_type),*> });
+
+    let mut state_index = 0;
+    for (ii, ((name, ty), default)) in prop_name
+        .iter()
+        .zip(prop_type)
+        .zip(prop_default)
+        .enumerate()
+    {
+        if default.is_some() {
+            setter.push(quote! {
+                pub fn #name<T>(mut self, value: T) -> Self
+                where
+                    T: ::std::convert::Into<#ty>,
+                {
+                    self.#name = value.into();
+                    self
+                }
+            });
+            continue;
+        }
+
+        // Setting a required property changes the type of its state
+        // parameter; every other property is moved across unchanged.
+        let result_param = param.iter().enumerate().map(|(jj, p)| {
+            if jj == state_index {
+                ty.clone()
+            } else {
+                p.to_token_stream()
+            }
+        });
+        let field_value = prop_name.iter().enumerate().map(|(jj, other)| {
+            if jj == ii {
+                quote! { value.into() }
+            } else {
+                quote! { self.#other }
+            }
+        });
+        setter.push(quote! {
+            pub fn #name<T>(self, value: T) -> #type_name<#(#result_param),*>
+            where
+                T: ::std::convert::Into<#ty>,
+            {
+                #type_name {
+                    #( #prop_name: #field_value, )*
+                }
+            }
+        });
+        state_index += 1;
+    }
+
+    // If there are no properties, all of this is kind of pointless, but at
+    // least this lets us avoid the lint warning.
+    let value_ident = if prop_name.is_empty() {
+        quote! { _value }
+    } else {
+        quote! { value }
+    };
+
+    quote! {
+        #[derive(Clone, Debug)]
+        pub struct #type_name #generics_decl {
+            #( #prop_name: #field_type, )*
+        }
+
+        impl ::std::default::Default for #type_name #unset {
+            fn default() -> Self {
+                Self {
+                    #( #prop_name: #field_init, )*
+                }
+            }
+        }
+
+        impl #generics #type_name #generics {
+            #( #setter )*
+        }
+
+        impl #type_name #set {
+            /// Construct the item; this is available once all required
+            /// properties have been set.
+            pub fn build(self) -> super::#type_name {
+                super::#type_name {
+                    #( #prop_name: self.#prop_name, )*
+                }
+            }
+        }
+
+        // Construct a builder from the item.
+        impl ::std::convert::From<super::#type_name> for #type_name #set {
+            fn from(#value_ident: super::#type_name) -> Self {
+                Self {
+                    #( #prop_name: value.#prop_name, )*
+                }
+            }
+        }
+    }
+}
+
+/// Emit a test that each of the schema's examples deserializes into the type
+/// and serializes back to the same value.
+fn output_examples(
+    type_space: &TypeSpace,
+    output: &mut OutputSpace,
+    name: &str,
+    schema: &SchemaWrapper,
+) {
+    let examples = schema_examples(schema);
+    if !type_space.settings.example_tests || examples.is_empty() {
+        return;
+    }
+
+    let type_name = format_ident!("{}", name);
+    let test_name = format_ident!("{}_examples", sanitize(name, Case::Snake));
+    let examples = examples
+        .iter()
+        .map(|example| serde_json::to_string(example).unwrap());
+
+    output.add_item(
+        OutputSpaceMod::Examples,
+        name,
+        quote! {
+            #[test]
+            fn #test_name() {
+                const EXAMPLES: &[&str] = &[#(#examples),*];
+                for example in EXAMPLES {
+                    let value: ::serde_json::Value = ::serde_json::from_str(example).unwrap();
+                    let typed: super::#type_name =
+                        ::serde_json::from_value(value.clone()).unwrap();
+                    assert_eq!(::serde_json::to_value(&typed).unwrap(), value);
+                }
+            }
+        },
+    );
+}
+
+fn schema_examples(schema: &SchemaWrapper) -> &[serde_json::Value] {
+    match &schema.0 {
+        Schema::Object(SchemaObject {
+            metadata: Some(metadata),
+            ..
+        }) => &metadata.examples,
+        _ => &[],
+    }
+}
+
+/// Whether the type is, or contains, a named type that is deprecated.
+fn refers_to_deprecated(type_space: &TypeSpace, type_id: &TypeId) -> bool {
+    let refers = |type_id: &TypeId| refers_to_deprecated(type_space, type_id);
+    match &type_space.id_to_entry.get(type_id).unwrap().details {
+        TypeEntryDetails::Enum(TypeEntryEnum { schema, .. })
+        | TypeEntryDetails::Struct(TypeEntryStruct { schema, .. })
+        | TypeEntryDetails::Newtype(TypeEntryNewtype { schema, .. }) => {
+            Annotations::from_schema(&schema.0).deprecated
+        }
+        TypeEntryDetails::Option(type_id)
+        | TypeEntryDetails::Box(type_id, _)
+        | TypeEntryDetails::Vec(type_id)
+        | TypeEntryDetails::Set(type_id)
+        | TypeEntryDetails::Array(type_id, _) => refers(type_id),
+        TypeEntryDetails::Map(key_id, value_id) => refers(key_id) || refers(value_id),
+        TypeEntryDetails::Tuple(type_ids) => type_ids.iter().any(refers),
+        _ => false,
+    }
+}
+
+fn refers_to_read_write_only(type_space: &TypeSpace, type_id: &TypeId) -> bool {
+    let refers = |type_id: &TypeId| refers_to_read_write_only(type_space, type_id);
+    match &type_space.id_to_entry.get(type_id).unwrap().details {
+        TypeEntryDetails::Struct(TypeEntryStruct { properties, .. }) => properties
+            .iter()
+            .any(|prop| prop.annotations.read_only || prop.annotations.write_only),
+        TypeEntryDetails::Option(type_id)
+        | TypeEntryDetails::Box(type_id, _)
+        | TypeEntryDetails::Vec(type_id)
+        | TypeEntryDetails::Set(type_id)
+        | TypeEntryDetails::Array(type_id, _) => refers(type_id),
+        TypeEntryDetails::Map(key_id, value_id) => refers(key_id) || refers(value_id),
+        TypeEntryDetails::Tuple(type_ids) => type_ids.iter().any(refers),
+        _ => false,
+    }
 }
 
 fn strings_to_derives<'a>(
@@ -2160,6 +3603,10 @@ mod tests {
             default: None,
             properties: vec![],
             deny_unknown_fields: false,
+            unknown_properties: false,
+            forbidden_properties: Vec::new(),
+            pointer_types: Default::default(),
+            bases: Vec::new(),
             schema: SchemaWrapper(schemars::schema::Schema::Bool(false)),
         }));
 
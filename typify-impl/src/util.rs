@@ -10,7 +10,10 @@ use schemars::schema::{
 };
 use unicode_ident::{is_xid_continue, is_xid_start};
 
-use crate::{validate::schema_value_validate, Error, Name, RefKey, Result, TypeSpace};
+use crate::{
+    validate::schema_value_validate, AnyOfStrategy, Error, Name, PointerType, RefKey, Result,
+    TypeSpace,
+};
 
 pub(crate) fn metadata_description(metadata: &Option<Box<Metadata>>) -> Option<String> {
     metadata
@@ -544,6 +547,362 @@ pub(crate) fn constant_string_value(schema: &Schema) -> Option<&str> {
     }
 }
 
+/// An `if`/`then`/`else` conditional.
+pub(crate) struct Conditional<'a> {
+    pub if_schema: &'a Schema,
+    pub then_schema: Option<&'a Schema>,
+    pub else_schema: Option<&'a Schema>,
+}
+
+/// Separate the conditionals of a schema--its own `if`/`then`/`else` and those
+/// of any `allOf` subschemas that consist of nothing else--from the `allOf`
+/// subschemas that remain.
+pub(crate) fn split_conditionals(
+    subschemas: &SubschemaValidation,
+) -> (Vec<Conditional<'_>>, Vec<&Schema>) {
+    fn conditional(subschemas: &SubschemaValidation) -> Option<Conditional<'_>> {
+        Some(Conditional {
+            if_schema: subschemas.if_schema.as_deref()?,
+            then_schema: subschemas.then_schema.as_deref(),
+            else_schema: subschemas.else_schema.as_deref(),
+        })
+    }
+
+    let mut conditionals = Vec::from_iter(conditional(subschemas));
+    let mut all_of = Vec::new();
+    for schema in subschemas.all_of.iter().flatten() {
+        match schema {
+            Schema::Object(SchemaObject {
+                metadata: _,
+                instance_type: None,
+                format: None,
+                enum_values: None,
+                const_value: None,
+                subschemas: Some(inner),
+                number: None,
+                string: None,
+                array: None,
+                object: None,
+                reference: None,
+                extensions: _,
+            }) if inner.all_of.is_none()
+                && inner.any_of.is_none()
+                && inner.one_of.is_none()
+                && inner.not.is_none() =>
+            {
+                match conditional(inner) {
+                    Some(cond) => conditionals.push(cond),
+                    None => all_of.push(schema),
+                }
+            }
+            _ => all_of.push(schema),
+        }
+    }
+
+    (conditionals, all_of)
+}
+
+/// If this schema tests only that a single property has a constant string
+/// value, return the name of that property, the value, and whether the
+/// property must be present for the test to pass.
+pub(crate) fn property_test(schema: &Schema) -> Option<(&str, &str, bool)> {
+    let Schema::Object(SchemaObject {
+        metadata: _,
+        instance_type,
+        format: None,
+        enum_values: None,
+        const_value: None,
+        subschemas: None,
+        number: None,
+        string: None,
+        array: None,
+        object: Some(validation),
+        reference: None,
+        extensions: _,
+    }) = schema
+    else {
+        return None;
+    };
+    match instance_type {
+        None => {}
+        Some(SingleOrVec::Single(it)) if **it == InstanceType::Object => {}
+        _ => return None,
+    }
+
+    let ObjectValidation {
+        max_properties: None,
+        min_properties: None,
+        required,
+        properties,
+        pattern_properties,
+        additional_properties: None,
+        property_names: None,
+    } = validation.as_ref()
+    else {
+        return None;
+    };
+
+    let mut props = properties.iter();
+    let (prop_name, prop_schema) = props.next()?;
+    if props.next().is_some()
+        || !pattern_properties.is_empty()
+        || required.iter().any(|name| name != prop_name)
+    {
+        return None;
+    }
+
+    let value = constant_string_value(prop_schema)?;
+    Some((prop_name, value, required.contains(prop_name)))
+}
+
+/// If this schema only requires properties, return their names.
+pub(crate) fn required_only(schema: &Schema) -> Option<&BTreeSet<String>> {
+    match schema {
+        Schema::Object(SchemaObject {
+            metadata: _,
+            instance_type: None,
+            format: None,
+            enum_values: None,
+            const_value: None,
+            subschemas: None,
+            number: None,
+            string: None,
+            array: None,
+            object: Some(validation),
+            reference: None,
+            extensions: _,
+        }) => match validation.as_ref() {
+            ObjectValidation {
+                max_properties: None,
+                min_properties: None,
+                required,
+                properties,
+                pattern_properties,
+                additional_properties: None,
+                property_names: None,
+            } if properties.is_empty() && pattern_properties.is_empty() => Some(required),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Values and string patterns excluded from an otherwise simple schema by a
+/// `not` subschema. Merging produces these when it can't subtract a `not`
+/// schema any other way; conversion turns them into newtypes that reject the
+/// excluded values.
+#[derive(Debug, Default)]
+pub(crate) struct Exclusions {
+    pub values: Vec<serde_json::Value>,
+    pub patterns: Vec<String>,
+}
+
+impl Exclusions {
+    /// Recognize subschemas that consist only of excluded values and
+    /// patterns.
+    pub fn from_subschemas(subschemas: &SubschemaValidation) -> Option<Self> {
+        match subschemas {
+            SubschemaValidation {
+                all_of: None,
+                any_of: None,
+                one_of: None,
+                not: Some(not),
+                if_schema: None,
+                then_schema: None,
+                else_schema: None,
+            } => Self::from_not(not),
+            _ => None,
+        }
+    }
+
+    fn from_not(schema: &Schema) -> Option<Self> {
+        let Schema::Object(schema_object) = schema else {
+            return None;
+        };
+        match schema_object {
+            SchemaObject {
+                metadata: _,
+                instance_type: None,
+                format: None,
+                enum_values: Some(values),
+                const_value: None,
+                subschemas: None,
+                number: None,
+                string: None,
+                array: None,
+                object: None,
+                reference: None,
+                extensions: _,
+            } => Some(Self {
+                values: values.clone(),
+                patterns: Vec::new(),
+            }),
+
+            SchemaObject {
+                metadata: _,
+                instance_type,
+                format: None,
+                enum_values: None,
+                const_value: None,
+                subschemas: None,
+                number: None,
+                string: Some(validation),
+                array: None,
+                object: None,
+                reference: None,
+                extensions: _,
+            } => {
+                match instance_type {
+                    None => {}
+                    Some(SingleOrVec::Single(it)) if **it == InstanceType::String => {}
+                    _ => return None,
+                }
+                match validation.as_ref() {
+                    StringValidation {
+                        max_length: None,
+                        min_length: None,
+                        pattern: Some(pattern),
+                    } => Some(Self {
+                        values: Vec::new(),
+                        patterns: vec![pattern.clone()],
+                    }),
+                    _ => None,
+                }
+            }
+
+            SchemaObject {
+                metadata: _,
+                instance_type: None,
+                format: None,
+                enum_values: None,
+                const_value: None,
+                subschemas: Some(subschemas),
+                number: None,
+                string: None,
+                array: None,
+                object: None,
+                reference: None,
+                extensions: _,
+            } => match subschemas.as_ref() {
+                SubschemaValidation {
+                    all_of: None,
+                    any_of: Some(any_of),
+                    one_of: None,
+                    not: None,
+                    if_schema: None,
+                    then_schema: None,
+                    else_schema: None,
+                } => any_of.iter().try_fold(Self::default(), |mut acc, schema| {
+                    acc.extend(Self::from_not(schema)?);
+                    Some(acc)
+                }),
+                _ => None,
+            },
+
+            _ => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.patterns.is_empty()
+    }
+
+    fn extend(&mut self, other: Self) {
+        for value in other.values {
+            if !self.values.contains(&value) {
+                self.values.push(value);
+            }
+        }
+        for pattern in other.patterns {
+            if !self.patterns.contains(&pattern) {
+                self.patterns.push(pattern);
+            }
+        }
+    }
+
+    /// Patterns may only be excluded from plain strings.
+    pub fn accepts_patterns(schema_object: &SchemaObject) -> bool {
+        matches!(
+            schema_object,
+            SchemaObject {
+                instance_type: Some(SingleOrVec::Single(it)),
+                format: None,
+                enum_values: None,
+                const_value: None,
+                reference: None,
+                ..
+            } if **it == InstanceType::String
+        )
+    }
+
+    /// Whether conversion can apply these exclusions to the schema. We
+    /// leave the permissive schema to the conversion of `not` schemas.
+    pub fn applies_to(&self, schema_object: &SchemaObject) -> bool {
+        let base = SchemaObject {
+            metadata: None,
+            subschemas: None,
+            extensions: Default::default(),
+            ..schema_object.clone()
+        };
+        base != SchemaObject::default()
+            && (self.patterns.is_empty() || Self::accepts_patterns(schema_object))
+    }
+
+    /// Record these exclusions as the `not` subschema of the schema, combining
+    /// them with any exclusions already there. If the schema has some other
+    /// `not` subschema we leave it be, accepting the excluded values.
+    pub fn exclude_from(mut self, schema_object: &mut SchemaObject) {
+        if self.is_empty() {
+            return;
+        }
+        let subschemas = schema_object.subschemas();
+        if let Some(not) = &subschemas.not {
+            match Self::from_not(not) {
+                Some(existing) => {
+                    let mut combined = existing;
+                    combined.extend(self);
+                    self = combined;
+                }
+                None => {
+                    debug!("unable to record exclusions {:?}", self);
+                    return;
+                }
+            }
+        }
+
+        let Self { values, patterns } = self;
+        let mut not_schemas = Vec::new();
+        if !values.is_empty() {
+            not_schemas.push(Schema::Object(SchemaObject {
+                enum_values: Some(values),
+                ..Default::default()
+            }));
+        }
+        not_schemas.extend(patterns.into_iter().map(|pattern| {
+            Schema::Object(SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                string: Some(Box::new(StringValidation {
+                    pattern: Some(pattern),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })
+        }));
+
+        let not = match not_schemas.len() {
+            1 => not_schemas.pop().unwrap(),
+            _ => Schema::Object(SchemaObject {
+                subschemas: Some(Box::new(SubschemaValidation {
+                    any_of: Some(not_schemas),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }),
+        };
+        subschemas.not = Some(Box::new(not));
+    }
+}
+
 fn decode_segment(segment: &str) -> String {
     segment.replace("~1", "/").replace("~0", "~")
 }
@@ -860,6 +1219,10 @@ pub(crate) struct TypePatch {
     pub name: String,
     pub derives: BTreeSet<String>,
     pub attrs: BTreeSet<String>,
+    pub unknown_variant: bool,
+    pub preserve_unknown_properties: bool,
+    pub any_of_strategy: AnyOfStrategy,
+    pub pointer_types: BTreeMap<String, PointerType>,
 }
 
 impl TypePatch {
@@ -870,17 +1233,34 @@ impl TypePatch {
                 name: type_name,
                 derives: Default::default(),
                 attrs: Default::default(),
+                unknown_variant: type_space.settings.unknown_variants,
+                preserve_unknown_properties: type_space.settings.preserve_unknown_properties,
+                any_of_strategy: type_space.settings.any_of_strategy,
+                pointer_types: Default::default(),
             },
 
             Some(patch) => {
                 let name = patch.rename.clone().unwrap_or(type_name);
                 let derives = patch.derives.iter().cloned().collect();
                 let attrs = patch.attrs.iter().cloned().collect();
+                let unknown_variant = patch
+                    .unknown_variant
+                    .unwrap_or(type_space.settings.unknown_variants);
+                let preserve_unknown_properties = patch
+                    .preserve_unknown_properties
+                    .unwrap_or(type_space.settings.preserve_unknown_properties);
+                let any_of_strategy = patch
+                    .any_of_strategy
+                    .unwrap_or(type_space.settings.any_of_strategy);
 
                 Self {
                     name,
                     derives,
                     attrs,
+                    unknown_variant,
+                    preserve_unknown_properties,
+                    any_of_strategy,
+                    pointer_types: patch.pointer_types.clone(),
                 }
             }
         }
@@ -0,0 +1,988 @@
+// Copyright 2026 Oxide Computer Company
+
+use std::collections::BTreeMap;
+
+use log::warn;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use schemars::schema::{ArrayValidation, ObjectValidation, Schema, SchemaObject};
+
+use crate::{
+    type_entry::{
+        StructProperty, StructPropertyRename, StructPropertyState, TypeEntry, TypeEntryDetails,
+        TypeEntryEnum, TypeEntryNewtype, TypeEntryStruct, VariantDetails, WrappedValue,
+    },
+    util::{property_test, required_only, split_conditionals, Conditional},
+    TypeId, TypeSpace,
+};
+
+/// Object-level constraints that can't be expressed in the shape of a
+/// generated struct. These are enforced by the generated `validate()` method.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct StructValidation {
+    pub min_properties: Option<u32>,
+    pub max_properties: Option<u32>,
+    /// Map from a (raw) property name to the properties that must be present
+    /// if it is present. This comes from `dependentRequired` or from the
+    /// array form of `dependencies`.
+    pub dependent_required: BTreeMap<String, Vec<String>>,
+    /// Properties required depending on whether another property has a
+    /// particular value. This comes from `if`/`then`/`else` conditionals.
+    pub conditional_required: Vec<ConditionalRequired>,
+}
+
+/// Properties required when (`then`) or unless (`else`) a property has a
+/// particular string value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct ConditionalRequired {
+    pub property: String,
+    pub value: String,
+    /// Whether the property must be present for the test to pass.
+    pub present: bool,
+    pub then_required: Vec<String>,
+    pub else_required: Vec<String>,
+}
+
+/// Array-level constraints that can't be expressed in the shape of a
+/// generated `Vec`. These are enforced by the generated `validate()` method.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct ItemsValidation {
+    pub min_items: Option<u32>,
+    pub max_items: Option<u32>,
+    pub unique_items: bool,
+    /// At least one item must be equal to one of these values. We only
+    /// handle `contains` schemas that are a `const` or an `enum`.
+    pub contains: Option<Vec<WrappedValue>>,
+}
+
+impl StructValidation {
+    fn from_schema(schema: &Schema) -> Self {
+        match schema {
+            Schema::Object(SchemaObject {
+                object: Some(validation),
+                subschemas,
+                extensions,
+                ..
+            }) => {
+                let mut this = Self::new(validation, extensions);
+                if let Some(subschemas) = subschemas {
+                    this.conditional_required = split_conditionals(subschemas)
+                        .0
+                        .into_iter()
+                        .filter_map(ConditionalRequired::new)
+                        .collect();
+                }
+                this
+            }
+            _ => Self::default(),
+        }
+    }
+
+    fn new(
+        validation: &ObjectValidation,
+        extensions: &schemars::Map<String, serde_json::Value>,
+    ) -> Self {
+        // schemars doesn't model `dependencies` or `dependentRequired` so we
+        // find them among the extensions. We only handle the array form of
+        // `dependencies`; the schema form would require a subschema check.
+        let dependent_required = ["dependencies", "dependentRequired"]
+            .into_iter()
+            .filter_map(|key| extensions.get(key)?.as_object())
+            .flatten()
+            .filter_map(|(prop_name, value)| {
+                let required = value
+                    .as_array()?
+                    .iter()
+                    .map(|v| v.as_str().map(String::from))
+                    .collect::<Option<Vec<_>>>()?;
+                Some((prop_name.clone(), required))
+            })
+            .collect();
+
+        Self {
+            min_properties: validation.min_properties,
+            max_properties: validation.max_properties,
+            dependent_required,
+            conditional_required: Vec::new(),
+        }
+    }
+}
+
+impl ConditionalRequired {
+    fn new(conditional: Conditional<'_>) -> Option<Self> {
+        let (property, value, present) = property_test(conditional.if_schema)?;
+        let required = |schema: Option<&Schema>| match schema {
+            None => Some(Vec::new()),
+            Some(schema) => Some(required_only(schema)?.iter().cloned().collect()),
+        };
+        Some(Self {
+            property: property.to_string(),
+            value: value.to_string(),
+            present,
+            then_required: required(conditional.then_schema)?,
+            else_required: required(conditional.else_schema)?,
+        })
+    }
+}
+
+impl ItemsValidation {
+    fn from_schema(schema: &Schema) -> Self {
+        match schema {
+            Schema::Object(SchemaObject {
+                array: Some(validation),
+                ..
+            }) => Self::new(validation),
+            _ => Self::default(),
+        }
+    }
+
+    fn new(validation: &ArrayValidation) -> Self {
+        let contains = validation.contains.as_deref().and_then(contains_values);
+
+        Self {
+            min_items: validation.min_items,
+            max_items: validation.max_items,
+            unique_items: validation.unique_items == Some(true),
+            contains,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Whether the checks compare the serialized forms of items.
+    fn compares_values(&self) -> bool {
+        self.unique_items || self.contains.is_some()
+    }
+}
+
+/// The values an array must contain (at least one of) according to its
+/// `contains` schema if that schema just lists them; we can't check others.
+fn contains_values(schema: &Schema) -> Option<Vec<WrappedValue>> {
+    match schema {
+        Schema::Object(SchemaObject {
+            const_value: Some(value),
+            ..
+        }) => Some(vec![WrappedValue::new(value.clone())]),
+        Schema::Object(SchemaObject {
+            enum_values: Some(values),
+            ..
+        }) => Some(values.iter().cloned().map(WrappedValue::new).collect()),
+        _ => None,
+    }
+}
+
+/// Whether an array's `contains` schema (if any) is checked, i.e. whether
+/// we generate `validate()` methods and they can express it.
+pub(crate) fn contains_checked(type_space: &TypeSpace, contains: Option<&Schema>) -> bool {
+    contains.is_none_or(|schema| {
+        type_space.settings.validation_methods && contains_values(schema).is_some()
+    })
+}
+
+/// Whether the `validate()` method of the type compares serialized values and
+/// therefore requires the `serde_json` crate. Constraints of the schema that
+/// the method doesn't check are reported along the way.
+pub(crate) fn validation_uses_serde_json(type_entry: &TypeEntry) -> bool {
+    match &type_entry.details {
+        TypeEntryDetails::Struct(TypeEntryStruct { name, schema, .. }) => {
+            report_unchecked(name, &schema.0);
+            let validation = StructValidation::from_schema(&schema.0);
+            let items = match &schema.0 {
+                Schema::Object(SchemaObject {
+                    object: Some(object),
+                    ..
+                }) => object
+                    .properties
+                    .values()
+                    .map(ItemsValidation::from_schema)
+                    .any(|items| items.compares_values()),
+                _ => false,
+            };
+            !validation.conditional_required.is_empty() || items
+        }
+        TypeEntryDetails::Newtype(TypeEntryNewtype { name, schema, .. }) => {
+            report_unchecked(name, &schema.0);
+            ItemsValidation::from_schema(&schema.0).compares_values()
+        }
+        _ => false,
+    }
+}
+
+/// Report the constraints of the schema that the `validate()` method can't
+/// check: `not` and the schema form of `dependencies`.
+fn report_unchecked(name: &str, schema: &Schema) {
+    let Schema::Object(SchemaObject {
+        subschemas,
+        extensions,
+        ..
+    }) = schema
+    else {
+        return;
+    };
+
+    if subschemas.as_ref().is_some_and(|s| s.not.is_some()) {
+        warn!(
+            "the not constraint of {} is not checked by its validate() method",
+            name
+        );
+    }
+
+    ["dependencies", "dependentSchemas"]
+        .into_iter()
+        .filter_map(|key| extensions.get(key)?.as_object())
+        .flatten()
+        .filter(|(_, value)| !value.is_array())
+        .for_each(|(prop_name, _)| {
+            warn!(
+                "the schema dependency of {} on {} is not checked by its validate() method",
+                name, prop_name,
+            );
+        });
+}
+
+/// The `validate()` method for a struct.
+pub(crate) fn struct_validate_impl(
+    type_space: &TypeSpace,
+    struct_details: &TypeEntryStruct,
+) -> TokenStream {
+    let TypeEntryStruct {
+        name,
+        properties,
+        schema,
+        ..
+    } = struct_details;
+
+    let type_name = format_ident!("{}", name);
+
+    let validation = StructValidation::from_schema(&schema.0);
+    let property_schemas = match &schema.0 {
+        Schema::Object(SchemaObject {
+            object: Some(object),
+            ..
+        }) => Some(&object.properties),
+        _ => None,
+    };
+
+    let mut checks = Vec::new();
+
+    // Properties we don't know about may be captured by a flattened map of
+    // additional properties.
+    let extra = properties.iter().find_map(|prop| {
+        let StructPropertyRename::Flatten = &prop.rename else {
+            return None;
+        };
+        let prop_type = type_space.id_to_entry.get(&prop.type_id).unwrap();
+        let TypeEntryDetails::Map(..) = &prop_type.details else {
+            return None;
+        };
+        Some(format_ident!("{}", prop.name))
+    });
+    let presence = |name: &str| match properties.iter().find(|prop| {
+        !matches!(prop.rename, StructPropertyRename::Flatten) && raw_name(prop) == name
+    }) {
+        Some(prop) => Presence::new(type_space, prop),
+        None => match &extra {
+            Some(extra) => Presence::Sometimes {
+                present: quote! { self.#extra.contains_key(#name) },
+                absent: quote! { !self.#extra.contains_key(#name) },
+            },
+            None => Presence::Never,
+        },
+    };
+
+    if validation.min_properties.is_some() || validation.max_properties.is_some() {
+        let mut counts = properties
+            .iter()
+            .map(|prop| {
+                let prop_name = format_ident!("{}", prop.name);
+                let prop_type = type_space.id_to_entry.get(&prop.type_id).unwrap();
+                match (&prop.rename, &prop_type.details) {
+                    (StructPropertyRename::Flatten, TypeEntryDetails::Map(..)) => {
+                        quote! { self.#prop_name.len() }
+                    }
+                    (StructPropertyRename::Flatten, _) => quote! { 0 },
+                    _ => match Presence::new(type_space, prop) {
+                        Presence::Sometimes { present, .. } => quote! { usize::from(#present) },
+                        Presence::Always => quote! { 1 },
+                        Presence::Never => quote! { 0 },
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+        if counts.is_empty() {
+            counts.push(quote! { 0 });
+        }
+        let min = validation.min_properties.map(|v| {
+            let v = v as usize;
+            let err = format!("fewer than {} properties", v);
+            quote! {
+                if count < #v {
+                    errors.push("", #err);
+                }
+            }
+        });
+        let max = validation.max_properties.map(|v| {
+            let v = v as usize;
+            let err = format!("more than {} properties", v);
+            quote! {
+                if count > #v {
+                    errors.push("", #err);
+                }
+            }
+        });
+        checks.push(quote! {
+            let count: usize = #( #counts )+*;
+            #min
+            #max
+        });
+    }
+
+    for (prop_name, required) in &validation.dependent_required {
+        let check = presence(prop_name);
+        for req in required {
+            let err = format!("required by {}", prop_name);
+            let condition = match (&check, presence(req)) {
+                // Nothing to check if the dependent property is never present
+                // or the required property is always present.
+                (Presence::Never, _) | (_, Presence::Always) => continue,
+                (Presence::Always, Presence::Never) => None,
+                (Presence::Always, Presence::Sometimes { absent, .. }) => Some(absent),
+                (Presence::Sometimes { present, .. }, Presence::Never) => Some(present.clone()),
+                (Presence::Sometimes { present, .. }, Presence::Sometimes { absent, .. }) => {
+                    Some(quote! { #present && #absent })
+                }
+            };
+            checks.push(match condition {
+                Some(condition) => quote! {
+                    if #condition {
+                        errors.push(#req, #err);
+                    }
+                },
+                None => quote! {
+                    errors.push(#req, #err);
+                },
+            });
+        }
+    }
+
+    for conditional in &validation.conditional_required {
+        let ConditionalRequired {
+            property,
+            value,
+            present,
+            then_required,
+            else_required,
+        } = conditional;
+
+        // Whether the value of the property is the one tested for (or, if
+        // the test permits, absent).
+        let matched = match properties.iter().find(|prop| {
+            !matches!(prop.rename, StructPropertyRename::Flatten) && raw_name(prop) == property
+        }) {
+            Some(prop) => {
+                let prop_name = format_ident!("{}", prop.name);
+                match Presence::new(type_space, prop) {
+                    Presence::Sometimes { .. }
+                        if matches!(
+                            type_space.id_to_entry.get(&prop.type_id).unwrap().details,
+                            TypeEntryDetails::Option(_)
+                        ) =>
+                    {
+                        let absent = !present;
+                        quote! {
+                            match &self.#prop_name {
+                                Some(value) => ::serde_json::to_value(value)
+                                    .is_ok_and(|v| v == #value),
+                                None => #absent,
+                            }
+                        }
+                    }
+                    _ => quote! {
+                        ::serde_json::to_value(&self.#prop_name).is_ok_and(|v| v == #value)
+                    },
+                }
+            }
+            None => match &extra {
+                Some(extra) => {
+                    let absent = !present;
+                    quote! {
+                        self.#extra.get(#property).map_or(#absent, |v| v == #value)
+                    }
+                }
+                None => {
+                    let absent = !present;
+                    quote! { #absent }
+                }
+            },
+        };
+
+        let then_err = format!("required when {} is {:?}", property, value);
+        let else_err = format!("required unless {} is {:?}", property, value);
+        let required_checks = then_required
+            .iter()
+            .map(|req| (req, quote! { matched }, &then_err))
+            .chain(
+                else_required
+                    .iter()
+                    .map(|req| (req, quote! { !matched }, &else_err)),
+            )
+            .filter_map(|(req, condition, err)| {
+                let condition = match presence(req) {
+                    Presence::Always => return None,
+                    Presence::Never => condition,
+                    Presence::Sometimes { absent, .. } => quote! { #condition && #absent },
+                };
+                Some(quote! {
+                    if #condition {
+                        errors.push(#req, #err);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if !required_checks.is_empty() {
+            checks.push(quote! {
+                let matched = #matched;
+                #( #required_checks )*
+            });
+        }
+    }
+
+    checks.extend(properties.iter().filter_map(|prop| {
+        let prop_name = format_ident!("{}", prop.name);
+        let items = property_schemas
+            .and_then(|schemas| schemas.get(raw_name(prop)))
+            .map(ItemsValidation::from_schema)
+            .unwrap_or_default();
+        property_checks(type_space, prop, &items, quote! { self.#prop_name })
+    }));
+
+    validate_impl(&type_name, checks)
+}
+
+/// The `validate()` method for an enum.
+pub(crate) fn enum_validate_impl(
+    type_space: &TypeSpace,
+    enum_details: &TypeEntryEnum,
+) -> TokenStream {
+    let TypeEntryEnum { name, variants, .. } = enum_details;
+
+    let type_name = format_ident!("{}", name);
+
+    let arms = variants
+        .iter()
+        .filter_map(|variant| {
+            let variant_name = format_ident!("{}", variant.ident_name.as_ref().unwrap());
+            match &variant.details {
+                VariantDetails::Simple => None,
+                VariantDetails::Item(type_id) => {
+                    let check = value_checks(type_space, type_id, quote! { value }, quote! { "" })?;
+                    Some(quote! {
+                        Self::#variant_name(value) => {
+                            #check
+                        }
+                    })
+                }
+                VariantDetails::Tuple(type_ids) => {
+                    let (bindings, checks): (Vec<_>, Vec<_>) = type_ids
+                        .iter()
+                        .enumerate()
+                        .map(|(ii, type_id)| {
+                            let binding = format_ident!("value{}", ii);
+                            let path = ii.to_string();
+                            match value_checks(
+                                type_space,
+                                type_id,
+                                quote! { #binding },
+                                quote! { #path },
+                            ) {
+                                Some(check) => (quote! { #binding }, Some(check)),
+                                None => (quote! { _ }, None),
+                            }
+                        })
+                        .unzip();
+                    let checks = checks.into_iter().flatten().collect::<Vec<_>>();
+                    if checks.is_empty() {
+                        return None;
+                    }
+                    Some(quote! {
+                        Self::#variant_name(#( #bindings ),*) => {
+                            #( #checks )*
+                        }
+                    })
+                }
+                VariantDetails::Struct(properties) => {
+                    let (bindings, checks): (Vec<_>, Vec<_>) = properties
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(ii, prop)| {
+                            let prop_name = format_ident!("{}", prop.name);
+                            let binding = format_ident!("value{}", ii);
+                            let check = property_checks(
+                                type_space,
+                                prop,
+                                &ItemsValidation::default(),
+                                quote! { #binding },
+                            )?;
+                            Some((quote! { #prop_name: #binding }, check))
+                        })
+                        .unzip();
+                    if checks.is_empty() {
+                        return None;
+                    }
+                    Some(quote! {
+                        Self::#variant_name { #( #bindings, )* .. } => {
+                            #( #checks )*
+                        }
+                    })
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let checks = if arms.is_empty() {
+        vec![]
+    } else {
+        let wildcard = (arms.len() != variants.len() || enum_details.unknown_variant.is_some())
+            .then(|| quote! { _ => {} });
+        vec![quote! {
+            match self {
+                #( #arms )*
+                #wildcard
+            }
+        }]
+    };
+
+    validate_impl(&type_name, checks)
+}
+
+/// The `validate()` method for a newtype.
+pub(crate) fn newtype_validate_impl(
+    type_space: &TypeSpace,
+    newtype_details: &TypeEntryNewtype,
+) -> TokenStream {
+    let TypeEntryNewtype {
+        name,
+        type_id,
+        schema,
+        ..
+    } = newtype_details;
+
+    let type_name = format_ident!("{}", name);
+
+    let items = ItemsValidation::from_schema(&schema.0);
+    let checks = items_checks(
+        type_space,
+        type_id,
+        &items,
+        quote! { self.0 },
+        quote! { "" },
+    )
+    .into_iter()
+    .chain(value_checks(
+        type_space,
+        type_id,
+        quote! { self.0 },
+        quote! { "" },
+    ))
+    .collect();
+
+    validate_impl(&type_name, checks)
+}
+
+fn validate_impl(type_name: &syn::Ident, checks: Vec<TokenStream>) -> TokenStream {
+    let body = if checks.is_empty() {
+        quote! { Ok(()) }
+    } else {
+        quote! {
+            let mut errors = self::error::ValidationErrors::default();
+            #( #checks )*
+            errors.into_result()
+        }
+    };
+
+    quote! {
+        impl #type_name {
+            /// Check the constraints from the schema that aren't enforced
+            /// by the structure of this type, including those of any nested
+            /// types.
+            pub fn validate(&self)
+                -> ::std::result::Result<(), self::error::ValidationErrors>
+            {
+                #body
+            }
+        }
+    }
+}
+
+/// The name of the property as it appears in serialized form.
+fn raw_name(prop: &StructProperty) -> &str {
+    match &prop.rename {
+        StructPropertyRename::Rename(raw) => raw,
+        StructPropertyRename::None | StructPropertyRename::Flatten => &prop.name,
+    }
+}
+
+/// Whether a property would be present in the serialized form.
+enum Presence {
+    Always,
+    Never,
+    Sometimes {
+        present: TokenStream,
+        absent: TokenStream,
+    },
+}
+
+impl Presence {
+    fn new(type_space: &TypeSpace, prop: &StructProperty) -> Self {
+        let prop_name = format_ident!("{}", prop.name);
+        let prop_type = type_space.id_to_entry.get(&prop.type_id).unwrap();
+        match (&prop.state, &prop_type.details) {
+            (StructPropertyState::Optional, TypeEntryDetails::Option(_)) => Self::Sometimes {
+                present: quote! { self.#prop_name.is_some() },
+                absent: quote! { self.#prop_name.is_none() },
+            },
+            (StructPropertyState::Optional, TypeEntryDetails::Vec(_))
+            | (StructPropertyState::Optional, TypeEntryDetails::Map(..)) => Self::Sometimes {
+                present: quote! { !self.#prop_name.is_empty() },
+                absent: quote! { self.#prop_name.is_empty() },
+            },
+            _ => Self::Always,
+        }
+    }
+}
+
+/// Checks for a struct property: its own array constraints (if any) as well
+/// as a recursive check of the value.
+fn property_checks(
+    type_space: &TypeSpace,
+    prop: &StructProperty,
+    items: &ItemsValidation,
+    access: TokenStream,
+) -> Option<TokenStream> {
+    let path = match &prop.rename {
+        StructPropertyRename::Flatten => "",
+        _ => raw_name(prop),
+    };
+    let path = quote! { #path };
+
+    let items = items_checks(
+        type_space,
+        &prop.type_id,
+        items,
+        access.clone(),
+        path.clone(),
+    );
+    let values = value_checks(type_space, &prop.type_id, access, path);
+
+    match (items, values) {
+        (None, None) => None,
+        (items, values) => Some(quote! {
+            #items
+            #values
+        }),
+    }
+}
+
+/// Checks for array constraints. The `access` expression must be a place or
+/// reference of the type identified by `type_id`.
+fn items_checks(
+    type_space: &TypeSpace,
+    type_id: &TypeId,
+    validation: &ItemsValidation,
+    access: TokenStream,
+    path: TokenStream,
+) -> Option<TokenStream> {
+    if validation.is_empty() {
+        return None;
+    }
+
+    let type_entry = type_space.id_to_entry.get(type_id).unwrap();
+    match &type_entry.details {
+        TypeEntryDetails::Option(inner_id) => {
+            let check = items_checks(type_space, inner_id, validation, quote! { value }, path)?;
+            Some(quote! {
+                if let Some(value) = #access.as_ref() {
+                    #check
+                }
+            })
+        }
+        TypeEntryDetails::Box(inner_id, _) => {
+            items_checks(type_space, inner_id, validation, access, path)
+        }
+        TypeEntryDetails::Vec(_) | TypeEntryDetails::Set(_) | TypeEntryDetails::Array(..) => {
+            let ItemsValidation {
+                min_items,
+                max_items,
+                unique_items,
+                contains,
+            } = validation;
+
+            // Avoid comparisons that clippy would prefer as `is_empty()`.
+            let min = min_items.filter(|v| *v > 0).map(|v| {
+                let err = format!("fewer than {} items", v);
+                let condition = if v == 1 {
+                    quote! { #access.is_empty() }
+                } else {
+                    let v = v as usize;
+                    quote! { #access.len() < #v }
+                };
+                quote! {
+                    if #condition {
+                        errors.push(#path, #err);
+                    }
+                }
+            });
+            let max = max_items.map(|v| {
+                let err = format!("more than {} items", v);
+                let condition = if v == 0 {
+                    quote! { !#access.is_empty() }
+                } else {
+                    let v = v as usize;
+                    quote! { #access.len() > #v }
+                };
+                quote! {
+                    if #condition {
+                        errors.push(#path, #err);
+                    }
+                }
+            });
+            // Generated types don't necessarily implement `PartialEq` or
+            // `Hash` so we compare the serialized forms.
+            let unique = unique_items.then(|| {
+                quote! {
+                    let values = #access
+                        .iter()
+                        .filter_map(|item| ::serde_json::to_value(item).ok())
+                        .collect::<::std::vec::Vec<_>>();
+                    if (1..values.len()).any(|ii| values[..ii].contains(&values[ii])) {
+                        errors.push(#path, "items are not unique");
+                    }
+                }
+            });
+            let contains = contains.as_ref().map(|values| {
+                let len = values.len();
+                let value_string = values
+                    .iter()
+                    .map(|value| serde_json::to_string(&value.0).unwrap());
+                let err = format!(
+                    "no item matches {}",
+                    values
+                        .iter()
+                        .map(|value| value.0.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                );
+                quote! {
+                    let contains: [::serde_json::Value; #len] = [
+                        #( ::serde_json::from_str(#value_string).unwrap(), )*
+                    ];
+                    if !#access.iter().any(|item| {
+                        ::serde_json::to_value(item).is_ok_and(|v| contains.contains(&v))
+                    }) {
+                        errors.push(#path, #err);
+                    }
+                }
+            });
+
+            Some(quote! {
+                #min
+                #max
+                #unique
+                #contains
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Recursive checks for nested generated types. The `access` expression must
+/// be a place or reference of the type identified by `type_id`.
+fn value_checks(
+    type_space: &TypeSpace,
+    type_id: &TypeId,
+    access: TokenStream,
+    path: TokenStream,
+) -> Option<TokenStream> {
+    let type_entry = type_space.id_to_entry.get(type_id).unwrap();
+    match &type_entry.details {
+        TypeEntryDetails::Enum(_) | TypeEntryDetails::Struct(_) | TypeEntryDetails::Newtype(_) => {
+            Some(quote! {
+                if let Err(e) = #access.validate() {
+                    errors.nest(#path, e);
+                }
+            })
+        }
+
+        TypeEntryDetails::Option(inner_id) => {
+            let inner = type_space.id_to_entry.get(inner_id).unwrap();
+            // Nested options are flattened in the generated type.
+            if let TypeEntryDetails::Option(_) = &inner.details {
+                return value_checks(type_space, inner_id, access, path);
+            }
+            let check = value_checks(type_space, inner_id, quote! { value }, path)?;
+            Some(quote! {
+                if let Some(value) = #access.as_ref() {
+                    #check
+                }
+            })
+        }
+
+        TypeEntryDetails::Box(inner_id, _) => value_checks(type_space, inner_id, access, path),
+
+        TypeEntryDetails::Vec(item_id)
+        | TypeEntryDetails::Set(item_id)
+        | TypeEntryDetails::Array(item_id, _) => {
+            let check = value_checks(
+                type_space,
+                item_id,
+                quote! { item },
+                quote! { &format!("{}[{}]", #path, index) },
+            )?;
+            Some(quote! {
+                for (index, item) in #access.iter().enumerate() {
+                    #check
+                }
+            })
+        }
+
+        TypeEntryDetails::Map(_, value_id) => {
+            let check = value_checks(
+                type_space,
+                value_id,
+                quote! { value },
+                quote! { &self::error::ValidationErrors::join(#path, key) },
+            )?;
+            Some(quote! {
+                for (key, value) in #access.iter() {
+                    #check
+                }
+            })
+        }
+
+        TypeEntryDetails::Tuple(type_ids) => {
+            let checks = type_ids
+                .iter()
+                .enumerate()
+                .filter_map(|(ii, item_id)| {
+                    let index = syn::Index::from(ii);
+                    let item_path = ii.to_string();
+                    value_checks(
+                        type_space,
+                        item_id,
+                        quote! { #access.#index },
+                        quote! { &self::error::ValidationErrors::join(#path, #item_path) },
+                    )
+                })
+                .collect::<Vec<_>>();
+            (!checks.is_empty()).then(|| quote! { #( #checks )* })
+        }
+
+        TypeEntryDetails::Native(_)
+        | TypeEntryDetails::Unit
+        | TypeEntryDetails::Boolean
+        | TypeEntryDetails::Integer(_)
+        | TypeEntryDetails::Float(_)
+        | TypeEntryDetails::String
+        | TypeEntryDetails::JsonValue => None,
+
+        TypeEntryDetails::Reference(_) => unreachable!(),
+    }
+}
+
+/// The error types used by generated `validate()` methods.
+pub(crate) fn validation_errors() -> TokenStream {
+    quote! {
+        /// A constraint violation reported by a `validate()` method.
+        #[derive(Clone, Debug)]
+        pub struct ValidationError {
+            /// The location of the offending value e.g. `spec.items[0].name`;
+            /// empty for the value being validated.
+            pub path: ::std::string::String,
+            /// A description of the violated constraint.
+            pub message: ::std::string::String,
+        }
+
+        impl ::std::fmt::Display for ValidationError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                -> Result<(), ::std::fmt::Error>
+            {
+                if self.path.is_empty() {
+                    f.write_str(&self.message)
+                } else {
+                    write!(f, "{}: {}", self.path, self.message)
+                }
+            }
+        }
+
+        /// Error from a `validate()` method; contains every violation found.
+        #[derive(Clone, Debug, Default)]
+        pub struct ValidationErrors(::std::vec::Vec<ValidationError>);
+
+        impl ValidationErrors {
+            /// All constraint violations.
+            pub fn errors(&self) -> &[ValidationError] {
+                &self.0
+            }
+
+            /// Record a violation at the given path.
+            pub fn push<P, M>(&mut self, path: P, message: M)
+            where
+                P: ::std::convert::Into<::std::string::String>,
+                M: ::std::convert::Into<::std::string::String>,
+            {
+                self.0.push(ValidationError {
+                    path: path.into(),
+                    message: message.into(),
+                });
+            }
+
+            /// Record the violations of a nested value found at the given
+            /// path.
+            pub fn nest(&mut self, path: &str, other: Self) {
+                self.0.extend(other.0.into_iter().map(|error| ValidationError {
+                    path: Self::join(path, &error.path),
+                    ..error
+                }));
+            }
+
+            /// Join two path components.
+            pub fn join(prefix: &str, suffix: &str) -> ::std::string::String {
+                if prefix.is_empty() {
+                    suffix.to_string()
+                } else if suffix.is_empty() {
+                    prefix.to_string()
+                } else if suffix.starts_with('[') {
+                    format!("{}{}", prefix, suffix)
+                } else {
+                    format!("{}.{}", prefix, suffix)
+                }
+            }
+
+            /// `Ok(())` if there are no violations.
+            pub fn into_result(self) -> Result<(), Self> {
+                if self.0.is_empty() {
+                    Ok(())
+                } else {
+                    Err(self)
+                }
+            }
+        }
+
+        impl ::std::error::Error for ValidationErrors {}
+        impl ::std::fmt::Display for ValidationErrors {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>)
+                -> Result<(), ::std::fmt::Error>
+            {
+                for (ii, error) in self.0.iter().enumerate() {
+                    if ii > 0 {
+                        f.write_str("; ")?;
+                    }
+                    error.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -7,8 +7,9 @@ use quote::{format_ident, quote};
 
 use crate::{
     convert::STD_NUM_NONZERO_PREFIX,
+    structs::is_double_option,
     type_entry::{
-        EnumTagType, StructProperty, StructPropertyRename, TypeEntry, TypeEntryDetails,
+        EnumRepr, EnumTagType, StructProperty, StructPropertyRename, TypeEntry, TypeEntryDetails,
         TypeEntryEnum, TypeEntryNative, TypeEntryNewtype, TypeEntryStruct, Variant, VariantDetails,
     },
     TypeId, TypeSpace,
@@ -29,6 +30,18 @@ impl TypeEntry {
         scope: &TokenStream,
     ) -> Option<TokenStream> {
         let v = match &self.details {
+            TypeEntryDetails::Enum(
+                details @ TypeEntryEnum {
+                    name,
+                    repr: EnumRepr::Integer | EnumRepr::Literal,
+                    ..
+                },
+            ) => {
+                let variant = details.literal_variant(value)?;
+                let var_ident = format_ident!("{}", &variant.ident_name.as_ref().unwrap());
+                let type_ident = format_ident!("{}", name);
+                quote! { #scope #type_ident::#var_ident }
+            }
             TypeEntryDetails::Enum(TypeEntryEnum {
                 name,
                 tag_type,
@@ -67,7 +80,15 @@ impl TypeEntry {
 
             TypeEntryDetails::Option(type_id) => {
                 if let serde_json::Value::Null = value {
-                    quote! { ::std::option::Option::None }
+                    // A null (rather than absent) value of an
+                    // `Option<Option<T>>` is `Some(None)`.
+                    if is_double_option(type_space, type_id) {
+                        quote! {
+                            ::std::option::Option::Some(::std::option::Option::None)
+                        }
+                    } else {
+                        quote! { ::std::option::Option::None }
+                    }
                 } else {
                     let inner = type_space
                         .id_to_entry
@@ -77,13 +98,14 @@ impl TypeEntry {
                     quote! { ::std::option::Option::Some(#inner) }
                 }
             }
-            TypeEntryDetails::Box(type_id) => {
+            TypeEntryDetails::Box(type_id, pointer_type) => {
                 let inner = type_space
                     .id_to_entry
                     .get(type_id)
                     .unwrap()
                     .output_value(type_space, value, scope)?;
-                quote! { ::std::boxed::Box::new(#inner) }
+                let pointer = pointer_type.path();
+                quote! { #pointer::new(#inner) }
             }
             // TODO: this should become a HashSet<_> once we figure out the
             // derives more precisely.
@@ -437,7 +459,7 @@ fn value_for_struct_props(
             }
 
             let flat_value = type_entry.output_value(type_space, &extra_value, scope)?;
-            let name = &prop.name;
+            let name = format_ident!("{}", &prop.name);
             Some(quote! { #name: #flat_value })
         }
         _ => None,
@@ -480,7 +502,7 @@ mod tests {
         let (type_space, type_id) = get_type::<Option<u32>>();
 
         let type_entry = TypeEntry {
-            details: crate::type_entry::TypeEntryDetails::Box(type_id),
+            details: crate::type_entry::TypeEntryDetails::Box(type_id, crate::PointerType::Box),
             extra_derives: Default::default(),
             extra_attrs: Default::default(),
         };
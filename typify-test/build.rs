@@ -6,7 +6,10 @@ use std::{env, fs, path::Path};
 use schemars::schema::Schema;
 use schemars::JsonSchema;
 use serde::Serialize;
-use typify::{TypeSpace, TypeSpaceSettings};
+use typify::{
+    AnyOfStrategy, PointerType, StructBuilderStyle, TypeNameContext, TypeSpace, TypeSpacePatch,
+    TypeSpaceSettings,
+};
 
 #[allow(dead_code)]
 #[derive(JsonSchema)]
@@ -210,6 +213,950 @@ fn main() {
     let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
     out_file.push("codegen_custommap.rs");
     fs::write(out_file, contents).unwrap();
+
+    // Generate validation methods for constraints that serde can't enforce.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_validation_methods(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Payment": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "credit_card": { "type": "string" },
+                    "billing_address": { "type": "string" },
+                    "tags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "uniqueItems": true,
+                        "maxItems": 3
+                    },
+                    "flags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "contains": { "const": "primary" }
+                    },
+                    "items": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/Item" }
+                    },
+                    "by_name": {
+                        "type": "object",
+                        "additionalProperties": { "$ref": "#/definitions/Item" }
+                    }
+                },
+                "required": ["name"],
+                "maxProperties": 5,
+                "dependentRequired": {
+                    "credit_card": ["billing_address"]
+                }
+            },
+            "Item": {
+                "type": "object",
+                "properties": {
+                    "a": { "type": "integer" },
+                    "b": { "type": "integer" }
+                },
+                "minProperties": 1
+            },
+            "ItemOrList": {
+                "oneOf": [
+                    { "$ref": "#/definitions/Item" },
+                    { "$ref": "#/definitions/ItemList" }
+                ]
+            },
+            "ItemList": {
+                "type": "array",
+                "items": { "$ref": "#/definitions/Item" },
+                "minItems": 1
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_validation.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate proptest strategies that respect schema constraints.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_proptest_arbitrary(true)
+        .with_validation_methods(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Record": {
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "string",
+                        "pattern": "^[a-z]{3}-[0-9]{2}$"
+                    },
+                    "name": { "$ref": "#/definitions/ShortName" },
+                    "count": {
+                        "type": "integer",
+                        "minimum": 10,
+                        "maximum": 20
+                    },
+                    "ratio": {
+                        "type": "number",
+                        "exclusiveMinimum": 0,
+                        "maximum": 1
+                    },
+                    "color": {
+                        "type": "string",
+                        "enum": ["red", "green"]
+                    },
+                    "level": {
+                        "type": "integer",
+                        "enum": [1, 2, 3]
+                    },
+                    "shape": { "$ref": "#/definitions/Shape" },
+                    "tags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "uniqueItems": true,
+                        "minItems": 1,
+                        "maxItems": 3
+                    },
+                    "tree": { "$ref": "#/definitions/Tree" },
+                    "when": { "type": "string", "format": "date-time" },
+                    "uid": { "type": "string", "format": "uuid" }
+                },
+                "required": ["id", "name", "count", "tags"],
+                "maxProperties": 10
+            },
+            "ShortName": {
+                "type": "string",
+                "minLength": 2,
+                "maxLength": 5
+            },
+            "Shape": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "kind": { "const": "circle" },
+                            "radius": { "type": "number" }
+                        },
+                        "required": ["kind", "radius"]
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "kind": { "const": "square" },
+                            "side": { "type": "number" }
+                        },
+                        "required": ["kind", "side"]
+                    }
+                ]
+            },
+            "Tree": {
+                "type": "object",
+                "properties": {
+                    "value": { "type": "integer" },
+                    "children": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/Tree" }
+                    },
+                    "next": { "$ref": "#/definitions/Tree" }
+                },
+                "required": ["value"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_arbitrary.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate round-trip tests for schema examples.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_example_tests(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Order": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer" },
+                    "items": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/OrderItem" }
+                    },
+                    "note": { "type": "string" }
+                },
+                "required": ["id"],
+                "examples": [
+                    { "id": 1 },
+                    { "id": 2, "items": [{ "sku": "a-1", "quantity": 3 }] },
+                    { "id": 3, "note": "fragile" }
+                ]
+            },
+            "OrderItem": {
+                "type": "object",
+                "properties": {
+                    "sku": { "type": "string", "minLength": 1 },
+                    "quantity": { "type": "integer", "minimum": 1 }
+                },
+                "required": ["sku", "quantity"],
+                "examples": [{ "sku": "b-2", "quantity": 10 }]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_examples.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Skip read-only and write-only properties in the appropriate direction.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_skip_read_write_only(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let user_schema = serde_json::json!({
+        "definitions": {
+            "User": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "readOnly": true },
+                    "name": { "type": "string" },
+                    "password": { "type": "string", "writeOnly": true },
+                    "email": { "type": "string", "deprecated": true }
+                },
+                "required": ["id", "name", "password"]
+            }
+        }
+    });
+    let schema = serde_json::from_value(user_schema.clone()).unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_read_write_only.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate separate request and response types.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_request_response_types(true)
+        .with_struct_builder(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(user_schema).unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_request_response.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate typestate builders.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_struct_builder(true)
+        .with_struct_builder_style(StructBuilderStyle::Typestate);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Connection": {
+                "type": "object",
+                "properties": {
+                    "host": { "type": "string" },
+                    "port": { "type": "integer", "format": "uint16" },
+                    "timeout": { "type": "integer", "format": "uint32", "default": 30 },
+                    "label": { "type": "string" }
+                },
+                "required": ["host", "port"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_typestate.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate constructors that take the required properties.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_struct_constructor(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Connection": {
+                "type": "object",
+                "properties": {
+                    "host": { "type": "string" },
+                    "port": { "type": "integer", "format": "uint16" },
+                    "timeout": { "type": "integer", "format": "uint32", "default": 30 },
+                    "scheme": { "type": "string", "default": "https" },
+                    "label": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["host", "port"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_constructor.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate builders for nested structures.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_struct_builder(true)
+        .with_structured_builder_errors(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Deployment": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "spec": { "$ref": "#/definitions/Spec" }
+                },
+                "required": ["name", "spec"]
+            },
+            "Spec": {
+                "type": "object",
+                "properties": {
+                    "replicas": { "type": "integer", "format": "uint8" },
+                    "containers": {
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/Container" }
+                    }
+                },
+                "required": ["replicas", "containers"]
+            },
+            "Container": {
+                "type": "object",
+                "properties": {
+                    "image-ref": { "type": "string" },
+                    "port": { "type": "integer", "format": "uint16" }
+                },
+                "required": ["image-ref"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_nested_builder.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate enum accessors.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_enum_accessors(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Expr": {
+                "oneOf": [
+                    { "enum": ["nil"] },
+                    {
+                        "type": "object",
+                        "properties": { "lit": { "type": "integer" } },
+                        "required": ["lit"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "neg": { "$ref": "#/definitions/Expr" } },
+                        "required": ["neg"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "pair": {
+                                "type": "array",
+                                "items": [{ "type": "integer" }, { "type": "string" }],
+                                "minItems": 2,
+                                "maxItems": 2
+                            }
+                        },
+                        "required": ["pair"],
+                        "additionalProperties": false
+                    }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_enum_accessors.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate enums with an Unknown variant, except where patched.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_unknown_variants(true).with_patch(
+        "Strict",
+        TypeSpacePatch::default().with_unknown_variant(false),
+    );
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Color": {
+                "type": "string",
+                "enum": ["red", "green"]
+            },
+            "Strict": {
+                "type": "string",
+                "enum": ["on", "off"]
+            },
+            "Event": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["click"] },
+                            "x": { "type": "integer" }
+                        },
+                        "required": ["type", "x"]
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["key"] },
+                            "code": { "type": "string" }
+                        },
+                        "required": ["type", "code"]
+                    }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_unknown_variants.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate integer enums.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_integer_enums(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Flag": {
+                "type": "integer",
+                "enum": [1, 2, 4, 8]
+            },
+            "Code": {
+                "type": "integer",
+                "enum": [200, -1],
+                "x-enum-varnames": ["Success", "Failure"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_integer_enums.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate enums whose values are of mixed types.
+    let mut type_space = TypeSpace::default();
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Setting": {
+                "enum": ["auto", 0, 1, 1.5, true]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_mixed_enums.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate structs that preserve unknown properties.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_preserve_unknown_properties(true)
+        .with_map_type("::std::collections::BTreeMap")
+        .with_patch(
+            "Closed",
+            TypeSpacePatch::default().with_preserve_unknown_properties(false),
+        );
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Resource": {
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": { "type": "string" },
+                    "labels": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" }
+                    }
+                }
+            },
+            "Closed": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" }
+                }
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_unknown_properties.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate structs whose optional, nullable properties are three-state.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_double_option(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Patch": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": ["string", "null"] },
+                    "size": { "type": "integer", "format": "uint32" }
+                }
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_double_option.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate types merged from allOf subschemas that reference base types
+    // along with conversions and traits for those base types.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_base_conversions(true).with_base_traits(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Animal": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "age": { "type": "integer", "format": "uint32" }
+                }
+            },
+            "Dog": {
+                "allOf": [
+                    { "$ref": "#/definitions/Animal" },
+                    {
+                        "type": "object",
+                        "required": ["breed"],
+                        "properties": { "breed": { "type": "string" } }
+                    }
+                ]
+            },
+            "Elder": {
+                "allOf": [
+                    { "$ref": "#/definitions/Animal" },
+                    { "required": ["age"] }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_all_of_bases.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate non-exclusive anyOf types as untagged enums, and one as a
+    // merged struct by way of a patch.
+    let mut settings = TypeSpaceSettings::default();
+    settings
+        .with_any_of_strategy(AnyOfStrategy::UntaggedEnum)
+        .with_patch(
+            "Merged",
+            TypeSpacePatch::default().with_any_of_strategy(AnyOfStrategy::MergedStruct),
+        );
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Named": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" } }
+                }
+            },
+            "Tagged": {
+                "type": "object",
+                "required": ["name", "tags"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "tags": { "type": "array", "items": { "type": "string" } }
+                }
+            },
+            "Item": {
+                "anyOf": [
+                    { "$ref": "#/definitions/Named" },
+                    { "$ref": "#/definitions/Tagged" }
+                ]
+            },
+            "Merged": {
+                "anyOf": [
+                    { "$ref": "#/definitions/Named" },
+                    { "$ref": "#/definitions/Tagged" }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_any_of.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate untagged enums whose deserialization errors describe why each
+    // variant failed.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_detailed_untagged_errors(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Circle": {
+                "type": "object",
+                "required": ["radius"],
+                "properties": { "radius": { "type": "number" } },
+                "additionalProperties": false
+            },
+            "Shape": {
+                "oneOf": [
+                    { "$ref": "#/definitions/Circle" },
+                    {
+                        "type": "object",
+                        "required": ["width", "height"],
+                        "properties": {
+                            "width": { "type": "number" },
+                            "height": { "type": "number" }
+                        },
+                        "additionalProperties": false
+                    },
+                    { "type": "string" }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_untagged_errors.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate types for if/then/else conditional schemas.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_validation_methods(true);
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Storage": {
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": ["s3", "local"] },
+                    "bucket": { "type": "string" },
+                    "path": { "type": "string" }
+                },
+                "required": ["kind"],
+                "if": { "properties": { "kind": { "const": "s3" } } },
+                "then": { "required": ["bucket"] },
+                "else": { "required": ["path"] }
+            },
+            "Address": {
+                "type": "object",
+                "properties": {
+                    "country": { "type": "string" },
+                    "state": { "type": "string" },
+                    "postcode": { "type": "string" }
+                },
+                "if": { "properties": { "country": { "const": "US" } } },
+                "then": { "required": ["state"] },
+                "else": { "required": ["postcode"] }
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_conditionals.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate types that exclude values, patterns, and properties with `not`.
+    let mut type_space =
+        TypeSpace::new(TypeSpaceSettings::default().with_forbidden_property_checks(true));
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Username": {
+                "type": "string",
+                "not": { "enum": ["root", "admin"] }
+            },
+            "Handle": {
+                "type": "string",
+                "minLength": 1,
+                "not": {
+                    "anyOf": [
+                        { "pattern": "^-" },
+                        { "enum": ["a.b"] }
+                    ]
+                }
+            },
+            "Settings": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "legacy": { "type": "boolean" }
+                },
+                "required": ["name"],
+                "not": { "required": ["legacy"] }
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_not.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate recursive types defined by merging with `allOf`.
+    let mut type_space = TypeSpace::new(&TypeSpaceSettings::default());
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Base": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "children": {
+                        "type": "array",
+                        "items": { "type": "object" }
+                    }
+                },
+                "required": ["name"]
+            },
+            "Node": {
+                "allOf": [
+                    { "$ref": "#/definitions/Base" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "children": {
+                                "type": "array",
+                                "items": { "$ref": "#/definitions/Node" }
+                            },
+                            "parent": { "$ref": "#/definitions/Node" }
+                        }
+                    }
+                ]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_recursive_all_of.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate recursive types whose cycles are broken with `Rc` and, for one
+    // property, `Arc`.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_pointer_type(PointerType::Rc).with_patch(
+        "Person",
+        TypeSpacePatch::default().with_property_pointer_type("parent", PointerType::Arc),
+    );
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Expr": {
+                "oneOf": [
+                    { "type": "number" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "op": { "type": "string" },
+                            "lhs": { "$ref": "#/definitions/Expr" },
+                            "rhs": { "$ref": "#/definitions/Expr" }
+                        },
+                        "required": ["op", "lhs", "rhs"]
+                    }
+                ]
+            },
+            "Person": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "parent": { "$ref": "#/definitions/Person" }
+                },
+                "required": ["name"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_pointer_types.rs");
+    fs::write(out_file, contents).unwrap();
+
+    // Generate types whose inline property types are named for the property
+    // alone.
+    let mut settings = TypeSpaceSettings::default();
+    settings.with_type_namer(|context: &TypeNameContext<'_>| {
+        match (context.property, context.title) {
+            (Some(property), None) => property.to_string(),
+            _ => context.suggested.to_string(),
+        }
+    });
+    let mut type_space = TypeSpace::new(&settings);
+
+    let schema = serde_json::from_value(serde_json::json!({
+        "definitions": {
+            "Order": {
+                "type": "object",
+                "properties": {
+                    "shipping_address": {
+                        "type": "object",
+                        "properties": {
+                            "street": { "type": "string" },
+                            "city": { "type": "string" }
+                        },
+                        "required": ["street", "city"]
+                    },
+                    "lines": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "sku": { "type": "string" },
+                                "quantity": { "type": "integer", "minimum": 1 }
+                            },
+                            "required": ["sku", "quantity"]
+                        }
+                    }
+                },
+                "required": ["shipping_address"]
+            }
+        }
+    }))
+    .unwrap();
+    type_space.add_root_schema(schema).unwrap();
+
+    let contents =
+        prettyplease::unparse(&syn::parse2::<syn::File>(type_space.to_stream()).unwrap());
+
+    let mut out_file = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+    out_file.push("codegen_type_namer.rs");
+    fs::write(out_file, contents).unwrap();
 }
 
 trait AddType {
@@ -115,3 +115,709 @@ mod custom_map {
         };
     }
 }
+
+mod validation {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_validation.rs"));
+
+    fn paths(errors: &error::ValidationErrors) -> Vec<(&str, &str)> {
+        errors
+            .errors()
+            .iter()
+            .map(|e| (e.path.as_str(), e.message.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid() {
+        let payment: Payment = serde_json::from_value(serde_json::json!({
+            "name": "Alice",
+            "credit_card": "1234",
+            "billing_address": "Main St",
+            "tags": ["a", "b"],
+            "flags": ["other", "primary"],
+        }))
+        .unwrap();
+        assert!(payment.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let payment: Payment = serde_json::from_value(serde_json::json!({
+            "name": "Alice",
+            "credit_card": "1234",
+            "tags": ["a", "a", "b", "c"],
+            "flags": ["other"],
+            "items": [{ "a": 1 }, {}],
+            "by_name": { "x": {} },
+        }))
+        .unwrap();
+        let errors = payment.validate().unwrap_err();
+        assert_eq!(
+            paths(&errors),
+            vec![
+                ("", "more than 5 properties"),
+                ("billing_address", "required by credit_card"),
+                ("by_name.x", "fewer than 1 properties"),
+                ("flags", "no item matches \"primary\""),
+                ("items[1]", "fewer than 1 properties"),
+                ("tags", "more than 3 items"),
+                ("tags", "items are not unique"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_and_newtype() {
+        let list = ItemList(vec![]);
+        assert_eq!(
+            paths(&list.validate().unwrap_err()),
+            vec![("", "fewer than 1 items")]
+        );
+
+        let value = ItemOrList::from(ItemList(vec![Item { a: None, b: None }]));
+        assert_eq!(
+            paths(&value.validate().unwrap_err()),
+            vec![("[0]", "fewer than 1 properties")]
+        );
+    }
+}
+
+#[cfg(test)]
+mod arbitrary {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_arbitrary.rs"));
+
+    proptest::proptest! {
+        #[test]
+        fn test_round_trip(record: Record) {
+            let json = serde_json::to_value(&record).unwrap();
+            let parsed = serde_json::from_value::<Record>(json.clone());
+            proptest::prop_assert!(parsed.is_ok(), "{}: {:?}", json, parsed);
+            proptest::prop_assert_eq!(serde_json::to_value(parsed.unwrap()).unwrap(), json);
+            proptest::prop_assert!(record.validate().is_ok());
+        }
+
+        #[test]
+        fn test_constraints(record: Record) {
+            let id = record.id.as_str();
+            proptest::prop_assert!(id.len() == 6 && id.as_bytes()[3] == b'-');
+            proptest::prop_assert!((2..=5).contains(&record.name.chars().count()));
+            proptest::prop_assert!((10..=20).contains(&record.count));
+            if let Some(ratio) = record.ratio {
+                proptest::prop_assert!(ratio > 0.0 && ratio <= 1.0);
+            }
+            proptest::prop_assert!((1..=3).contains(&record.tags.len()));
+        }
+    }
+}
+
+mod examples {
+    #![allow(dead_code)]
+
+    // The generated `typify_examples` module contains the tests.
+    include!(concat!(env!("OUT_DIR"), "/codegen_examples.rs"));
+}
+
+mod read_write_only {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_read_write_only.rs"));
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_skip() {
+        let user: User = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "name": "ferris",
+            "password": "hunter2",
+            "email": "ferris@example.com"
+        }))
+        .unwrap();
+        assert_eq!(user.id, 7);
+        assert_eq!(user.password, "");
+        assert_eq!(user.email.as_deref(), Some("ferris@example.com"));
+
+        let user = User {
+            password: "hunter2".to_string(),
+            ..user
+        };
+        assert_eq!(
+            serde_json::to_value(&user).unwrap(),
+            serde_json::json!({
+                "name": "ferris",
+                "password": "hunter2",
+                "email": "ferris@example.com"
+            })
+        );
+    }
+}
+
+mod request_response {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_request_response.rs"));
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_split() {
+        let request: UserRequest = serde_json::from_value(serde_json::json!({
+            "name": "ferris",
+            "password": "hunter2"
+        }))
+        .unwrap();
+
+        // The read-only properties must be supplied to build the full type.
+        assert!(User::try_from(builder::User::from(request.clone())).is_err());
+        let user = User::try_from(builder::User::from(request).id(7)).unwrap();
+        assert_eq!(user.id, 7);
+        assert_eq!(user.password, "hunter2");
+
+        let response = UserResponse::from(user);
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({ "id": 7, "name": "ferris" })
+        );
+    }
+}
+
+mod typestate {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_typestate.rs"));
+
+    #[test]
+    fn test_build() {
+        // Required properties may be set in any order; optional ones needn't
+        // be set at all.
+        let connection = Connection::builder()
+            .port(8080_u16)
+            .label("primary".to_string())
+            .host("localhost")
+            .build();
+        assert_eq!(connection.host, "localhost");
+        assert_eq!(connection.port, 8080);
+        assert_eq!(connection.timeout, 30);
+        assert_eq!(connection.label.as_deref(), Some("primary"));
+
+        // A builder from an existing value can be modified and rebuilt.
+        let connection = builder::Connection::from(connection)
+            .timeout(5_u32)
+            .label(None)
+            .build();
+        assert_eq!(connection.timeout, 5);
+        assert_eq!(connection.label, None);
+    }
+}
+
+mod constructor {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_constructor.rs"));
+
+    #[test]
+    fn test_new() {
+        let connection = Connection::new("localhost".to_string(), 8080);
+        assert_eq!(connection.host, "localhost");
+        assert_eq!(connection.port, 8080);
+        assert_eq!(connection.timeout, 30);
+        assert_eq!(connection.scheme, "https");
+        assert_eq!(connection.label, None);
+        assert!(connection.tags.is_empty());
+
+        let connection = connection
+            .with_label("primary".to_string())
+            .with_scheme("http".to_string())
+            .with_tags(vec!["a".to_string()]);
+        assert_eq!(connection.label.as_deref(), Some("primary"));
+        assert_eq!(connection.scheme, "http");
+        assert_eq!(connection.tags, vec!["a".to_string()]);
+    }
+}
+
+mod nested_builder {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_nested_builder.rs"));
+
+    #[test]
+    fn test_errors() {
+        let err = Deployment::try_from(Deployment::builder().spec(
+            Spec::builder().replicas(300).containers_items([
+                Container::builder().image_ref("nginx"),
+                Container::builder().port(80),
+            ]),
+        ))
+        .unwrap_err();
+
+        let errors = err.errors().collect::<Vec<_>>();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0], ("name", "no value supplied"));
+        assert_eq!(
+            errors[1],
+            ("spec.containers[1].image-ref", "no value supplied")
+        );
+        assert_eq!(errors[2].0, "spec.replicas");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "name: no value supplied; \
+                spec.containers[1].image-ref: no value supplied; \
+                spec.replicas: {}",
+                errors[2].1,
+            ),
+        );
+
+        let deployment = Deployment::try_from(
+            Deployment::builder().name("web").spec(
+                Spec::builder()
+                    .replicas(3)
+                    .containers_items([Container::builder().image_ref("nginx")]),
+            ),
+        )
+        .unwrap();
+        assert_eq!(deployment.spec.containers[0].image_ref, "nginx");
+    }
+}
+
+mod enum_accessors {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_enum_accessors.rs"));
+
+    #[test]
+    fn test_accessors() {
+        let expr = Expr::Neg(Box::new(Expr::Lit(7)));
+        assert!(expr.is_neg());
+        assert!(!expr.is_lit());
+        assert_eq!(expr.kind(), ExprKind::Neg);
+        assert_eq!(expr.as_neg().and_then(Expr::as_lit), Some(&7));
+        assert!(expr.clone().into_lit().is_none());
+        assert_eq!(expr.into_neg().and_then(|e| e.into_lit()), Some(7));
+
+        let expr = Expr::Pair(1, "one".to_string());
+        assert_eq!(expr.as_pair(), Some((&1, &"one".to_string())));
+        assert_eq!(expr.kind(), ExprKind::Pair);
+        assert_eq!(Expr::Nil.kind(), ExprKind::Nil);
+        assert!(Expr::Nil.is_nil());
+
+        assert_eq!(i64::try_from(Expr::Lit(3)).unwrap(), 3);
+        assert!(i64::try_from(Expr::Nil).is_err());
+        assert_eq!(
+            <(i64, String)>::try_from(expr).unwrap(),
+            (1, "one".to_string())
+        );
+    }
+}
+
+mod unknown_variants {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_unknown_variants.rs"));
+
+    #[test]
+    fn test_string_enum() {
+        let colors: Vec<Color> = serde_json::from_str(r#"["red", "blue"]"#).unwrap();
+        assert_eq!(colors, vec![Color::Red, Color::Unknown("blue".to_string())]);
+        assert_eq!(colors[1].to_string(), "blue");
+        assert_eq!("blue".parse::<Color>().unwrap(), colors[1]);
+        assert_eq!(serde_json::to_string(&colors).unwrap(), r#"["red","blue"]"#);
+
+        // The patch opts this type out.
+        assert!(serde_json::from_str::<Strict>(r#""dim""#).is_err());
+    }
+
+    #[test]
+    fn test_tagged_enum() {
+        let event: Event = serde_json::from_str(r#"{"type":"key","code":"a"}"#).unwrap();
+        assert!(matches!(event, Event::Key { .. }));
+
+        let raw = serde_json::json!({ "type": "scroll", "dy": 3 });
+        let event: Event = serde_json::from_value(raw.clone()).unwrap();
+        match &event {
+            Event::Unknown(value) => assert_eq!(value, &raw),
+            _ => panic!("unexpected variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), raw);
+    }
+}
+
+mod integer_enums {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_integer_enums.rs"));
+
+    #[test]
+    fn test_integer_enums() {
+        assert_eq!(i64::from(Flag::Value4), 4);
+        assert_eq!(Flag::try_from(8).unwrap(), Flag::Value8);
+        assert!(Flag::try_from(3).is_err());
+        assert_eq!(Code::Failure as i64, -1);
+
+        let codes: Vec<Code> = serde_json::from_str("[200, -1]").unwrap();
+        assert_eq!(codes, vec![Code::Success, Code::Failure]);
+        assert_eq!(serde_json::to_string(&codes).unwrap(), "[200,-1]");
+        assert!(serde_json::from_str::<Code>("404").is_err());
+        assert!(serde_json::from_str::<Code>("\"Success\"").is_err());
+    }
+}
+
+mod mixed_enums {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_mixed_enums.rs"));
+
+    #[test]
+    fn test_mixed_enums() {
+        let settings: Vec<Setting> = serde_json::from_str(r#"["auto", 0, 1, 1.5, true]"#).unwrap();
+        assert_eq!(
+            settings,
+            vec![
+                Setting::Auto,
+                Setting::Number0,
+                Setting::Number1,
+                Setting::Number1Point5,
+                Setting::True,
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&settings).unwrap(),
+            r#"["auto",0,1,1.5,true]"#
+        );
+        assert_eq!(Setting::Number1Point5.to_value(), serde_json::json!(1.5));
+        assert!(serde_json::from_str::<Setting>("\"Auto\"").is_err());
+        assert!(serde_json::from_str::<Setting>("false").is_err());
+
+        // Numbers match by value.
+        assert_eq!(
+            serde_json::from_str::<Setting>("0.0").unwrap(),
+            Setting::Number0,
+        );
+        assert_eq!(
+            serde_json::from_str::<Setting>("1.50").unwrap(),
+            Setting::Number1Point5,
+        );
+        assert_eq!(
+            serde_json::from_str::<Setting>("1.0").unwrap(),
+            Setting::Number1,
+        );
+        assert!(serde_json::from_str::<Setting>("2").is_err());
+    }
+}
+
+mod unknown_properties {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_unknown_properties.rs"));
+
+    #[test]
+    fn test_unknown_properties() {
+        let json = r#"{"id":"r1","labels":{"a":"b"},"owner":{"name":"x"},"rev":3}"#;
+        let mut resource: Resource = serde_json::from_str(json).unwrap();
+        assert_eq!(resource.extra_property("rev"), Some(&serde_json::json!(3)));
+        assert_eq!(resource.extra_property("id"), None);
+        assert_eq!(serde_json::to_string(&resource).unwrap(), json);
+
+        assert_eq!(
+            resource.set_extra_property("rev", serde_json::json!(4)),
+            Some(serde_json::json!(3))
+        );
+        assert_eq!(
+            serde_json::to_value(&resource).unwrap()["rev"],
+            serde_json::json!(4)
+        );
+
+        let empty: Resource = serde_json::from_str(r#"{"id":"r2"}"#).unwrap();
+        assert_eq!(serde_json::to_string(&empty).unwrap(), r#"{"id":"r2"}"#);
+
+        let closed: Closed = serde_json::from_str(r#"{"id":"c","rev":3}"#).unwrap();
+        assert_eq!(serde_json::to_string(&closed).unwrap(), r#"{"id":"c"}"#);
+    }
+}
+
+mod double_option {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_double_option.rs"));
+
+    #[test]
+    fn test_double_option() {
+        for (json, name) in [
+            (r#"{}"#, None),
+            (r#"{"name":null}"#, Some(None)),
+            (r#"{"name":"x"}"#, Some(Some("x".to_string()))),
+        ] {
+            let patch: Patch = serde_json::from_str(json).unwrap();
+            assert_eq!(patch.name, name);
+            assert_eq!(patch.size, None);
+            assert_eq!(serde_json::to_string(&patch).unwrap(), json);
+        }
+    }
+}
+
+mod all_of_bases {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_all_of_bases.rs"));
+
+    fn name_of(animal: impl Into<Animal>) -> String {
+        animal.into().name
+    }
+
+    #[test]
+    fn test_all_of_bases() {
+        let dog = Dog {
+            age: Some(3),
+            breed: "collie".to_string(),
+            name: "Lassie".to_string(),
+        };
+        assert_eq!(name_of(dog), "Lassie");
+
+        let elder = Elder {
+            age: 12,
+            name: "Grey".to_string(),
+        };
+        let animal = Animal::from(elder);
+        assert_eq!(animal.age, Some(12));
+        assert_eq!(Elder::try_from(animal).unwrap().age, 12);
+
+        let young = Animal {
+            age: None,
+            name: "Pup".to_string(),
+        };
+        let err = Elder::try_from(young).unwrap_err();
+        assert_eq!(err.to_string(), "no value supplied for age");
+    }
+
+    fn describe(animal: &dyn AnimalFields) -> String {
+        match animal.age() {
+            Some(age) => format!("{} ({})", animal.name(), age),
+            None => animal.name().to_string(),
+        }
+    }
+
+    #[test]
+    fn test_base_traits() {
+        let dog = Dog {
+            age: None,
+            breed: "collie".to_string(),
+            name: "Lassie".to_string(),
+        };
+        let elder = Elder {
+            age: 12,
+            name: "Grey".to_string(),
+        };
+        assert_eq!(describe(&dog), "Lassie");
+        assert_eq!(describe(&elder), "Grey (12)");
+        assert_eq!(describe(&Animal::from(elder)), "Grey (12)");
+    }
+}
+
+mod any_of {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_any_of.rs"));
+
+    #[test]
+    fn test_untagged_enum() {
+        let item: Item = serde_json::from_str(r#"{ "name": "a", "tags": ["b"] }"#).unwrap();
+        assert!(matches!(item, Item::Tagged(_)));
+
+        let item: Item = serde_json::from_str(r#"{ "name": "a" }"#).unwrap();
+        assert!(matches!(item, Item::Named(_)));
+    }
+
+    #[test]
+    fn test_merged_struct() {
+        let merged: Merged = serde_json::from_str(r#"{ "tags": ["b"] }"#).unwrap();
+        assert_eq!(merged.name, None);
+        assert_eq!(merged.tags, vec!["b".to_string()]);
+    }
+}
+
+mod untagged_errors {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_untagged_errors.rs"));
+
+    #[test]
+    fn test_untagged_errors() {
+        let shape: Shape = serde_json::from_str(r#"{ "width": 1, "height": 2 }"#).unwrap();
+        assert!(matches!(shape, Shape::Object { .. }));
+
+        let err = serde_json::from_str::<Shape>(r#"{ "width": 1 }"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "data did not match any variant of untagged enum Shape: \
+             Circle: unknown field `width`, expected `radius`; \
+             Object: missing field `height`; \
+             String: invalid type: map, expected a string",
+        );
+    }
+}
+
+mod conditionals {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_conditionals.rs"));
+
+    fn paths(errors: &error::ValidationErrors) -> Vec<(&str, &str)> {
+        errors
+            .errors()
+            .iter()
+            .map(|e| (e.path.as_str(), e.message.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_tagged() {
+        let storage: Storage =
+            serde_json::from_str(r#"{ "kind": "s3", "bucket": "artifacts" }"#).unwrap();
+        assert!(matches!(storage, Storage::S3 { .. }));
+
+        let storage: Storage =
+            serde_json::from_str(r#"{ "kind": "local", "path": "/tmp" }"#).unwrap();
+        assert!(matches!(storage, Storage::Local { .. }));
+
+        assert!(serde_json::from_str::<Storage>(r#"{ "kind": "s3", "path": "/tmp" }"#).is_err());
+    }
+
+    #[test]
+    fn test_validated() {
+        let address: Address = serde_json::from_str(r#"{ "country": "US" }"#).unwrap();
+        assert_eq!(
+            paths(&address.validate().unwrap_err()),
+            vec![("state", "required when country is \"US\"")]
+        );
+
+        // The test passes when the property is absent.
+        let address: Address = serde_json::from_str(r#"{ "postcode": "12345" }"#).unwrap();
+        assert_eq!(
+            paths(&address.validate().unwrap_err()),
+            vec![("state", "required when country is \"US\"")]
+        );
+
+        let address: Address =
+            serde_json::from_str(r#"{ "country": "FR", "state": "IDF" }"#).unwrap();
+        assert_eq!(
+            paths(&address.validate().unwrap_err()),
+            vec![("postcode", "required unless country is \"US\"")]
+        );
+
+        let address: Address =
+            serde_json::from_str(r#"{ "country": "FR", "postcode": "75001" }"#).unwrap();
+        assert!(address.validate().is_ok());
+    }
+}
+
+mod not {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_not.rs"));
+
+    #[test]
+    fn test_excluded_values() {
+        assert!(serde_json::from_str::<Username>(r#""alice""#).is_ok());
+        assert!(serde_json::from_str::<Username>(r#""root""#).is_err());
+        assert!(Username::try_from("admin".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_excluded_patterns() {
+        assert!("ab".parse::<Handle>().is_ok());
+        assert!("aXb".parse::<Handle>().is_ok());
+        assert!("a.b".parse::<Handle>().is_err());
+        assert!("-ab".parse::<Handle>().is_err());
+        assert!("".parse::<Handle>().is_err());
+    }
+
+    #[test]
+    fn test_forbidden_property() {
+        let settings: Settings = serde_json::from_str(r#"{ "name": "x" }"#).unwrap();
+        assert_eq!(settings.name, "x");
+
+        let err =
+            serde_json::from_str::<Settings>(r#"{ "name": "x", "legacy": true }"#).unwrap_err();
+        assert_eq!(err.to_string(), "property \"legacy\" is not permitted");
+    }
+}
+
+mod recursive_all_of {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_recursive_all_of.rs"));
+
+    #[test]
+    fn test_recursive_node() {
+        let node: Node = serde_json::from_str(
+            r#"{
+                "name": "root",
+                "children": [
+                    { "name": "leaf", "parent": { "name": "root" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(node.name, "root");
+        assert_eq!(node.children[0].name, "leaf");
+        assert_eq!(node.children[0].parent.as_ref().unwrap().name, "root");
+        assert!(node.children[0].children.is_empty());
+    }
+}
+
+mod pointer_types {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_pointer_types.rs"));
+
+    #[test]
+    fn test_rc_pointer() {
+        let expr: Expr = serde_json::from_str(
+            r#"{ "op": "+", "lhs": 1.0, "rhs": { "op": "*", "lhs": 2.0, "rhs": 3.0 } }"#,
+        )
+        .unwrap();
+        let Expr::Object { lhs, rhs, .. } = &expr else {
+            panic!("unexpected {:?}", expr);
+        };
+        let lhs: &std::rc::Rc<Expr> = lhs;
+        assert!(matches!(lhs.as_ref(), Expr::Number(_)));
+        assert!(matches!(rhs.as_ref(), Expr::Object { .. }));
+
+        let copy = expr.clone();
+        assert_eq!(
+            serde_json::to_value(&copy).unwrap(),
+            serde_json::to_value(&expr).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_property_pointer() {
+        let person: Person =
+            serde_json::from_str(r#"{ "name": "child", "parent": { "name": "parent" } }"#).unwrap();
+        let parent: &std::sync::Arc<Person> = person.parent.as_ref().unwrap();
+        assert_eq!(parent.name, "parent");
+    }
+}
+
+mod type_namer {
+    #![allow(dead_code)]
+
+    include!(concat!(env!("OUT_DIR"), "/codegen_type_namer.rs"));
+
+    #[test]
+    fn test_type_namer() {
+        let order: Order = serde_json::from_str(
+            r#"{
+                "shipping_address": { "street": "1 Main St", "city": "Springfield" },
+                "lines": [ { "sku": "A-1", "quantity": 2 } ]
+            }"#,
+        )
+        .unwrap();
+        let address: &ShippingAddress = &order.shipping_address;
+        assert_eq!(address.city, "Springfield");
+        let line: &LinesItem = &order.lines[0];
+        assert_eq!(line.sku, "A-1");
+    }
+}
@@ -149,14 +149,21 @@
 #![deny(missing_docs)]
 
 pub use typify_impl::accept_as_ident;
+pub use typify_impl::AnyOfStrategy;
 pub use typify_impl::CrateVers;
 pub use typify_impl::Error;
 pub use typify_impl::MapType;
+pub use typify_impl::PointerType;
+pub use typify_impl::PropertyOrder;
+pub use typify_impl::StructBuilderStyle;
 pub use typify_impl::Type;
 pub use typify_impl::TypeDetails;
 pub use typify_impl::TypeEnum;
 pub use typify_impl::TypeEnumVariant;
 pub use typify_impl::TypeId;
+pub use typify_impl::TypeNameContext;
+pub use typify_impl::TypeNamer;
+pub use typify_impl::TypeNaming;
 pub use typify_impl::TypeNewtype;
 pub use typify_impl::TypeSpace;
 pub use typify_impl::TypeSpaceImpl;
@@ -7,7 +7,10 @@ use glob::glob;
 use quote::quote;
 use schemars::schema::RootSchema;
 use serde_json::json;
-use typify::{TypeSpace, TypeSpacePatch, TypeSpaceSettings};
+use typify::{
+    AnyOfStrategy, PointerType, StructBuilderStyle, TypeNaming, TypeSpace, TypeSpacePatch,
+    TypeSpaceSettings,
+};
 use typify_impl::TypeSpaceImpl;
 
 #[test]
@@ -51,6 +54,287 @@ fn test_various_enums_json_schema() {
     trybuild::TestCases::new().pass("tests/schemas/various-enums-json-schema.rs");
 }
 
+/// Ensure that examples are rendered in documentation and as tests when
+/// requested.
+#[test]
+fn test_examples() {
+    validate_schema(
+        "tests/schemas/examples.json".into(),
+        "tests/schemas/examples_doc.rs".into(),
+        TypeSpaceSettings::default()
+            .with_doc_examples(true)
+            .with_example_tests(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/examples_doc.rs");
+}
+
+/// Ensure that read-only and write-only properties are skipped when requested.
+#[test]
+fn test_skip_read_write_only() {
+    validate_schema(
+        "tests/schemas/annotations.json".into(),
+        "tests/schemas/annotations_skip.rs".into(),
+        TypeSpaceSettings::default().with_skip_read_write_only(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/annotations_skip.rs");
+}
+
+/// Ensure that separate request and response types are generated when
+/// requested.
+#[test]
+fn test_request_response_types() {
+    validate_schema(
+        "tests/schemas/annotations.json".into(),
+        "tests/schemas/annotations_split.rs".into(),
+        TypeSpaceSettings::default().with_request_response_types(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/annotations_split.rs");
+}
+
+/// Ensure that typestate builders check for required properties at compile
+/// time, including for request and response types.
+#[test]
+fn test_typestate_builder() {
+    validate_schema(
+        "tests/schemas/annotations.json".into(),
+        "tests/schemas/annotations_typestate.rs".into(),
+        TypeSpaceSettings::default()
+            .with_struct_builder_style(StructBuilderStyle::Typestate)
+            .with_request_response_types(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/annotations_typestate.rs");
+}
+
+/// Ensure that struct constructors and setters are generated when requested.
+#[test]
+fn test_struct_constructor() {
+    validate_schema(
+        "tests/schemas/simple-types.json".into(),
+        "tests/schemas/simple-types-constructor.rs".into(),
+        TypeSpaceSettings::default().with_struct_constructor(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/simple-types-constructor.rs");
+}
+
+/// Ensure that enum accessors and conversions are generated when requested.
+#[test]
+fn test_enum_accessors() {
+    validate_schema(
+        "tests/schemas/various-enums.json".into(),
+        "tests/schemas/various-enums-accessors.rs".into(),
+        TypeSpaceSettings::default().with_enum_accessors(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/various-enums-accessors.rs");
+}
+
+/// Ensure that enums have an `Unknown` variant when requested.
+#[test]
+fn test_unknown_variants() {
+    validate_schema(
+        "tests/schemas/various-enums.json".into(),
+        "tests/schemas/various-enums-unknown.rs".into(),
+        TypeSpaceSettings::default().with_unknown_variants(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/various-enums-unknown.rs");
+}
+
+/// Ensure that untagged enum variants are moved ahead of those that would
+/// shadow them when requested.
+#[test]
+fn test_reorder_untagged_variants() {
+    validate_schema(
+        "tests/schemas/various-enums.json".into(),
+        "tests/schemas/various-enums-reordered.rs".into(),
+        TypeSpaceSettings::default().with_reorder_untagged_variants(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/various-enums-reordered.rs");
+}
+
+/// Ensure that integer enums are generated as `#[repr(i64)]` enums when
+/// requested.
+#[test]
+fn test_integer_enums() {
+    validate_schema(
+        "tests/schemas/integer-enums.json".into(),
+        "tests/schemas/integer-enums-repr.rs".into(),
+        TypeSpaceSettings::default().with_integer_enums(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/integer-enums-repr.rs");
+}
+
+/// Ensure that struct properties follow the order of the schema when
+/// requested. This relies on schemars keeping the order of properties.
+#[cfg(feature = "preserve_order")]
+#[test]
+fn test_property_order() {
+    validate_schema(
+        "tests/schemas/property-order.json".into(),
+        "tests/schemas/property-order-schema.rs".into(),
+        TypeSpaceSettings::default().with_property_order(typify::PropertyOrder::Schema),
+    )
+    .unwrap();
+
+    let contents = std::fs::read_to_string("tests/schemas/property-order-schema.rs").unwrap();
+    let position = |field: &str| contents.find(&format!("pub {}:", field)).unwrap();
+    assert!(position("timestamp") < position("kind"));
+    assert!(position("kind") < position("attributes"));
+
+    trybuild::TestCases::new().pass("tests/schemas/property-order-schema.rs");
+}
+
+/// Ensure that structs preserve unknown properties when requested.
+#[test]
+fn test_preserve_unknown_properties() {
+    validate_schema(
+        "tests/schemas/types-with-defaults.json".into(),
+        "tests/schemas/types-with-defaults-preserve.rs".into(),
+        TypeSpaceSettings::default()
+            .with_preserve_unknown_properties(true)
+            .with_map_type("::std::collections::BTreeMap"),
+    )
+    .unwrap();
+
+    // The map of unknown properties is the configured map type.
+    let contents =
+        std::fs::read_to_string("tests/schemas/types-with-defaults-preserve.rs").unwrap();
+    assert!(contents.contains(
+        "pub extra: ::std::collections::BTreeMap<::std::string::String, ::serde_json::Value>,"
+    ));
+    assert!(!contents.contains("::serde_json::Map"));
+
+    trybuild::TestCases::new().pass("tests/schemas/types-with-defaults-preserve.rs");
+}
+
+/// Ensure that optional, nullable properties are `Option<Option<T>>` when
+/// requested.
+#[test]
+fn test_double_option() {
+    validate_schema(
+        "tests/schemas/nullable-properties.json".into(),
+        "tests/schemas/nullable-properties-double.rs".into(),
+        TypeSpaceSettings::default().with_double_option(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/nullable-properties-double.rs");
+}
+
+/// Ensure that conversions and traits are generated for allOf base types when
+/// requested.
+#[test]
+fn test_base_traits() {
+    validate_schema(
+        "tests/schemas/all-of-bases.json".into(),
+        "tests/schemas/all-of-bases-traits.rs".into(),
+        TypeSpaceSettings::default()
+            .with_base_conversions(true)
+            .with_base_traits(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/all-of-bases-traits.rs");
+}
+
+/// Non-exclusive `anyOf` schemas as untagged enums.
+#[test]
+fn test_any_of_untagged_enum() {
+    validate_schema(
+        "tests/schemas/any-of-strategies.json".into(),
+        "tests/schemas/any-of-strategies-untagged.rs".into(),
+        TypeSpaceSettings::default().with_any_of_strategy(AnyOfStrategy::UntaggedEnum),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/any-of-strategies-untagged.rs");
+}
+
+/// Non-exclusive `anyOf` schemas as a struct with the union of properties.
+#[test]
+fn test_any_of_merged_struct() {
+    validate_schema(
+        "tests/schemas/any-of-strategies.json".into(),
+        "tests/schemas/any-of-strategies-merged.rs".into(),
+        TypeSpaceSettings::default().with_any_of_strategy(AnyOfStrategy::MergedStruct),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/any-of-strategies-merged.rs");
+}
+
+/// Untagged enums that report why each variant failed to deserialize.
+#[test]
+fn test_detailed_untagged_errors() {
+    validate_schema(
+        "tests/schemas/various-enums.json".into(),
+        "tests/schemas/various-enums-detailed.rs".into(),
+        TypeSpaceSettings::default().with_detailed_untagged_errors(true),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/various-enums-detailed.rs");
+}
+
+/// Containment cycles broken with `Rc` and, for one property, `Arc`.
+#[test]
+fn test_pointer_types() {
+    validate_schema(
+        "tests/schemas/recursive-pointers.json".into(),
+        "tests/schemas/recursive-pointers-rc.rs".into(),
+        TypeSpaceSettings::default()
+            .with_pointer_type(PointerType::Rc)
+            .with_patch(
+                "Person",
+                TypeSpacePatch::default().with_property_pointer_type("parent", PointerType::Arc),
+            ),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/recursive-pointers-rc.rs");
+}
+
+#[test]
+fn test_type_naming() {
+    validate_schema(
+        "tests/schemas/type-naming.json".into(),
+        "tests/schemas/type-naming-shorten.rs".into(),
+        TypeSpaceSettings::default().with_type_namer(TypeNaming::Shorten),
+    )
+    .unwrap();
+
+    // The shortened name of `Pet.pet_type` belongs to a definition.
+    let contents = std::fs::read_to_string("tests/schemas/type-naming-shorten.rs").unwrap();
+    assert!(contents.contains("pub enum PetPetType {"));
+    assert_eq!(contents.matches("\npub struct PetType {").count(), 1);
+
+    validate_schema(
+        "tests/schemas/type-naming.json".into(),
+        "tests/schemas/type-naming-path.rs".into(),
+        TypeSpaceSettings::default().with_type_namer(TypeNaming::Path),
+    )
+    .unwrap();
+
+    trybuild::TestCases::new().pass("tests/schemas/type-naming-shorten.rs");
+    trybuild::TestCases::new().pass("tests/schemas/type-naming-path.rs");
+}
+
 fn validate_schema(
     path: std::path::PathBuf,
     out_path: std::path::PathBuf,
@@ -0,0 +1,305 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Animal`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Animal {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub age: ::std::option::Option<u32>,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub tags: ::std::vec::Vec<::std::string::String>,
+}
+impl Animal {
+    pub fn builder() -> builder::Animal {
+        Default::default()
+    }
+}
+#[doc = "An animal with a breed."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Dog {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub age: ::std::option::Option<u32>,
+    pub breed: ::std::string::String,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub tags: ::std::vec::Vec<::std::string::String>,
+}
+impl Dog {
+    pub fn builder() -> builder::Dog {
+        Default::default()
+    }
+}
+#[doc = "An animal whose age is known."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Elder {
+    pub age: u32,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub tags: ::std::vec::Vec<::std::string::String>,
+}
+impl Elder {
+    pub fn builder() -> builder::Elder {
+        Default::default()
+    }
+}
+#[doc = "`Named`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct Named(pub Animal);
+impl ::std::ops::Deref for Named {
+    type Target = Animal;
+    fn deref(&self) -> &Animal {
+        &self.0
+    }
+}
+impl ::std::convert::From<Named> for Animal {
+    fn from(value: Named) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<Animal> for Named {
+    fn from(value: Animal) -> Self {
+        Self(value)
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Animal {
+        age: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        tags: ::std::result::Result<::std::vec::Vec<::std::string::String>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Animal {
+        fn default() -> Self {
+            Self {
+                age: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                tags: Ok(Default::default()),
+            }
+        }
+    }
+    impl Animal {
+        pub fn age<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u32>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.age = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for age: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn tags<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tags = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tags: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Animal> for super::Animal {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Animal) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                age: value.age?,
+                name: value.name?,
+                tags: value.tags?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Animal> for Animal {
+        fn from(value: super::Animal) -> Self {
+            Self {
+                age: Ok(value.age),
+                name: Ok(value.name),
+                tags: Ok(value.tags),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Dog {
+        age: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+        breed: ::std::result::Result<::std::string::String, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        tags: ::std::result::Result<::std::vec::Vec<::std::string::String>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Dog {
+        fn default() -> Self {
+            Self {
+                age: Ok(Default::default()),
+                breed: Err("no value supplied for breed".to_string()),
+                name: Err("no value supplied for name".to_string()),
+                tags: Ok(Default::default()),
+            }
+        }
+    }
+    impl Dog {
+        pub fn age<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u32>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.age = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for age: {e}"));
+            self
+        }
+        pub fn breed<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.breed = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for breed: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn tags<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tags = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tags: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Dog> for super::Dog {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Dog) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                age: value.age?,
+                breed: value.breed?,
+                name: value.name?,
+                tags: value.tags?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Dog> for Dog {
+        fn from(value: super::Dog) -> Self {
+            Self {
+                age: Ok(value.age),
+                breed: Ok(value.breed),
+                name: Ok(value.name),
+                tags: Ok(value.tags),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Elder {
+        age: ::std::result::Result<u32, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        tags: ::std::result::Result<::std::vec::Vec<::std::string::String>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Elder {
+        fn default() -> Self {
+            Self {
+                age: Err("no value supplied for age".to_string()),
+                name: Err("no value supplied for name".to_string()),
+                tags: Ok(Default::default()),
+            }
+        }
+    }
+    impl Elder {
+        pub fn age<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<u32>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.age = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for age: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn tags<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tags = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tags: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Elder> for super::Elder {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Elder) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                age: value.age?,
+                name: value.name?,
+                tags: value.tags?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Elder> for Elder {
+        fn from(value: super::Elder) -> Self {
+            Self {
+                age: Ok(value.age),
+                name: Ok(value.name),
+                tags: Ok(value.tags),
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,319 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[allow(deprecated)]
+#[doc = "`Account`\n\nAccounts are created through the signup flow."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Account {
+    #[doc = "This value is read-only."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub created: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+    #[doc = "Assigned by the server.\n\nThis value is read-only."]
+    pub id: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legacy: ::std::option::Option<LegacyId>,
+    pub name: ::std::string::String,
+    #[doc = "Use `name` instead."]
+    #[deprecated]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub nickname: ::std::option::Option<::std::string::String>,
+    #[doc = "This value is write-only."]
+    pub password: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub plan: ::std::option::Option<Plan>,
+}
+#[allow(deprecated)]
+impl Account {
+    pub fn builder() -> builder::Account {
+        Default::default()
+    }
+}
+#[allow(deprecated)]
+#[doc = "An identifier from the previous system."]
+#[deprecated]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[serde(transparent)]
+pub struct LegacyId(pub ::std::string::String);
+#[allow(deprecated)]
+impl ::std::ops::Deref for LegacyId {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+#[allow(deprecated)]
+impl ::std::convert::From<LegacyId> for ::std::string::String {
+    fn from(value: LegacyId) -> Self {
+        value.0
+    }
+}
+#[allow(deprecated)]
+impl ::std::convert::From<::std::string::String> for LegacyId {
+    fn from(value: ::std::string::String) -> Self {
+        Self(value)
+    }
+}
+#[allow(deprecated)]
+impl ::std::str::FromStr for LegacyId {
+    type Err = ::std::convert::Infallible;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.to_string()))
+    }
+}
+#[allow(deprecated)]
+impl ::std::fmt::Display for LegacyId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+#[allow(deprecated)]
+#[doc = "`OldAccount`"]
+#[deprecated]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct OldAccount {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub account: ::std::option::Option<Account>,
+}
+#[allow(deprecated)]
+impl OldAccount {
+    pub fn builder() -> builder::OldAccount {
+        Default::default()
+    }
+}
+#[allow(deprecated)]
+#[doc = "`Plan`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub enum Plan {
+    #[serde(rename = "free")]
+    Free,
+    #[doc = "Trials were discontinued."]
+    #[deprecated]
+    #[serde(rename = "trial")]
+    Trial,
+    #[serde(rename = "paid")]
+    Paid {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        legacy: ::std::option::Option<LegacyId>,
+        seats: u32,
+    },
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[allow(deprecated)]
+    #[derive(Clone, Debug)]
+    pub struct Account {
+        created: ::std::result::Result<
+            ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+            ::std::string::String,
+        >,
+        id: ::std::result::Result<::std::string::String, ::std::string::String>,
+        legacy:
+            ::std::result::Result<::std::option::Option<super::LegacyId>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        nickname: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        password: ::std::result::Result<::std::string::String, ::std::string::String>,
+        plan: ::std::result::Result<::std::option::Option<super::Plan>, ::std::string::String>,
+    }
+    #[allow(deprecated)]
+    impl ::std::default::Default for Account {
+        fn default() -> Self {
+            Self {
+                created: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                legacy: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                nickname: Ok(Default::default()),
+                password: Err("no value supplied for password".to_string()),
+                plan: Ok(Default::default()),
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl Account {
+        pub fn created<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.created = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for created: {e}"));
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {e}"));
+            self
+        }
+        pub fn legacy<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::LegacyId>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.legacy = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for legacy: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn nickname<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.nickname = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for nickname: {e}"));
+            self
+        }
+        pub fn password<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.password = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for password: {e}"));
+            self
+        }
+        pub fn plan<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Plan>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.plan = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for plan: {e}"));
+            self
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::TryFrom<Account> for super::Account {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Account) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                created: value.created?,
+                id: value.id?,
+                legacy: value.legacy?,
+                name: value.name?,
+                nickname: value.nickname?,
+                password: value.password?,
+                plan: value.plan?,
+            })
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::Account> for Account {
+        fn from(value: super::Account) -> Self {
+            Self {
+                created: Ok(value.created),
+                id: Ok(value.id),
+                legacy: Ok(value.legacy),
+                name: Ok(value.name),
+                nickname: Ok(value.nickname),
+                password: Ok(value.password),
+                plan: Ok(value.plan),
+            }
+        }
+    }
+    #[allow(deprecated)]
+    #[derive(Clone, Debug)]
+    pub struct OldAccount {
+        account:
+            ::std::result::Result<::std::option::Option<super::Account>, ::std::string::String>,
+    }
+    #[allow(deprecated)]
+    impl ::std::default::Default for OldAccount {
+        fn default() -> Self {
+            Self {
+                account: Ok(Default::default()),
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl OldAccount {
+        pub fn account<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Account>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.account = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for account: {e}"));
+            self
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::TryFrom<OldAccount> for super::OldAccount {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: OldAccount,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                account: value.account?,
+            })
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::OldAccount> for OldAccount {
+        fn from(value: super::OldAccount) -> Self {
+            Self {
+                account: Ok(value.account),
+            }
+        }
+    }
+}
+fn main() {}
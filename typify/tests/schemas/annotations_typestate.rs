@@ -0,0 +1,404 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[allow(deprecated)]
+#[doc = "`Account`\n\nAccounts are created through the signup flow."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Account {
+    #[doc = "This value is read-only."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub created: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+    #[doc = "Assigned by the server.\n\nThis value is read-only."]
+    pub id: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legacy: ::std::option::Option<LegacyId>,
+    pub name: ::std::string::String,
+    #[doc = "Use `name` instead."]
+    #[deprecated]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub nickname: ::std::option::Option<::std::string::String>,
+    #[doc = "This value is write-only."]
+    pub password: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub plan: ::std::option::Option<Plan>,
+}
+#[allow(deprecated)]
+#[doc = "`Account` without its read-only properties, for use in requests."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct AccountRequest {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legacy: ::std::option::Option<LegacyId>,
+    pub name: ::std::string::String,
+    #[doc = "Use `name` instead."]
+    #[deprecated]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub nickname: ::std::option::Option<::std::string::String>,
+    #[doc = "This value is write-only."]
+    pub password: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub plan: ::std::option::Option<Plan>,
+}
+#[allow(deprecated)]
+impl ::std::convert::From<Account> for AccountRequest {
+    fn from(value: Account) -> Self {
+        Self {
+            legacy: value.legacy,
+            name: value.name,
+            nickname: value.nickname,
+            password: value.password,
+            plan: value.plan,
+        }
+    }
+}
+#[allow(deprecated)]
+#[doc = "`Account` without its write-only properties, for use in responses."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct AccountResponse {
+    #[doc = "This value is read-only."]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub created: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+    #[doc = "Assigned by the server.\n\nThis value is read-only."]
+    pub id: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legacy: ::std::option::Option<LegacyId>,
+    pub name: ::std::string::String,
+    #[doc = "Use `name` instead."]
+    #[deprecated]
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub nickname: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub plan: ::std::option::Option<Plan>,
+}
+#[allow(deprecated)]
+impl ::std::convert::From<Account> for AccountResponse {
+    fn from(value: Account) -> Self {
+        Self {
+            created: value.created,
+            id: value.id,
+            legacy: value.legacy,
+            name: value.name,
+            nickname: value.nickname,
+            plan: value.plan,
+        }
+    }
+}
+#[allow(deprecated)]
+impl Account {
+    pub fn builder() -> builder::Account {
+        Default::default()
+    }
+}
+#[allow(deprecated)]
+#[doc = "An identifier from the previous system."]
+#[deprecated]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[serde(transparent)]
+pub struct LegacyId(pub ::std::string::String);
+#[allow(deprecated)]
+impl ::std::ops::Deref for LegacyId {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+#[allow(deprecated)]
+impl ::std::convert::From<LegacyId> for ::std::string::String {
+    fn from(value: LegacyId) -> Self {
+        value.0
+    }
+}
+#[allow(deprecated)]
+impl ::std::convert::From<::std::string::String> for LegacyId {
+    fn from(value: ::std::string::String) -> Self {
+        Self(value)
+    }
+}
+#[allow(deprecated)]
+impl ::std::str::FromStr for LegacyId {
+    type Err = ::std::convert::Infallible;
+    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+        Ok(Self(value.to_string()))
+    }
+}
+#[allow(deprecated)]
+impl ::std::fmt::Display for LegacyId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+#[allow(deprecated)]
+#[doc = "`OldAccount`"]
+#[deprecated]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct OldAccount {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub account: ::std::option::Option<Account>,
+}
+#[allow(deprecated)]
+impl OldAccount {
+    pub fn builder() -> builder::OldAccount {
+        Default::default()
+    }
+}
+#[allow(deprecated)]
+#[doc = "`Plan`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub enum Plan {
+    #[serde(rename = "free")]
+    Free,
+    #[doc = "Trials were discontinued."]
+    #[deprecated]
+    #[serde(rename = "trial")]
+    Trial,
+    #[serde(rename = "paid")]
+    Paid {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        legacy: ::std::option::Option<LegacyId>,
+        seats: u32,
+    },
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::AccountRequest>
+        for Account<(), ::std::string::String, ::std::string::String>
+    {
+        fn from(value: super::AccountRequest) -> Self {
+            let unset = <Account as ::std::default::Default>::default();
+            Self {
+                legacy: value.legacy,
+                name: value.name,
+                nickname: value.nickname,
+                password: value.password,
+                plan: value.plan,
+                created: unset.created,
+                id: unset.id,
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::AccountResponse>
+        for Account<::std::string::String, ::std::string::String, ()>
+    {
+        fn from(value: super::AccountResponse) -> Self {
+            let unset = <Account as ::std::default::Default>::default();
+            Self {
+                created: value.created,
+                id: value.id,
+                legacy: value.legacy,
+                name: value.name,
+                nickname: value.nickname,
+                plan: value.plan,
+                password: unset.password,
+            }
+        }
+    }
+    #[allow(deprecated)]
+    #[derive(Clone, Debug)]
+    pub struct Account<IdState = (), NameState = (), PasswordState = ()> {
+        created: ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+        id: IdState,
+        legacy: ::std::option::Option<super::LegacyId>,
+        name: NameState,
+        nickname: ::std::option::Option<::std::string::String>,
+        password: PasswordState,
+        plan: ::std::option::Option<super::Plan>,
+    }
+    #[allow(deprecated)]
+    impl ::std::default::Default for Account<(), (), ()> {
+        fn default() -> Self {
+            Self {
+                created: Default::default(),
+                id: (),
+                legacy: Default::default(),
+                name: (),
+                nickname: Default::default(),
+                password: (),
+                plan: Default::default(),
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl<IdState, NameState, PasswordState> Account<IdState, NameState, PasswordState> {
+        pub fn created<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::Into<
+                ::std::option::Option<::chrono::DateTime<::chrono::offset::Utc>>,
+            >,
+        {
+            self.created = value.into();
+            self
+        }
+        pub fn id<T>(self, value: T) -> Account<::std::string::String, NameState, PasswordState>
+        where
+            T: ::std::convert::Into<::std::string::String>,
+        {
+            Account {
+                created: self.created,
+                id: value.into(),
+                legacy: self.legacy,
+                name: self.name,
+                nickname: self.nickname,
+                password: self.password,
+                plan: self.plan,
+            }
+        }
+        pub fn legacy<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::Into<::std::option::Option<super::LegacyId>>,
+        {
+            self.legacy = value.into();
+            self
+        }
+        pub fn name<T>(self, value: T) -> Account<IdState, ::std::string::String, PasswordState>
+        where
+            T: ::std::convert::Into<::std::string::String>,
+        {
+            Account {
+                created: self.created,
+                id: self.id,
+                legacy: self.legacy,
+                name: value.into(),
+                nickname: self.nickname,
+                password: self.password,
+                plan: self.plan,
+            }
+        }
+        pub fn nickname<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::Into<::std::option::Option<::std::string::String>>,
+        {
+            self.nickname = value.into();
+            self
+        }
+        pub fn password<T>(self, value: T) -> Account<IdState, NameState, ::std::string::String>
+        where
+            T: ::std::convert::Into<::std::string::String>,
+        {
+            Account {
+                created: self.created,
+                id: self.id,
+                legacy: self.legacy,
+                name: self.name,
+                nickname: self.nickname,
+                password: value.into(),
+                plan: self.plan,
+            }
+        }
+        pub fn plan<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::Into<::std::option::Option<super::Plan>>,
+        {
+            self.plan = value.into();
+            self
+        }
+    }
+    #[allow(deprecated)]
+    impl Account<::std::string::String, ::std::string::String, ::std::string::String> {
+        #[doc = r" Construct the item; this is available once all required"]
+        #[doc = r" properties have been set."]
+        pub fn build(self) -> super::Account {
+            super::Account {
+                created: self.created,
+                id: self.id,
+                legacy: self.legacy,
+                name: self.name,
+                nickname: self.nickname,
+                password: self.password,
+                plan: self.plan,
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::Account>
+        for Account<::std::string::String, ::std::string::String, ::std::string::String>
+    {
+        fn from(value: super::Account) -> Self {
+            Self {
+                created: value.created,
+                id: value.id,
+                legacy: value.legacy,
+                name: value.name,
+                nickname: value.nickname,
+                password: value.password,
+                plan: value.plan,
+            }
+        }
+    }
+    #[allow(deprecated)]
+    #[derive(Clone, Debug)]
+    pub struct OldAccount {
+        account: ::std::option::Option<super::Account>,
+    }
+    #[allow(deprecated)]
+    impl ::std::default::Default for OldAccount {
+        fn default() -> Self {
+            Self {
+                account: Default::default(),
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl OldAccount {
+        pub fn account<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::Into<::std::option::Option<super::Account>>,
+        {
+            self.account = value.into();
+            self
+        }
+    }
+    #[allow(deprecated)]
+    impl OldAccount {
+        #[doc = r" Construct the item; this is available once all required"]
+        #[doc = r" properties have been set."]
+        pub fn build(self) -> super::OldAccount {
+            super::OldAccount {
+                account: self.account,
+            }
+        }
+    }
+    #[allow(deprecated)]
+    impl ::std::convert::From<super::OldAccount> for OldAccount {
+        fn from(value: super::OldAccount) -> Self {
+            Self {
+                account: value.account,
+            }
+        }
+    }
+}
+fn main() {}
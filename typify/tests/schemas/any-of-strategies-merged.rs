@@ -0,0 +1,521 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "A way to reach someone."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Contact {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub email: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub extension: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub name: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub phone: ::std::option::Option<::std::string::String>,
+}
+impl Contact {
+    pub fn builder() -> builder::Contact {
+        Default::default()
+    }
+}
+#[doc = "`Email`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Email {
+    pub email: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub name: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub phone: ::std::option::Option<::std::string::String>,
+}
+impl Email {
+    pub fn builder() -> builder::Email {
+        Default::default()
+    }
+}
+#[doc = "`Labels`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Labels {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub owner: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub priority: ::std::option::Option<i64>,
+    #[serde(flatten)]
+    pub extra: ::std::collections::HashMap<::std::string::String, LabelsExtraValue>,
+}
+impl Labels {
+    pub fn builder() -> builder::Labels {
+        Default::default()
+    }
+}
+#[doc = "`LabelsExtraValue`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum LabelsExtraValue {
+    String(::std::string::String),
+    Integer(i64),
+}
+impl ::std::fmt::Display for LabelsExtraValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::String(x) => x.fmt(f),
+            Self::Integer(x) => x.fmt(f),
+        }
+    }
+}
+impl ::std::convert::From<i64> for LabelsExtraValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+#[doc = "`Phone`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Phone {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub email: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub extension: ::std::option::Option<u64>,
+    pub name: ::std::string::String,
+    pub phone: ::std::string::String,
+}
+impl Phone {
+    pub fn builder() -> builder::Phone {
+        Default::default()
+    }
+}
+#[doc = "`Setting`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Setting {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub label: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub level: ::std::option::Option<SettingLevel>,
+}
+impl Setting {
+    pub fn builder() -> builder::Setting {
+        Default::default()
+    }
+}
+#[doc = "`SettingLevel`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum SettingLevel {
+    Integer(i64),
+    String(::std::string::String),
+}
+impl ::std::fmt::Display for SettingLevel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Integer(x) => x.fmt(f),
+            Self::String(x) => x.fmt(f),
+        }
+    }
+}
+impl ::std::convert::From<i64> for SettingLevel {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Contact {
+        email: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        extension: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+        name: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        phone: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Contact {
+        fn default() -> Self {
+            Self {
+                email: Ok(Default::default()),
+                extension: Ok(Default::default()),
+                name: Ok(Default::default()),
+                phone: Ok(Default::default()),
+            }
+        }
+    }
+    impl Contact {
+        pub fn email<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.email = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for email: {e}"));
+            self
+        }
+        pub fn extension<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extension = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extension: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn phone<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.phone = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for phone: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Contact> for super::Contact {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Contact) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                email: value.email?,
+                extension: value.extension?,
+                name: value.name?,
+                phone: value.phone?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Contact> for Contact {
+        fn from(value: super::Contact) -> Self {
+            Self {
+                email: Ok(value.email),
+                extension: Ok(value.extension),
+                name: Ok(value.name),
+                phone: Ok(value.phone),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Email {
+        email: ::std::result::Result<::std::string::String, ::std::string::String>,
+        name: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        phone: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Email {
+        fn default() -> Self {
+            Self {
+                email: Err("no value supplied for email".to_string()),
+                name: Ok(Default::default()),
+                phone: Ok(Default::default()),
+            }
+        }
+    }
+    impl Email {
+        pub fn email<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.email = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for email: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn phone<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.phone = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for phone: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Email> for super::Email {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Email) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                email: value.email?,
+                name: value.name?,
+                phone: value.phone?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Email> for Email {
+        fn from(value: super::Email) -> Self {
+            Self {
+                email: Ok(value.email),
+                name: Ok(value.name),
+                phone: Ok(value.phone),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Labels {
+        owner: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        priority: ::std::result::Result<::std::option::Option<i64>, ::std::string::String>,
+        extra: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, super::LabelsExtraValue>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Labels {
+        fn default() -> Self {
+            Self {
+                owner: Ok(Default::default()),
+                priority: Ok(Default::default()),
+                extra: Err("no value supplied for extra".to_string()),
+            }
+        }
+    }
+    impl Labels {
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+        pub fn priority<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<i64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.priority = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for priority: {e}"));
+            self
+        }
+        pub fn extra<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<::std::string::String, super::LabelsExtraValue>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extra = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extra: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Labels> for super::Labels {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Labels) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                owner: value.owner?,
+                priority: value.priority?,
+                extra: value.extra?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Labels> for Labels {
+        fn from(value: super::Labels) -> Self {
+            Self {
+                owner: Ok(value.owner),
+                priority: Ok(value.priority),
+                extra: Ok(value.extra),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Phone {
+        email: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        extension: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        phone: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Phone {
+        fn default() -> Self {
+            Self {
+                email: Ok(Default::default()),
+                extension: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                phone: Err("no value supplied for phone".to_string()),
+            }
+        }
+    }
+    impl Phone {
+        pub fn email<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.email = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for email: {e}"));
+            self
+        }
+        pub fn extension<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extension = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extension: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn phone<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.phone = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for phone: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Phone> for super::Phone {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Phone) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                email: value.email?,
+                extension: value.extension?,
+                name: value.name?,
+                phone: value.phone?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Phone> for Phone {
+        fn from(value: super::Phone) -> Self {
+            Self {
+                email: Ok(value.email),
+                extension: Ok(value.extension),
+                name: Ok(value.name),
+                phone: Ok(value.phone),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Setting {
+        label: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        level: ::std::result::Result<
+            ::std::option::Option<super::SettingLevel>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Setting {
+        fn default() -> Self {
+            Self {
+                label: Ok(Default::default()),
+                level: Ok(Default::default()),
+            }
+        }
+    }
+    impl Setting {
+        pub fn label<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.label = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for label: {e}"));
+            self
+        }
+        pub fn level<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::SettingLevel>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.level = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for level: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Setting> for super::Setting {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Setting) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                label: value.label?,
+                level: value.level?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Setting> for Setting {
+        fn from(value: super::Setting) -> Self {
+            Self {
+                label: Ok(value.label),
+                level: Ok(value.level),
+            }
+        }
+    }
+}
+fn main() {}
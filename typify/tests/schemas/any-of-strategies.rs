@@ -0,0 +1,717 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "A way to reach someone."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Contact {
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_0: ::std::option::Option<Email>,
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_1: ::std::option::Option<Phone>,
+}
+impl Contact {
+    pub fn builder() -> builder::Contact {
+        Default::default()
+    }
+}
+#[doc = "`Email`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Email {
+    pub email: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub name: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub phone: ::std::option::Option<::std::string::String>,
+}
+impl Email {
+    pub fn builder() -> builder::Email {
+        Default::default()
+    }
+}
+#[doc = "`Labels`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Labels {
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_0: ::std::option::Option<LabelsSubtype0>,
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_1: ::std::option::Option<LabelsSubtype1>,
+}
+impl Labels {
+    pub fn builder() -> builder::Labels {
+        Default::default()
+    }
+}
+#[doc = "`LabelsSubtype0`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct LabelsSubtype0 {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub owner: ::std::option::Option<::std::string::String>,
+    #[serde(flatten)]
+    pub extra: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+}
+impl LabelsSubtype0 {
+    pub fn builder() -> builder::LabelsSubtype0 {
+        Default::default()
+    }
+}
+#[doc = "`LabelsSubtype1`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct LabelsSubtype1 {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub priority: ::std::option::Option<i64>,
+    #[serde(flatten)]
+    pub extra: ::std::collections::HashMap<::std::string::String, i64>,
+}
+impl LabelsSubtype1 {
+    pub fn builder() -> builder::LabelsSubtype1 {
+        Default::default()
+    }
+}
+#[doc = "`Phone`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Phone {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub email: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub extension: ::std::option::Option<u64>,
+    pub name: ::std::string::String,
+    pub phone: ::std::string::String,
+}
+impl Phone {
+    pub fn builder() -> builder::Phone {
+        Default::default()
+    }
+}
+#[doc = "`Setting`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Setting {
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_0: ::std::option::Option<SettingSubtype0>,
+    #[serde(
+        flatten,
+        default,
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub subtype_1: ::std::option::Option<SettingSubtype1>,
+}
+impl Setting {
+    pub fn builder() -> builder::Setting {
+        Default::default()
+    }
+}
+#[doc = "`SettingSubtype0`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct SettingSubtype0 {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub level: ::std::option::Option<i64>,
+}
+impl SettingSubtype0 {
+    pub fn builder() -> builder::SettingSubtype0 {
+        Default::default()
+    }
+}
+#[doc = "`SettingSubtype1`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct SettingSubtype1 {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub label: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub level: ::std::option::Option<::std::string::String>,
+}
+impl SettingSubtype1 {
+    pub fn builder() -> builder::SettingSubtype1 {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Contact {
+        subtype_0:
+            ::std::result::Result<::std::option::Option<super::Email>, ::std::string::String>,
+        subtype_1:
+            ::std::result::Result<::std::option::Option<super::Phone>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Contact {
+        fn default() -> Self {
+            Self {
+                subtype_0: Ok(Default::default()),
+                subtype_1: Ok(Default::default()),
+            }
+        }
+    }
+    impl Contact {
+        pub fn subtype_0<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Email>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_0 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_0: {e}"));
+            self
+        }
+        pub fn subtype_1<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Phone>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_1 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_1: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Contact> for super::Contact {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Contact) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                subtype_0: value.subtype_0?,
+                subtype_1: value.subtype_1?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Contact> for Contact {
+        fn from(value: super::Contact) -> Self {
+            Self {
+                subtype_0: Ok(value.subtype_0),
+                subtype_1: Ok(value.subtype_1),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Email {
+        email: ::std::result::Result<::std::string::String, ::std::string::String>,
+        name: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        phone: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Email {
+        fn default() -> Self {
+            Self {
+                email: Err("no value supplied for email".to_string()),
+                name: Ok(Default::default()),
+                phone: Ok(Default::default()),
+            }
+        }
+    }
+    impl Email {
+        pub fn email<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.email = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for email: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn phone<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.phone = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for phone: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Email> for super::Email {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Email) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                email: value.email?,
+                name: value.name?,
+                phone: value.phone?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Email> for Email {
+        fn from(value: super::Email) -> Self {
+            Self {
+                email: Ok(value.email),
+                name: Ok(value.name),
+                phone: Ok(value.phone),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Labels {
+        subtype_0: ::std::result::Result<
+            ::std::option::Option<super::LabelsSubtype0>,
+            ::std::string::String,
+        >,
+        subtype_1: ::std::result::Result<
+            ::std::option::Option<super::LabelsSubtype1>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Labels {
+        fn default() -> Self {
+            Self {
+                subtype_0: Ok(Default::default()),
+                subtype_1: Ok(Default::default()),
+            }
+        }
+    }
+    impl Labels {
+        pub fn subtype_0<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::LabelsSubtype0>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_0 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_0: {e}"));
+            self
+        }
+        pub fn subtype_1<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::LabelsSubtype1>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_1 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_1: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Labels> for super::Labels {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Labels) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                subtype_0: value.subtype_0?,
+                subtype_1: value.subtype_1?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Labels> for Labels {
+        fn from(value: super::Labels) -> Self {
+            Self {
+                subtype_0: Ok(value.subtype_0),
+                subtype_1: Ok(value.subtype_1),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct LabelsSubtype0 {
+        owner: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        extra: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for LabelsSubtype0 {
+        fn default() -> Self {
+            Self {
+                owner: Ok(Default::default()),
+                extra: Err("no value supplied for extra".to_string()),
+            }
+        }
+    }
+    impl LabelsSubtype0 {
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+        pub fn extra<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extra = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extra: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<LabelsSubtype0> for super::LabelsSubtype0 {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: LabelsSubtype0,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                owner: value.owner?,
+                extra: value.extra?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::LabelsSubtype0> for LabelsSubtype0 {
+        fn from(value: super::LabelsSubtype0) -> Self {
+            Self {
+                owner: Ok(value.owner),
+                extra: Ok(value.extra),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct LabelsSubtype1 {
+        priority: ::std::result::Result<::std::option::Option<i64>, ::std::string::String>,
+        extra: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, i64>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for LabelsSubtype1 {
+        fn default() -> Self {
+            Self {
+                priority: Ok(Default::default()),
+                extra: Err("no value supplied for extra".to_string()),
+            }
+        }
+    }
+    impl LabelsSubtype1 {
+        pub fn priority<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<i64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.priority = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for priority: {e}"));
+            self
+        }
+        pub fn extra<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::collections::HashMap<::std::string::String, i64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extra = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extra: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<LabelsSubtype1> for super::LabelsSubtype1 {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: LabelsSubtype1,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                priority: value.priority?,
+                extra: value.extra?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::LabelsSubtype1> for LabelsSubtype1 {
+        fn from(value: super::LabelsSubtype1) -> Self {
+            Self {
+                priority: Ok(value.priority),
+                extra: Ok(value.extra),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Phone {
+        email: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        extension: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        phone: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Phone {
+        fn default() -> Self {
+            Self {
+                email: Ok(Default::default()),
+                extension: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                phone: Err("no value supplied for phone".to_string()),
+            }
+        }
+    }
+    impl Phone {
+        pub fn email<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.email = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for email: {e}"));
+            self
+        }
+        pub fn extension<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.extension = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for extension: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn phone<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.phone = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for phone: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Phone> for super::Phone {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Phone) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                email: value.email?,
+                extension: value.extension?,
+                name: value.name?,
+                phone: value.phone?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Phone> for Phone {
+        fn from(value: super::Phone) -> Self {
+            Self {
+                email: Ok(value.email),
+                extension: Ok(value.extension),
+                name: Ok(value.name),
+                phone: Ok(value.phone),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Setting {
+        subtype_0: ::std::result::Result<
+            ::std::option::Option<super::SettingSubtype0>,
+            ::std::string::String,
+        >,
+        subtype_1: ::std::result::Result<
+            ::std::option::Option<super::SettingSubtype1>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Setting {
+        fn default() -> Self {
+            Self {
+                subtype_0: Ok(Default::default()),
+                subtype_1: Ok(Default::default()),
+            }
+        }
+    }
+    impl Setting {
+        pub fn subtype_0<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::SettingSubtype0>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_0 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_0: {e}"));
+            self
+        }
+        pub fn subtype_1<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::SettingSubtype1>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.subtype_1 = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for subtype_1: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Setting> for super::Setting {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Setting) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                subtype_0: value.subtype_0?,
+                subtype_1: value.subtype_1?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Setting> for Setting {
+        fn from(value: super::Setting) -> Self {
+            Self {
+                subtype_0: Ok(value.subtype_0),
+                subtype_1: Ok(value.subtype_1),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SettingSubtype0 {
+        level: ::std::result::Result<::std::option::Option<i64>, ::std::string::String>,
+    }
+    impl ::std::default::Default for SettingSubtype0 {
+        fn default() -> Self {
+            Self {
+                level: Ok(Default::default()),
+            }
+        }
+    }
+    impl SettingSubtype0 {
+        pub fn level<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<i64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.level = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for level: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<SettingSubtype0> for super::SettingSubtype0 {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: SettingSubtype0,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                level: value.level?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::SettingSubtype0> for SettingSubtype0 {
+        fn from(value: super::SettingSubtype0) -> Self {
+            Self {
+                level: Ok(value.level),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct SettingSubtype1 {
+        label: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        level: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for SettingSubtype1 {
+        fn default() -> Self {
+            Self {
+                label: Ok(Default::default()),
+                level: Ok(Default::default()),
+            }
+        }
+    }
+    impl SettingSubtype1 {
+        pub fn label<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.label = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for label: {e}"));
+            self
+        }
+        pub fn level<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.level = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for level: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<SettingSubtype1> for super::SettingSubtype1 {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: SettingSubtype1,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                label: value.label?,
+                level: value.level?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::SettingSubtype1> for SettingSubtype1 {
+        fn from(value: super::SettingSubtype1) -> Self {
+            Self {
+                label: Ok(value.label),
+                level: Ok(value.level),
+            }
+        }
+    }
+}
+fn main() {}
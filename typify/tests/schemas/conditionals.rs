@@ -0,0 +1,219 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Address`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Address {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub country: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub postcode: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub state: ::std::option::Option<::std::string::String>,
+}
+impl Address {
+    pub fn builder() -> builder::Address {
+        Default::default()
+    }
+}
+#[doc = "`Payment`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(tag = "method")]
+pub enum Payment {
+    #[serde(rename = "card")]
+    Card {
+        amount: u64,
+        card_number: ::std::string::String,
+    },
+    #[serde(rename = "cash")]
+    Cash {
+        amount: u64,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        card_number: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        change: ::std::option::Option<u64>,
+    },
+}
+#[doc = "`PaymentMethod`"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum PaymentMethod {
+    #[serde(rename = "card")]
+    Card,
+    #[serde(rename = "cash")]
+    Cash,
+}
+impl ::std::fmt::Display for PaymentMethod {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Card => f.write_str("card"),
+            Self::Cash => f.write_str("cash"),
+        }
+    }
+}
+impl ::std::str::FromStr for PaymentMethod {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "card" => Ok(Self::Card),
+            "cash" => Ok(Self::Cash),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for PaymentMethod {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for PaymentMethod {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "Where to store artifacts."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(tag = "kind")]
+pub enum Storage {
+    #[serde(rename = "s3")]
+    S3 {
+        bucket: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        path: ::std::option::Option<::std::string::String>,
+        region: ::std::string::String,
+    },
+    #[serde(rename = "gcs")]
+    Gcs {
+        bucket: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        path: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        region: ::std::option::Option<::std::string::String>,
+    },
+    #[serde(rename = "local")]
+    Local {
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        bucket: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        path: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+        region: ::std::option::Option<::std::string::String>,
+    },
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Address {
+        country: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        postcode: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+        state: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Address {
+        fn default() -> Self {
+            Self {
+                country: Ok(Default::default()),
+                postcode: Ok(Default::default()),
+                state: Ok(Default::default()),
+            }
+        }
+    }
+    impl Address {
+        pub fn country<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.country = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for country: {e}"));
+            self
+        }
+        pub fn postcode<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.postcode = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for postcode: {e}"));
+            self
+        }
+        pub fn state<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.state = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for state: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Address> for super::Address {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Address) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                country: value.country?,
+                postcode: value.postcode?,
+                state: value.state?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Address> for Address {
+        fn from(value: super::Address) -> Self {
+            Self {
+                country: Ok(value.country),
+                postcode: Ok(value.postcode),
+                state: Ok(value.state),
+            }
+        }
+    }
+}
+fn main() {}
@@ -25,7 +25,7 @@ pub mod error {
         }
     }
 }
-#[doc = "`TestType`"]
+#[doc = "`TestType`\n\nvalidate a 'not' schema with typed- and untyped-subschemas"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 pub struct TestType {
     pub where_not: TestTypeWhereNot,
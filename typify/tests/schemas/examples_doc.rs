@@ -0,0 +1,247 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "A pet in the store.\n\n# Examples\n\n```json\n{\n  \"kind\": \"dog\",\n  \"name\": \"Rex\"\n}\n```\n\n```json\n{\n  \"kind\": \"cat\",\n  \"name\": \"Tom\",\n  \"tag\": \"t-100\"\n}\n```"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Pet {
+    pub kind: PetKind,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub tag: ::std::option::Option<Tag>,
+}
+impl Pet {
+    pub fn builder() -> builder::Pet {
+        Default::default()
+    }
+}
+#[doc = "`PetKind`\n\n# Examples\n\n```json\n\"dog\"\n```"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum PetKind {
+    #[serde(rename = "dog")]
+    Dog,
+    #[serde(rename = "cat")]
+    Cat,
+}
+impl ::std::fmt::Display for PetKind {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Dog => f.write_str("dog"),
+            Self::Cat => f.write_str("cat"),
+        }
+    }
+}
+impl ::std::str::FromStr for PetKind {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "dog" => Ok(Self::Dog),
+            "cat" => Ok(Self::Cat),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for PetKind {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for PetKind {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`Tag`\n\n# Examples\n\n```json\n\"t-1\"\n```"]
+#[derive(:: serde :: Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct Tag(::std::string::String);
+impl ::std::ops::Deref for Tag {
+    type Target = ::std::string::String;
+    fn deref(&self) -> &::std::string::String {
+        &self.0
+    }
+}
+impl ::std::convert::From<Tag> for ::std::string::String {
+    fn from(value: Tag) -> Self {
+        value.0
+    }
+}
+impl ::std::str::FromStr for Tag {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        static PATTERN: ::std::sync::LazyLock<::regress::Regex> =
+            ::std::sync::LazyLock::new(|| ::regress::Regex::new("^t-[0-9]+$").unwrap());
+        if PATTERN.find(value).is_none() {
+            return Err("doesn't match pattern \"^t-[0-9]+$\"".into());
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+impl ::std::convert::TryFrom<&str> for Tag {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for Tag {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        ::std::string::String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| {
+                <D::Error as ::serde::de::Error>::custom(e.to_string())
+            })
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Pet {
+        kind: ::std::result::Result<super::PetKind, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        tag: ::std::result::Result<::std::option::Option<super::Tag>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Pet {
+        fn default() -> Self {
+            Self {
+                kind: Err("no value supplied for kind".to_string()),
+                name: Err("no value supplied for name".to_string()),
+                tag: Ok(Default::default()),
+            }
+        }
+    }
+    impl Pet {
+        pub fn kind<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::PetKind>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.kind = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for kind: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn tag<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Tag>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tag = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tag: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Pet> for super::Pet {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Pet) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                kind: value.kind?,
+                name: value.name?,
+                tag: value.tag?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Pet> for Pet {
+        fn from(value: super::Pet) -> Self {
+            Self {
+                kind: Ok(value.kind),
+                name: Ok(value.name),
+                tag: Ok(value.tag),
+            }
+        }
+    }
+}
+#[doc = r" Tests that schema examples round-trip through their types."]
+#[cfg(test)]
+mod typify_examples {
+    #[test]
+    fn pet_examples() {
+        const EXAMPLES: &[&str] = &[
+            "{\"kind\":\"dog\",\"name\":\"Rex\"}",
+            "{\"kind\":\"cat\",\"name\":\"Tom\",\"tag\":\"t-100\"}",
+        ];
+        for example in EXAMPLES {
+            let value: ::serde_json::Value = ::serde_json::from_str(example).unwrap();
+            let typed: super::Pet = ::serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(::serde_json::to_value(&typed).unwrap(), value);
+        }
+    }
+    #[test]
+    fn pet_kind_examples() {
+        const EXAMPLES: &[&str] = &["\"dog\""];
+        for example in EXAMPLES {
+            let value: ::serde_json::Value = ::serde_json::from_str(example).unwrap();
+            let typed: super::PetKind = ::serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(::serde_json::to_value(&typed).unwrap(), value);
+        }
+    }
+    #[test]
+    fn tag_examples() {
+        const EXAMPLES: &[&str] = &["\"t-1\""];
+        for example in EXAMPLES {
+            let value: ::serde_json::Value = ::serde_json::from_str(example).unwrap();
+            let typed: super::Tag = ::serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(::serde_json::to_value(&typed).unwrap(), value);
+        }
+    }
+}
+fn main() {}
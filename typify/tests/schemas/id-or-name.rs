@@ -76,7 +76,7 @@ impl ::std::convert::From<Name> for IdOrName {
         Self::Name(value)
     }
 }
-#[doc = "`IdOrNameRedundant`"]
+#[doc = "`IdOrNameRedundant`\n\ntests references that include a redundant type field"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum IdOrNameRedundant {
@@ -0,0 +1,240 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Level`"]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(i64)]
+pub enum Level {
+    Low = 10,
+    High = 20,
+}
+impl ::std::convert::From<Level> for i64 {
+    fn from(value: Level) -> Self {
+        value as i64
+    }
+}
+impl ::std::convert::TryFrom<i64> for Level {
+    type Error = self::error::ConversionError;
+    fn try_from(value: i64) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            10 => Ok(Self::Low),
+            20 => Ok(Self::High),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::serde::Serialize for Level {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_i64(*self as i64)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Level {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        Self::try_from(<i64 as ::serde::Deserialize>::deserialize(deserializer)?)
+            .map_err(<D::Error as ::serde::de::Error>::custom)
+    }
+}
+#[doc = "`Priority`"]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(i64)]
+pub enum Priority {
+    Value1 = 1,
+    Value2 = 2,
+    Value4 = 4,
+    Value8 = 8,
+}
+impl ::std::convert::From<Priority> for i64 {
+    fn from(value: Priority) -> Self {
+        value as i64
+    }
+}
+impl ::std::convert::TryFrom<i64> for Priority {
+    type Error = self::error::ConversionError;
+    fn try_from(value: i64) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            1 => Ok(Self::Value1),
+            2 => Ok(Self::Value2),
+            4 => Ok(Self::Value4),
+            8 => Ok(Self::Value8),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::serde::Serialize for Priority {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_i64(*self as i64)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Priority {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        Self::try_from(<i64 as ::serde::Deserialize>::deserialize(deserializer)?)
+            .map_err(<D::Error as ::serde::de::Error>::custom)
+    }
+}
+#[doc = "The status of a request."]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(i64)]
+pub enum Status {
+    Ok = 0,
+    NotFound = 404,
+    Error = -1,
+}
+impl ::std::convert::From<Status> for i64 {
+    fn from(value: Status) -> Self {
+        value as i64
+    }
+}
+impl ::std::convert::TryFrom<i64> for Status {
+    type Error = self::error::ConversionError;
+    fn try_from(value: i64) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            0 => Ok(Self::Ok),
+            404 => Ok(Self::NotFound),
+            -1 => Ok(Self::Error),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_i64(*self as i64)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        Self::try_from(<i64 as ::serde::Deserialize>::deserialize(deserializer)?)
+            .map_err(<D::Error as ::serde::de::Error>::custom)
+    }
+}
+#[doc = "`Task`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Task {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub level: ::std::option::Option<Level>,
+    #[serde(default = "defaults::task_priority")]
+    pub priority: Priority,
+    pub status: Status,
+}
+impl Task {
+    pub fn builder() -> builder::Task {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Task {
+        level: ::std::result::Result<::std::option::Option<super::Level>, ::std::string::String>,
+        priority: ::std::result::Result<super::Priority, ::std::string::String>,
+        status: ::std::result::Result<super::Status, ::std::string::String>,
+    }
+    impl ::std::default::Default for Task {
+        fn default() -> Self {
+            Self {
+                level: Ok(Default::default()),
+                priority: Ok(super::defaults::task_priority()),
+                status: Err("no value supplied for status".to_string()),
+            }
+        }
+    }
+    impl Task {
+        pub fn level<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Level>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.level = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for level: {e}"));
+            self
+        }
+        pub fn priority<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Priority>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.priority = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for priority: {e}"));
+            self
+        }
+        pub fn status<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Status>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.status = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for status: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Task> for super::Task {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Task) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                level: value.level?,
+                priority: value.priority?,
+                status: value.status?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Task> for Task {
+        fn from(value: super::Task) -> Self {
+            Self {
+                level: Ok(value.level),
+                priority: Ok(value.priority),
+                status: Ok(value.status),
+            }
+        }
+    }
+}
+#[doc = r" Generation of default values for serde."]
+pub mod defaults {
+    pub(super) fn task_priority() -> super::Priority {
+        super::Priority::Value2
+    }
+}
+fn main() {}
@@ -25,7 +25,7 @@ pub mod error {
         }
     }
 }
-#[doc = "`DeadSimple`"]
+#[doc = "`DeadSimple`\n\nusual case of a map whose name must come from its title"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct DeadSimple(pub ::serde_json::Map<::std::string::String, ::serde_json::Value>);
@@ -90,7 +90,7 @@ impl ::std::fmt::Display for Eh {
         self.0.fmt(f)
     }
 }
-#[doc = "`MapWithDateKeys`"]
+#[doc = "`MapWithDateKeys`\n\ntest that a type isn't needed for propertyNames"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct MapWithDateKeys(pub std::collections::BTreeMap<::chrono::naive::NaiveDate, Value>);
@@ -114,7 +114,7 @@ impl ::std::convert::From<std::collections::BTreeMap<::chrono::naive::NaiveDate,
         Self(value)
     }
 }
-#[doc = "`MapWithDateTimeKeys`"]
+#[doc = "`MapWithDateTimeKeys`\n\ntest that a type isn't needed for propertyNames"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct MapWithDateTimeKeys(
@@ -205,7 +205,7 @@ impl ::std::convert::TryFrom<::std::string::String> for JsonSuccessResult {
         value.parse()
     }
 }
-#[doc = "`MergeEmpty`"]
+#[doc = "`MergeEmpty`\n\nproperties conflict but are not required so we end up with an empty object"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
 #[serde(deny_unknown_fields)]
 pub struct MergeEmpty {}
@@ -214,7 +214,7 @@ impl MergeEmpty {
         Default::default()
     }
 }
-#[doc = "`MergeNumberBounds`"]
+#[doc = "`MergeNumberBounds`\n\nmerging number constraints takes the most restrictive bounds; mixed inclusive/exclusive bounds are normalized to drop the subsumed one"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct MergeNumberBounds(pub f64);
@@ -257,7 +257,7 @@ impl ::std::fmt::Display for MergeNumberBounds {
         self.0.fmt(f)
     }
 }
-#[doc = "`MergeStringBounds`"]
+#[doc = "`MergeStringBounds`\n\nmerging string constraints takes the most restrictive bounds: max_length takes the min, min_length takes the max"]
 #[derive(:: serde :: Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[serde(transparent)]
 pub struct MergeStringBounds(::std::string::String);
@@ -365,7 +365,7 @@ impl OrderDependentMerge {
         Default::default()
     }
 }
-#[doc = "`Pickingone`"]
+#[doc = "`Pickingone`\n\nTODO this generates an extra type for the pickingone-user dependency"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 pub struct Pickingone {
     pub suspended_by: PickingoneSuspendedBy,
@@ -529,7 +529,7 @@ impl ::std::convert::TryFrom<::std::string::String> for UnchangedByMergeTag {
         value.parse()
     }
 }
-#[doc = "`Unresolvable`"]
+#[doc = "`Unresolvable`\n\nsubschemas all end up unresolvable"]
 #[derive(
     :: serde :: Deserialize,
     :: serde :: Serialize,
@@ -559,7 +559,7 @@ pub enum Unresolvable {}
 )]
 #[serde(deny_unknown_fields)]
 pub enum Unsatisfiable1 {}
-#[doc = "`Unsatisfiable2`"]
+#[doc = "`Unsatisfiable2`\n\ncan't be satisfied because required properties conflict in their enum values"]
 #[derive(
     :: serde :: Deserialize,
     :: serde :: Serialize,
@@ -574,7 +574,7 @@ pub enum Unsatisfiable1 {}
 )]
 #[serde(deny_unknown_fields)]
 pub enum Unsatisfiable2 {}
-#[doc = "`Unsatisfiable3`"]
+#[doc = "`Unsatisfiable3`\n\ntests a complex merge that can't be satisfied; it's basically the same as unsatisfiable-2, but is broken into multiple pieces"]
 #[derive(
     :: serde :: Deserialize,
     :: serde :: Serialize,
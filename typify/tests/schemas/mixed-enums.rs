@@ -0,0 +1,290 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Config`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Config {
+    #[serde(default = "defaults::config_flag")]
+    pub flag: Flag,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub limit: ::std::option::Option<MaybeLimit>,
+    #[serde(default = "defaults::config_setting")]
+    pub setting: Setting,
+}
+impl ::std::default::Default for Config {
+    fn default() -> Self {
+        Self {
+            flag: defaults::config_flag(),
+            limit: Default::default(),
+            setting: defaults::config_setting(),
+        }
+    }
+}
+impl Config {
+    pub fn builder() -> builder::Config {
+        Default::default()
+    }
+}
+#[doc = "`Flag`"]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Flag {
+    True,
+    BooleanTrue,
+    False,
+}
+impl Flag {
+    #[doc = r" The literal value of this variant."]
+    pub fn to_value(self) -> ::serde_json::Value {
+        match self {
+            Self::True => ::serde_json::json!("true"),
+            Self::BooleanTrue => ::serde_json::json!(true),
+            Self::False => ::serde_json::json!(false),
+        }
+    }
+}
+impl ::serde::Serialize for Flag {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        ::serde::Serialize::serialize(&self.to_value(), serializer)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Flag {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+        if value.as_str() == Some("true") {
+            Ok(Self::True)
+        } else if value.as_bool() == Some(true) {
+            Ok(Self::BooleanTrue)
+        } else if value.as_bool() == Some(false) {
+            Ok(Self::False)
+        } else {
+            Err(<D::Error as ::serde::de::Error>::custom(format!(
+                "invalid value: {}",
+                value,
+            )))
+        }
+    }
+}
+#[doc = "`MaybeLimit`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct MaybeLimit(pub ::std::option::Option<MaybeLimitInner>);
+impl ::std::ops::Deref for MaybeLimit {
+    type Target = ::std::option::Option<MaybeLimitInner>;
+    fn deref(&self) -> &::std::option::Option<MaybeLimitInner> {
+        &self.0
+    }
+}
+impl ::std::convert::From<MaybeLimit> for ::std::option::Option<MaybeLimitInner> {
+    fn from(value: MaybeLimit) -> Self {
+        value.0
+    }
+}
+impl ::std::convert::From<::std::option::Option<MaybeLimitInner>> for MaybeLimit {
+    fn from(value: ::std::option::Option<MaybeLimitInner>) -> Self {
+        Self(value)
+    }
+}
+#[doc = "`MaybeLimitInner`"]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MaybeLimitInner {
+    None,
+    Number10,
+    NumberNeg1,
+}
+impl MaybeLimitInner {
+    #[doc = r" The literal value of this variant."]
+    pub fn to_value(self) -> ::serde_json::Value {
+        match self {
+            Self::None => ::serde_json::json!("none"),
+            Self::Number10 => ::serde_json::json!(10),
+            Self::NumberNeg1 => ::serde_json::json!(-1),
+        }
+    }
+}
+impl ::serde::Serialize for MaybeLimitInner {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        ::serde::Serialize::serialize(&self.to_value(), serializer)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for MaybeLimitInner {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+        if value.as_str() == Some("none") {
+            Ok(Self::None)
+        } else if value.as_i64() == Some(10) || (value.is_f64() && value.as_f64() == Some(10.0)) {
+            Ok(Self::Number10)
+        } else if value.as_i64() == Some(-1) || (value.is_f64() && value.as_f64() == Some(-1.0)) {
+            Ok(Self::NumberNeg1)
+        } else {
+            Err(<D::Error as ::serde::de::Error>::custom(format!(
+                "invalid value: {}",
+                value,
+            )))
+        }
+    }
+}
+#[doc = "A setting that may be automatic or a specific value."]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Setting {
+    Auto,
+    Number0,
+    Number1Point5,
+    True,
+}
+impl Setting {
+    #[doc = r" The literal value of this variant."]
+    pub fn to_value(self) -> ::serde_json::Value {
+        match self {
+            Self::Auto => ::serde_json::json!("auto"),
+            Self::Number0 => ::serde_json::json!(0),
+            Self::Number1Point5 => ::serde_json::json!(1.5),
+            Self::True => ::serde_json::json!(true),
+        }
+    }
+}
+impl ::serde::Serialize for Setting {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        ::serde::Serialize::serialize(&self.to_value(), serializer)
+    }
+}
+impl<'de> ::serde::Deserialize<'de> for Setting {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+        if value.as_str() == Some("auto") {
+            Ok(Self::Auto)
+        } else if value.as_i64() == Some(0) || (value.is_f64() && value.as_f64() == Some(0.0)) {
+            Ok(Self::Number0)
+        } else if value.as_f64() == Some(1.5) {
+            Ok(Self::Number1Point5)
+        } else if value.as_bool() == Some(true) {
+            Ok(Self::True)
+        } else {
+            Err(<D::Error as ::serde::de::Error>::custom(format!(
+                "invalid value: {}",
+                value,
+            )))
+        }
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Config {
+        flag: ::std::result::Result<super::Flag, ::std::string::String>,
+        limit:
+            ::std::result::Result<::std::option::Option<super::MaybeLimit>, ::std::string::String>,
+        setting: ::std::result::Result<super::Setting, ::std::string::String>,
+    }
+    impl ::std::default::Default for Config {
+        fn default() -> Self {
+            Self {
+                flag: Ok(super::defaults::config_flag()),
+                limit: Ok(Default::default()),
+                setting: Ok(super::defaults::config_setting()),
+            }
+        }
+    }
+    impl Config {
+        pub fn flag<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Flag>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.flag = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for flag: {e}"));
+            self
+        }
+        pub fn limit<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::MaybeLimit>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.limit = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for limit: {e}"));
+            self
+        }
+        pub fn setting<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Setting>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.setting = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for setting: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Config> for super::Config {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Config) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                flag: value.flag?,
+                limit: value.limit?,
+                setting: value.setting?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Config> for Config {
+        fn from(value: super::Config) -> Self {
+            Self {
+                flag: Ok(value.flag),
+                limit: Ok(value.limit),
+                setting: Ok(value.setting),
+            }
+        }
+    }
+}
+#[doc = r" Generation of default values for serde."]
+pub mod defaults {
+    pub(super) fn config_flag() -> super::Flag {
+        super::Flag::False
+    }
+    pub(super) fn config_setting() -> super::Setting {
+        super::Setting::Auto
+    }
+}
+fn main() {}
@@ -0,0 +1,276 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Limits`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Limits {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub cpu: ::std::option::Option<u32>,
+}
+impl Limits {
+    pub fn builder() -> builder::Limits {
+        Default::default()
+    }
+}
+#[doc = "A PATCH-style update in which null clears a value."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct UpdateRequest {
+    #[serde(
+        default,
+        deserialize_with = "serde_helpers::deserialize_some",
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub description: ::std::option::Option<::std::option::Option<::std::string::String>>,
+    #[doc = "Nested Options other than those of properties are flattened."]
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub history: ::std::vec::Vec<::std::option::Option<::std::string::String>>,
+    pub id: ::std::string::String,
+    #[serde(
+        default,
+        deserialize_with = "serde_helpers::deserialize_some",
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub limits: ::std::option::Option<::std::option::Option<Limits>>,
+    #[serde(
+        default,
+        deserialize_with = "serde_helpers::deserialize_some",
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub owner: ::std::option::Option<::std::option::Option<::std::string::String>>,
+    #[serde(
+        default,
+        deserialize_with = "serde_helpers::deserialize_some",
+        skip_serializing_if = "::std::option::Option::is_none"
+    )]
+    pub previous: ::std::option::Option<::std::option::Option<::std::string::String>>,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub replicas: ::std::option::Option<u32>,
+}
+impl UpdateRequest {
+    pub fn builder() -> builder::UpdateRequest {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Limits {
+        cpu: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Limits {
+        fn default() -> Self {
+            Self {
+                cpu: Ok(Default::default()),
+            }
+        }
+    }
+    impl Limits {
+        pub fn cpu<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u32>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.cpu = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for cpu: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Limits> for super::Limits {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Limits) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self { cpu: value.cpu? })
+        }
+    }
+    impl ::std::convert::From<super::Limits> for Limits {
+        fn from(value: super::Limits) -> Self {
+            Self { cpu: Ok(value.cpu) }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct UpdateRequest {
+        description: ::std::result::Result<
+            ::std::option::Option<::std::option::Option<::std::string::String>>,
+            ::std::string::String,
+        >,
+        history: ::std::result::Result<
+            ::std::vec::Vec<::std::option::Option<::std::string::String>>,
+            ::std::string::String,
+        >,
+        id: ::std::result::Result<::std::string::String, ::std::string::String>,
+        limits: ::std::result::Result<
+            ::std::option::Option<::std::option::Option<super::Limits>>,
+            ::std::string::String,
+        >,
+        owner: ::std::result::Result<
+            ::std::option::Option<::std::option::Option<::std::string::String>>,
+            ::std::string::String,
+        >,
+        previous: ::std::result::Result<
+            ::std::option::Option<::std::option::Option<::std::string::String>>,
+            ::std::string::String,
+        >,
+        replicas: ::std::result::Result<::std::option::Option<u32>, ::std::string::String>,
+    }
+    impl ::std::default::Default for UpdateRequest {
+        fn default() -> Self {
+            Self {
+                description: Ok(Default::default()),
+                history: Ok(Default::default()),
+                id: Err("no value supplied for id".to_string()),
+                limits: Ok(Default::default()),
+                owner: Ok(Default::default()),
+                previous: Ok(Default::default()),
+                replicas: Ok(Default::default()),
+            }
+        }
+    }
+    impl UpdateRequest {
+        pub fn description<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<::std::option::Option<::std::string::String>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.description = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for description: {e}"));
+            self
+        }
+        pub fn history<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::vec::Vec<::std::option::Option<::std::string::String>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.history = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for history: {e}"));
+            self
+        }
+        pub fn id<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.id = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for id: {e}"));
+            self
+        }
+        pub fn limits<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::option::Option<super::Limits>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.limits = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for limits: {e}"));
+            self
+        }
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<::std::option::Option<::std::string::String>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+        pub fn previous<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<::std::option::Option<::std::string::String>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.previous = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for previous: {e}"));
+            self
+        }
+        pub fn replicas<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u32>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.replicas = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for replicas: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<UpdateRequest> for super::UpdateRequest {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: UpdateRequest,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                description: value.description?,
+                history: value.history?,
+                id: value.id?,
+                limits: value.limits?,
+                owner: value.owner?,
+                previous: value.previous?,
+                replicas: value.replicas?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::UpdateRequest> for UpdateRequest {
+        fn from(value: super::UpdateRequest) -> Self {
+            Self {
+                description: Ok(value.description),
+                history: Ok(value.history),
+                id: Ok(value.id),
+                limits: Ok(value.limits),
+                owner: Ok(value.owner),
+                previous: Ok(value.previous),
+                replicas: Ok(value.replicas),
+            }
+        }
+    }
+}
+#[doc = r" Helpers for serde."]
+pub mod serde_helpers {
+    #[doc = r" Deserialize a property that is present (though"]
+    #[doc = r" possibly `null`) as `Some`."]
+    pub fn deserialize_some<'de, D, T>(
+        deserializer: D,
+    ) -> ::std::result::Result<::std::option::Option<T>, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+        T: ::serde::Deserialize<'de>,
+    {
+        T::deserialize(deserializer).map(::std::option::Option::Some)
+    }
+}
+fn main() {}
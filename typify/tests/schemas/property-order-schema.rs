@@ -0,0 +1,118 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Event`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Event {
+    pub timestamp: ::std::string::String,
+    pub kind: ::std::string::String,
+    #[serde(
+        default,
+        skip_serializing_if = ":: std :: collections :: HashMap::is_empty"
+    )]
+    pub attributes: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+}
+impl Event {
+    pub fn builder() -> builder::Event {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Event {
+        timestamp: ::std::result::Result<::std::string::String, ::std::string::String>,
+        kind: ::std::result::Result<::std::string::String, ::std::string::String>,
+        attributes: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Event {
+        fn default() -> Self {
+            Self {
+                timestamp: Err("no value supplied for timestamp".to_string()),
+                kind: Err("no value supplied for kind".to_string()),
+                attributes: Ok(Default::default()),
+            }
+        }
+    }
+    impl Event {
+        pub fn timestamp<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.timestamp = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for timestamp: {e}"));
+            self
+        }
+        pub fn kind<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.kind = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for kind: {e}"));
+            self
+        }
+        pub fn attributes<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.attributes = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for attributes: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Event> for super::Event {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Event) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                timestamp: value.timestamp?,
+                kind: value.kind?,
+                attributes: value.attributes?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Event> for Event {
+        fn from(value: super::Event) -> Self {
+            Self {
+                timestamp: Ok(value.timestamp),
+                kind: Ok(value.kind),
+                attributes: Ok(value.attributes),
+            }
+        }
+    }
+}
+fn main() {}
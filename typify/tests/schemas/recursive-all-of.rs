@@ -0,0 +1,382 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Base`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Base {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+    pub name: ::std::string::String,
+}
+impl Base {
+    pub fn builder() -> builder::Base {
+        Default::default()
+    }
+}
+#[doc = "a node that refers to its parent"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Linked {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub parent: ::std::option::Option<::std::boxed::Box<Linked>>,
+}
+impl Linked {
+    pub fn builder() -> builder::Linked {
+        Default::default()
+    }
+}
+#[doc = "a tree node whose children are nodes"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Node {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<Node>,
+    pub name: ::std::string::String,
+}
+impl Node {
+    pub fn builder() -> builder::Node {
+        Default::default()
+    }
+}
+#[doc = "`Paragraph`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Paragraph {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<Section>,
+    pub name: ::std::string::String,
+}
+impl Paragraph {
+    pub fn builder() -> builder::Paragraph {
+        Default::default()
+    }
+}
+#[doc = "sections contain paragraphs which contain sections"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Section {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<Paragraph>,
+    pub name: ::std::string::String,
+}
+impl Section {
+    pub fn builder() -> builder::Section {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Base {
+        children: ::std::result::Result<
+            ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+            ::std::string::String,
+        >,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Base {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl Base {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Base> for super::Base {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Base) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Base> for Base {
+        fn from(value: super::Base) -> Self {
+            Self {
+                children: Ok(value.children),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Linked {
+        children: ::std::result::Result<
+            ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+            ::std::string::String,
+        >,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        parent: ::std::result::Result<
+            ::std::option::Option<::std::boxed::Box<super::Linked>>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Linked {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                parent: Ok(Default::default()),
+            }
+        }
+    }
+    impl Linked {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::vec::Vec<::serde_json::Map<::std::string::String, ::serde_json::Value>>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn parent<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::boxed::Box<super::Linked>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.parent = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for parent: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Linked> for super::Linked {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Linked) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                name: value.name?,
+                parent: value.parent?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Linked> for Linked {
+        fn from(value: super::Linked) -> Self {
+            Self {
+                children: Ok(value.children),
+                name: Ok(value.name),
+                parent: Ok(value.parent),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Node {
+        children: ::std::result::Result<::std::vec::Vec<super::Node>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Node {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl Node {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::Node>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Node> for super::Node {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Node) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Node> for Node {
+        fn from(value: super::Node) -> Self {
+            Self {
+                children: Ok(value.children),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Paragraph {
+        children: ::std::result::Result<::std::vec::Vec<super::Section>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Paragraph {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl Paragraph {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::Section>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Paragraph> for super::Paragraph {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: Paragraph,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Paragraph> for Paragraph {
+        fn from(value: super::Paragraph) -> Self {
+            Self {
+                children: Ok(value.children),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Section {
+        children: ::std::result::Result<::std::vec::Vec<super::Paragraph>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for Section {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl Section {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::Paragraph>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Section> for super::Section {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Section) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Section> for Section {
+        fn from(value: super::Section) -> Self {
+            Self {
+                children: Ok(value.children),
+                name: Ok(value.name),
+            }
+        }
+    }
+}
+fn main() {}
@@ -0,0 +1,332 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "the pointer goes on the optional field that refers back to the company rather than on the required one"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Company {
+    pub name: ::std::string::String,
+    pub owner: Owner,
+}
+impl Company {
+    pub fn builder() -> builder::Company {
+        Default::default()
+    }
+}
+#[doc = "`Expr`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum Expr {
+    Number(f64),
+    Object {
+        lhs: ::std::boxed::Box<Expr>,
+        op: ::std::string::String,
+        rhs: ::std::boxed::Box<Expr>,
+    },
+}
+impl ::std::convert::From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+#[doc = "`Owner`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Owner {
+    pub person: Person,
+}
+impl Owner {
+    pub fn builder() -> builder::Owner {
+        Default::default()
+    }
+}
+#[doc = "`Person`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Person {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub employer: ::std::option::Option<::std::boxed::Box<Company>>,
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub parent: ::std::option::Option<::std::boxed::Box<Person>>,
+}
+impl Person {
+    pub fn builder() -> builder::Person {
+        Default::default()
+    }
+}
+#[doc = "children are behind a Vec so no pointer is needed"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Tree {
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub children: ::std::vec::Vec<Tree>,
+    #[serde(
+        default,
+        skip_serializing_if = ":: std :: collections :: HashMap::is_empty"
+    )]
+    pub labels: ::std::collections::HashMap<::std::string::String, Tree>,
+    pub value: i64,
+}
+impl Tree {
+    pub fn builder() -> builder::Tree {
+        Default::default()
+    }
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Company {
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        owner: ::std::result::Result<super::Owner, ::std::string::String>,
+    }
+    impl ::std::default::Default for Company {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                owner: Err("no value supplied for owner".to_string()),
+            }
+        }
+    }
+    impl Company {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Owner>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Company> for super::Company {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Company) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                name: value.name?,
+                owner: value.owner?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Company> for Company {
+        fn from(value: super::Company) -> Self {
+            Self {
+                name: Ok(value.name),
+                owner: Ok(value.owner),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Owner {
+        person: ::std::result::Result<super::Person, ::std::string::String>,
+    }
+    impl ::std::default::Default for Owner {
+        fn default() -> Self {
+            Self {
+                person: Err("no value supplied for person".to_string()),
+            }
+        }
+    }
+    impl Owner {
+        pub fn person<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::Person>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.person = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for person: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Owner> for super::Owner {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Owner) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                person: value.person?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Owner> for Owner {
+        fn from(value: super::Owner) -> Self {
+            Self {
+                person: Ok(value.person),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Person {
+        employer: ::std::result::Result<
+            ::std::option::Option<::std::boxed::Box<super::Company>>,
+            ::std::string::String,
+        >,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        parent: ::std::result::Result<
+            ::std::option::Option<::std::boxed::Box<super::Person>>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Person {
+        fn default() -> Self {
+            Self {
+                employer: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+                parent: Ok(Default::default()),
+            }
+        }
+    }
+    impl Person {
+        pub fn employer<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::boxed::Box<super::Company>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.employer = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for employer: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn parent<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::boxed::Box<super::Person>>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.parent = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for parent: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Person> for super::Person {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Person) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                employer: value.employer?,
+                name: value.name?,
+                parent: value.parent?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Person> for Person {
+        fn from(value: super::Person) -> Self {
+            Self {
+                employer: Ok(value.employer),
+                name: Ok(value.name),
+                parent: Ok(value.parent),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Tree {
+        children: ::std::result::Result<::std::vec::Vec<super::Tree>, ::std::string::String>,
+        labels: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, super::Tree>,
+            ::std::string::String,
+        >,
+        value: ::std::result::Result<i64, ::std::string::String>,
+    }
+    impl ::std::default::Default for Tree {
+        fn default() -> Self {
+            Self {
+                children: Ok(Default::default()),
+                labels: Ok(Default::default()),
+                value: Err("no value supplied for value".to_string()),
+            }
+        }
+    }
+    impl Tree {
+        pub fn children<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::Tree>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.children = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for children: {e}"));
+            self
+        }
+        pub fn labels<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<::std::string::String, super::Tree>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.labels = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for labels: {e}"));
+            self
+        }
+        pub fn value<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<i64>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.value = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for value: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Tree> for super::Tree {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Tree) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                children: value.children?,
+                labels: value.labels?,
+                value: value.value?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Tree> for Tree {
+        fn from(value: super::Tree) -> Self {
+            Self {
+                children: Ok(value.children),
+                labels: Ok(value.labels),
+                value: Ok(value.value),
+            }
+        }
+    }
+}
+fn main() {}
@@ -25,7 +25,7 @@ pub mod error {
         }
     }
 }
-#[doc = "`Node`"]
+#[doc = "`Node`\n\nvalidate references to the whole"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
 pub struct Node {
     #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -0,0 +1,629 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Pet`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Pet {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub owner: ::std::option::Option<PetPropertiesOwner>,
+    pub pet_type: PetPropertiesPetType,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub tags: ::std::vec::Vec<PetPropertiesTagsItems>,
+}
+impl Pet {
+    pub fn builder() -> builder::Pet {
+        Default::default()
+    }
+}
+#[doc = "`PetPropertiesOwner`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct PetPropertiesOwner {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub name: ::std::option::Option<::std::string::String>,
+}
+impl PetPropertiesOwner {
+    pub fn builder() -> builder::PetPropertiesOwner {
+        Default::default()
+    }
+}
+#[doc = "`PetPropertiesPetType`"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum PetPropertiesPetType {
+    #[serde(rename = "cat")]
+    Cat,
+    #[serde(rename = "dog")]
+    Dog,
+}
+impl ::std::fmt::Display for PetPropertiesPetType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Cat => f.write_str("cat"),
+            Self::Dog => f.write_str("dog"),
+        }
+    }
+}
+impl ::std::str::FromStr for PetPropertiesPetType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "cat" => Ok(Self::Cat),
+            "dog" => Ok(Self::Dog),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for PetPropertiesPetType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for PetPropertiesPetType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`PetPropertiesTagsItems`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct PetPropertiesTagsItems {
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub value: ::std::option::Option<::std::string::String>,
+}
+impl PetPropertiesTagsItems {
+    pub fn builder() -> builder::PetPropertiesTagsItems {
+        Default::default()
+    }
+}
+#[doc = "A kind of pet, as opposed to the type of a particular pet."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct PetType {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legs: ::std::option::Option<u64>,
+    pub name: ::std::string::String,
+}
+impl PetType {
+    pub fn builder() -> builder::PetType {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfig`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct ServerConfig {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub config_file: ::std::option::Option<ServerConfigPropertiesConfigFile>,
+    #[serde(
+        default,
+        skip_serializing_if = ":: std :: collections :: HashMap::is_empty"
+    )]
+    pub limits: ::std::collections::HashMap<
+        ::std::string::String,
+        ServerConfigPropertiesLimitsAdditionalProperties,
+    >,
+}
+impl ServerConfig {
+    pub fn builder() -> builder::ServerConfig {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfigPropertiesConfigFile`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct ServerConfigPropertiesConfigFile {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub format: ::std::option::Option<ServerConfigPropertiesConfigFilePropertiesFormat>,
+    pub path: ::std::string::String,
+}
+impl ServerConfigPropertiesConfigFile {
+    pub fn builder() -> builder::ServerConfigPropertiesConfigFile {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfigPropertiesConfigFilePropertiesFormat`"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum ServerConfigPropertiesConfigFilePropertiesFormat {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "toml")]
+    Toml,
+}
+impl ::std::fmt::Display for ServerConfigPropertiesConfigFilePropertiesFormat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Json => f.write_str("json"),
+            Self::Toml => f.write_str("toml"),
+        }
+    }
+}
+impl ::std::str::FromStr for ServerConfigPropertiesConfigFilePropertiesFormat {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "json" => Ok(Self::Json),
+            "toml" => Ok(Self::Toml),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for ServerConfigPropertiesConfigFilePropertiesFormat {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String>
+    for ServerConfigPropertiesConfigFilePropertiesFormat
+{
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`ServerConfigPropertiesLimitsAdditionalProperties`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct ServerConfigPropertiesLimitsAdditionalProperties {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max: ::std::option::Option<u64>,
+}
+impl ServerConfigPropertiesLimitsAdditionalProperties {
+    pub fn builder() -> builder::ServerConfigPropertiesLimitsAdditionalProperties {
+        Default::default()
+    }
+}
+#[doc = "`Shape`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub enum Shape {
+    #[serde(rename = "circle")]
+    Circle { radius: f64 },
+    #[serde(rename = "square")]
+    Square { side: f64 },
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Pet {
+        owner: ::std::result::Result<
+            ::std::option::Option<super::PetPropertiesOwner>,
+            ::std::string::String,
+        >,
+        pet_type: ::std::result::Result<super::PetPropertiesPetType, ::std::string::String>,
+        tags: ::std::result::Result<
+            ::std::vec::Vec<super::PetPropertiesTagsItems>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Pet {
+        fn default() -> Self {
+            Self {
+                owner: Ok(Default::default()),
+                pet_type: Err("no value supplied for pet_type".to_string()),
+                tags: Ok(Default::default()),
+            }
+        }
+    }
+    impl Pet {
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::PetPropertiesOwner>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+        pub fn pet_type<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::PetPropertiesPetType>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.pet_type = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for pet_type: {e}"));
+            self
+        }
+        pub fn tags<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::PetPropertiesTagsItems>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tags = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tags: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Pet> for super::Pet {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Pet) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                owner: value.owner?,
+                pet_type: value.pet_type?,
+                tags: value.tags?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Pet> for Pet {
+        fn from(value: super::Pet) -> Self {
+            Self {
+                owner: Ok(value.owner),
+                pet_type: Ok(value.pet_type),
+                tags: Ok(value.tags),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PetPropertiesOwner {
+        name: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for PetPropertiesOwner {
+        fn default() -> Self {
+            Self {
+                name: Ok(Default::default()),
+            }
+        }
+    }
+    impl PetPropertiesOwner {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PetPropertiesOwner> for super::PetPropertiesOwner {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: PetPropertiesOwner,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self { name: value.name? })
+        }
+    }
+    impl ::std::convert::From<super::PetPropertiesOwner> for PetPropertiesOwner {
+        fn from(value: super::PetPropertiesOwner) -> Self {
+            Self {
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PetPropertiesTagsItems {
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        value: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for PetPropertiesTagsItems {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                value: Ok(Default::default()),
+            }
+        }
+    }
+    impl PetPropertiesTagsItems {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn value<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.value = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for value: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PetPropertiesTagsItems> for super::PetPropertiesTagsItems {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: PetPropertiesTagsItems,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                name: value.name?,
+                value: value.value?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::PetPropertiesTagsItems> for PetPropertiesTagsItems {
+        fn from(value: super::PetPropertiesTagsItems) -> Self {
+            Self {
+                name: Ok(value.name),
+                value: Ok(value.value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PetType {
+        legs: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for PetType {
+        fn default() -> Self {
+            Self {
+                legs: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl PetType {
+        pub fn legs<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.legs = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for legs: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PetType> for super::PetType {
+        type Error = super::error::ConversionError;
+        fn try_from(value: PetType) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                legs: value.legs?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::PetType> for PetType {
+        fn from(value: super::PetType) -> Self {
+            Self {
+                legs: Ok(value.legs),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfig {
+        config_file: ::std::result::Result<
+            ::std::option::Option<super::ServerConfigPropertiesConfigFile>,
+            ::std::string::String,
+        >,
+        limits: ::std::result::Result<
+            ::std::collections::HashMap<
+                ::std::string::String,
+                super::ServerConfigPropertiesLimitsAdditionalProperties,
+            >,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for ServerConfig {
+        fn default() -> Self {
+            Self {
+                config_file: Ok(Default::default()),
+                limits: Ok(Default::default()),
+            }
+        }
+    }
+    impl ServerConfig {
+        pub fn config_file<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<super::ServerConfigPropertiesConfigFile>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.config_file = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for config_file: {e}"));
+            self
+        }
+        pub fn limits<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<
+                    ::std::string::String,
+                    super::ServerConfigPropertiesLimitsAdditionalProperties,
+                >,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.limits = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for limits: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfig> for super::ServerConfig {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfig,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                config_file: value.config_file?,
+                limits: value.limits?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfig> for ServerConfig {
+        fn from(value: super::ServerConfig) -> Self {
+            Self {
+                config_file: Ok(value.config_file),
+                limits: Ok(value.limits),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfigPropertiesConfigFile {
+        format: ::std::result::Result<
+            ::std::option::Option<super::ServerConfigPropertiesConfigFilePropertiesFormat>,
+            ::std::string::String,
+        >,
+        path: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for ServerConfigPropertiesConfigFile {
+        fn default() -> Self {
+            Self {
+                format: Ok(Default::default()),
+                path: Err("no value supplied for path".to_string()),
+            }
+        }
+    }
+    impl ServerConfigPropertiesConfigFile {
+        pub fn format<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::option::Option<super::ServerConfigPropertiesConfigFilePropertiesFormat>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.format = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for format: {e}"));
+            self
+        }
+        pub fn path<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.path = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for path: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfigPropertiesConfigFile>
+        for super::ServerConfigPropertiesConfigFile
+    {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfigPropertiesConfigFile,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                format: value.format?,
+                path: value.path?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfigPropertiesConfigFile>
+        for ServerConfigPropertiesConfigFile
+    {
+        fn from(value: super::ServerConfigPropertiesConfigFile) -> Self {
+            Self {
+                format: Ok(value.format),
+                path: Ok(value.path),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfigPropertiesLimitsAdditionalProperties {
+        max: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+    }
+    impl ::std::default::Default for ServerConfigPropertiesLimitsAdditionalProperties {
+        fn default() -> Self {
+            Self {
+                max: Ok(Default::default()),
+            }
+        }
+    }
+    impl ServerConfigPropertiesLimitsAdditionalProperties {
+        pub fn max<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.max = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for max: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfigPropertiesLimitsAdditionalProperties>
+        for super::ServerConfigPropertiesLimitsAdditionalProperties
+    {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfigPropertiesLimitsAdditionalProperties,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self { max: value.max? })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfigPropertiesLimitsAdditionalProperties>
+        for ServerConfigPropertiesLimitsAdditionalProperties
+    {
+        fn from(value: super::ServerConfigPropertiesLimitsAdditionalProperties) -> Self {
+            Self { max: Ok(value.max) }
+        }
+    }
+}
+fn main() {}
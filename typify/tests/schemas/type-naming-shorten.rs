@@ -0,0 +1,598 @@
+#![deny(warnings)]
+#[doc = r" Error types."]
+pub mod error {
+    #[doc = r" Error from a `TryFrom` or `FromStr` implementation."]
+    pub struct ConversionError(::std::borrow::Cow<'static, str>);
+    impl ::std::error::Error for ConversionError {}
+    impl ::std::fmt::Display for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+    impl ::std::fmt::Debug for ConversionError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> Result<(), ::std::fmt::Error> {
+            ::std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+    impl From<&'static str> for ConversionError {
+        fn from(value: &'static str) -> Self {
+            Self(value.into())
+        }
+    }
+    impl From<String> for ConversionError {
+        fn from(value: String) -> Self {
+            Self(value.into())
+        }
+    }
+}
+#[doc = "`Owner`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct Owner {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub name: ::std::option::Option<::std::string::String>,
+}
+impl Owner {
+    pub fn builder() -> builder::Owner {
+        Default::default()
+    }
+}
+#[doc = "`Pet`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct Pet {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub owner: ::std::option::Option<Owner>,
+    pub pet_type: PetPetType,
+    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
+    pub tags: ::std::vec::Vec<PetTagsItem>,
+}
+impl Pet {
+    pub fn builder() -> builder::Pet {
+        Default::default()
+    }
+}
+#[doc = "`PetPetType`"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum PetPetType {
+    #[serde(rename = "cat")]
+    Cat,
+    #[serde(rename = "dog")]
+    Dog,
+}
+impl ::std::fmt::Display for PetPetType {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Cat => f.write_str("cat"),
+            Self::Dog => f.write_str("dog"),
+        }
+    }
+}
+impl ::std::str::FromStr for PetPetType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "cat" => Ok(Self::Cat),
+            "dog" => Ok(Self::Dog),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for PetPetType {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for PetPetType {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`PetTagsItem`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct PetTagsItem {
+    pub name: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub value: ::std::option::Option<::std::string::String>,
+}
+impl PetTagsItem {
+    pub fn builder() -> builder::PetTagsItem {
+        Default::default()
+    }
+}
+#[doc = "A kind of pet, as opposed to the type of a particular pet."]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct PetType {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub legs: ::std::option::Option<u64>,
+    pub name: ::std::string::String,
+}
+impl PetType {
+    pub fn builder() -> builder::PetType {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfig`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct ServerConfig {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub config_file: ::std::option::Option<ServerConfigFile>,
+    #[serde(
+        default,
+        skip_serializing_if = ":: std :: collections :: HashMap::is_empty"
+    )]
+    pub limits: ::std::collections::HashMap<::std::string::String, ServerConfigLimitsValue>,
+}
+impl ServerConfig {
+    pub fn builder() -> builder::ServerConfig {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfigFile`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub struct ServerConfigFile {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub format: ::std::option::Option<ServerConfigFileFormat>,
+    pub path: ::std::string::String,
+}
+impl ServerConfigFile {
+    pub fn builder() -> builder::ServerConfigFile {
+        Default::default()
+    }
+}
+#[doc = "`ServerConfigFileFormat`"]
+#[derive(
+    :: serde :: Deserialize,
+    :: serde :: Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+pub enum ServerConfigFileFormat {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "toml")]
+    Toml,
+}
+impl ::std::fmt::Display for ServerConfigFileFormat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            Self::Json => f.write_str("json"),
+            Self::Toml => f.write_str("toml"),
+        }
+    }
+}
+impl ::std::str::FromStr for ServerConfigFileFormat {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        match value {
+            "json" => Ok(Self::Json),
+            "toml" => Ok(Self::Toml),
+            _ => Err("invalid value".into()),
+        }
+    }
+}
+impl ::std::convert::TryFrom<&str> for ServerConfigFileFormat {
+    type Error = self::error::ConversionError;
+    fn try_from(value: &str) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+impl ::std::convert::TryFrom<::std::string::String> for ServerConfigFileFormat {
+    type Error = self::error::ConversionError;
+    fn try_from(
+        value: ::std::string::String,
+    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value.parse()
+    }
+}
+#[doc = "`ServerConfigLimitsValue`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Default)]
+pub struct ServerConfigLimitsValue {
+    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
+    pub max: ::std::option::Option<u64>,
+}
+impl ServerConfigLimitsValue {
+    pub fn builder() -> builder::ServerConfigLimitsValue {
+        Default::default()
+    }
+}
+#[doc = "`Shape`"]
+#[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+pub enum Shape {
+    #[serde(rename = "circle")]
+    Circle { radius: f64 },
+    #[serde(rename = "square")]
+    Square { side: f64 },
+}
+#[doc = r" Types for composing complex structures."]
+pub mod builder {
+    #[derive(Clone, Debug)]
+    pub struct Owner {
+        name: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for Owner {
+        fn default() -> Self {
+            Self {
+                name: Ok(Default::default()),
+            }
+        }
+    }
+    impl Owner {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Owner> for super::Owner {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Owner) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self { name: value.name? })
+        }
+    }
+    impl ::std::convert::From<super::Owner> for Owner {
+        fn from(value: super::Owner) -> Self {
+            Self {
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct Pet {
+        owner: ::std::result::Result<::std::option::Option<super::Owner>, ::std::string::String>,
+        pet_type: ::std::result::Result<super::PetPetType, ::std::string::String>,
+        tags: ::std::result::Result<::std::vec::Vec<super::PetTagsItem>, ::std::string::String>,
+    }
+    impl ::std::default::Default for Pet {
+        fn default() -> Self {
+            Self {
+                owner: Ok(Default::default()),
+                pet_type: Err("no value supplied for pet_type".to_string()),
+                tags: Ok(Default::default()),
+            }
+        }
+    }
+    impl Pet {
+        pub fn owner<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::Owner>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.owner = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for owner: {e}"));
+            self
+        }
+        pub fn pet_type<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<super::PetPetType>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.pet_type = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for pet_type: {e}"));
+            self
+        }
+        pub fn tags<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::vec::Vec<super::PetTagsItem>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.tags = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for tags: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<Pet> for super::Pet {
+        type Error = super::error::ConversionError;
+        fn try_from(value: Pet) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                owner: value.owner?,
+                pet_type: value.pet_type?,
+                tags: value.tags?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::Pet> for Pet {
+        fn from(value: super::Pet) -> Self {
+            Self {
+                owner: Ok(value.owner),
+                pet_type: Ok(value.pet_type),
+                tags: Ok(value.tags),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PetTagsItem {
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+        value: ::std::result::Result<
+            ::std::option::Option<::std::string::String>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for PetTagsItem {
+        fn default() -> Self {
+            Self {
+                name: Err("no value supplied for name".to_string()),
+                value: Ok(Default::default()),
+            }
+        }
+    }
+    impl PetTagsItem {
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+        pub fn value<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<::std::string::String>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.value = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for value: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PetTagsItem> for super::PetTagsItem {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: PetTagsItem,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                name: value.name?,
+                value: value.value?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::PetTagsItem> for PetTagsItem {
+        fn from(value: super::PetTagsItem) -> Self {
+            Self {
+                name: Ok(value.name),
+                value: Ok(value.value),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct PetType {
+        legs: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+        name: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for PetType {
+        fn default() -> Self {
+            Self {
+                legs: Ok(Default::default()),
+                name: Err("no value supplied for name".to_string()),
+            }
+        }
+    }
+    impl PetType {
+        pub fn legs<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.legs = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for legs: {e}"));
+            self
+        }
+        pub fn name<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.name = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for name: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<PetType> for super::PetType {
+        type Error = super::error::ConversionError;
+        fn try_from(value: PetType) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                legs: value.legs?,
+                name: value.name?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::PetType> for PetType {
+        fn from(value: super::PetType) -> Self {
+            Self {
+                legs: Ok(value.legs),
+                name: Ok(value.name),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfig {
+        config_file: ::std::result::Result<
+            ::std::option::Option<super::ServerConfigFile>,
+            ::std::string::String,
+        >,
+        limits: ::std::result::Result<
+            ::std::collections::HashMap<::std::string::String, super::ServerConfigLimitsValue>,
+            ::std::string::String,
+        >,
+    }
+    impl ::std::default::Default for ServerConfig {
+        fn default() -> Self {
+            Self {
+                config_file: Ok(Default::default()),
+                limits: Ok(Default::default()),
+            }
+        }
+    }
+    impl ServerConfig {
+        pub fn config_file<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::ServerConfigFile>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.config_file = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for config_file: {e}"));
+            self
+        }
+        pub fn limits<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<
+                ::std::collections::HashMap<::std::string::String, super::ServerConfigLimitsValue>,
+            >,
+            T::Error: ::std::fmt::Display,
+        {
+            self.limits = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for limits: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfig> for super::ServerConfig {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfig,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                config_file: value.config_file?,
+                limits: value.limits?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfig> for ServerConfig {
+        fn from(value: super::ServerConfig) -> Self {
+            Self {
+                config_file: Ok(value.config_file),
+                limits: Ok(value.limits),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfigFile {
+        format: ::std::result::Result<
+            ::std::option::Option<super::ServerConfigFileFormat>,
+            ::std::string::String,
+        >,
+        path: ::std::result::Result<::std::string::String, ::std::string::String>,
+    }
+    impl ::std::default::Default for ServerConfigFile {
+        fn default() -> Self {
+            Self {
+                format: Ok(Default::default()),
+                path: Err("no value supplied for path".to_string()),
+            }
+        }
+    }
+    impl ServerConfigFile {
+        pub fn format<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<super::ServerConfigFileFormat>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.format = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for format: {e}"));
+            self
+        }
+        pub fn path<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::string::String>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.path = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for path: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfigFile> for super::ServerConfigFile {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfigFile,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self {
+                format: value.format?,
+                path: value.path?,
+            })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfigFile> for ServerConfigFile {
+        fn from(value: super::ServerConfigFile) -> Self {
+            Self {
+                format: Ok(value.format),
+                path: Ok(value.path),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct ServerConfigLimitsValue {
+        max: ::std::result::Result<::std::option::Option<u64>, ::std::string::String>,
+    }
+    impl ::std::default::Default for ServerConfigLimitsValue {
+        fn default() -> Self {
+            Self {
+                max: Ok(Default::default()),
+            }
+        }
+    }
+    impl ServerConfigLimitsValue {
+        pub fn max<T>(mut self, value: T) -> Self
+        where
+            T: ::std::convert::TryInto<::std::option::Option<u64>>,
+            T::Error: ::std::fmt::Display,
+        {
+            self.max = value
+                .try_into()
+                .map_err(|e| format!("error converting supplied value for max: {e}"));
+            self
+        }
+    }
+    impl ::std::convert::TryFrom<ServerConfigLimitsValue> for super::ServerConfigLimitsValue {
+        type Error = super::error::ConversionError;
+        fn try_from(
+            value: ServerConfigLimitsValue,
+        ) -> ::std::result::Result<Self, super::error::ConversionError> {
+            Ok(Self { max: value.max? })
+        }
+    }
+    impl ::std::convert::From<super::ServerConfigLimitsValue> for ServerConfigLimitsValue {
+        fn from(value: super::ServerConfigLimitsValue) -> Self {
+            Self { max: Ok(value.max) }
+        }
+    }
+}
+fn main() {}
@@ -25,7 +25,7 @@ pub mod error {
         }
     }
 }
-#[doc = "`TestType`"]
+#[doc = "`TestType`\n\nvalidate replacement, patch, and conversion settings"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 pub struct TestType {
     pub converted_type: serde_json::Value,
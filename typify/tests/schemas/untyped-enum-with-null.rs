@@ -25,7 +25,7 @@ pub mod error {
         }
     }
 }
-#[doc = "`TestType`"]
+#[doc = "`TestType`\n\nvalidate a type with no type and enum values that include a null"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
 pub struct TestType {
     pub value: ::std::option::Option<TestTypeValue>,
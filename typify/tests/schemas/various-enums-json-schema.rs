@@ -375,7 +375,7 @@ pub enum EnumAndConstant {
     #[serde(rename = "fish")]
     Fish { float: ::std::string::String },
 }
-#[doc = "`IpNet`"]
+#[doc = "`IpNet`\n\nwe want to see *nice* variant names in the output"]
 #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, schemars :: JsonSchema)]
 #[serde(untagged)]
 pub enum IpNet {
@@ -623,22 +623,22 @@ pub enum NeverEverForever {}
 #[doc = "`NotBlockSize`"]
 #[derive(:: serde :: Serialize, Clone, Debug)]
 #[serde(transparent)]
-pub struct NotBlockSize(f64);
+pub struct NotBlockSize(i64);
 impl ::std::ops::Deref for NotBlockSize {
-    type Target = f64;
-    fn deref(&self) -> &f64 {
+    type Target = i64;
+    fn deref(&self) -> &i64 {
         &self.0
     }
 }
-impl ::std::convert::From<NotBlockSize> for f64 {
+impl ::std::convert::From<NotBlockSize> for i64 {
     fn from(value: NotBlockSize) -> Self {
         value.0
     }
 }
-impl ::std::convert::TryFrom<f64> for NotBlockSize {
+impl ::std::convert::TryFrom<i64> for NotBlockSize {
     type Error = self::error::ConversionError;
-    fn try_from(value: f64) -> ::std::result::Result<Self, self::error::ConversionError> {
-        if [512_f64, 2048_f64, 4096_f64].contains(&value) {
+    fn try_from(value: i64) -> ::std::result::Result<Self, self::error::ConversionError> {
+        if [512_i64, 2048_i64, 4096_i64].contains(&value) {
             Err("invalid value".into())
         } else {
             Ok(Self(value))
@@ -650,7 +650,7 @@ impl<'de> ::serde::Deserialize<'de> for NotBlockSize {
     where
         D: ::serde::Deserializer<'de>,
     {
-        Self::try_from(<f64>::deserialize(deserializer)?)
+        Self::try_from(<i64>::deserialize(deserializer)?)
             .map_err(|e| <D::Error as ::serde::de::Error>::custom(e.to_string()))
     }
 }
@@ -659,7 +659,7 @@ impl ::schemars::JsonSchema for NotBlockSize {
         "NotBlockSize".to_string()
     }
     fn json_schema(gen: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
-        let mut schema = <f64 as ::schemars::JsonSchema>::json_schema(gen).into_object();
+        let mut schema = <i64 as ::schemars::JsonSchema>::json_schema(gen).into_object();
         let not = ::schemars::schema::SchemaObject {
             enum_values: ::std::option::Option::Some(
                 [
@@ -977,6 +977,7 @@ impl ::std::fmt::Display for ReferenceDef {
 #[serde(untagged)]
 pub enum References {
     Array(::std::vec::Vec<::std::string::String>),
+    #[doc = "Mapping of mod name to the desired version"]
     Object(::std::collections::HashMap<::std::string::String, ReferencesObjectValue>),
 }
 impl ::std::convert::From<::std::vec::Vec<::std::string::String>> for References {